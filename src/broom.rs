@@ -1,7 +1,9 @@
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::prelude::*;
+use std::collections::HashSet;
 use crate::bullet::aabb_overlap;
 use crate::{TILE_SIZE, GameState};
 use crate::player::Player;
+use crate::replay::{Action, PlayerActions};
 use crate::collidable::Collider;
 use crate::enemies::Enemy;
 use crate::window::{Health, GlassState, Window};
@@ -16,8 +18,58 @@ pub struct BroomSwing {
     pub timer: Timer,
     pub active: bool,
     pub base_angle: f32,
+    /// Held to full charge: longer reach, wider arc and heavy knockback.
+    pub charged: bool,
+    pub length: f32,
+    /// Total sweep angle in radians.
+    pub arc: f32,
+    /// Enemies already knocked back (and stunned) by this swing.
+    pub hit: HashSet<Entity>,
 }
 
+/// Broom upgrades collected this run; lives on the player and is carried
+/// between stations in `SavedPlayerBuffs`.
+#[derive(Component, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct BroomStats {
+    /// Health restored to a broken window per frame of contact.
+    pub repair: f32,
+    /// Multiplier on broom length and sweep angle.
+    pub reach: f32,
+    /// Seconds of Stun applied on hit; 0 until the upgrade is found.
+    pub stun: f32,
+}
+
+impl Default for BroomStats {
+    fn default() -> Self {
+        Self { repair: 20.0, reach: 1.0, stun: 0.0 }
+    }
+}
+
+// ── Tuning ───────────────────────────────────────────────────────────────────
+
+const BROOM_LENGTH: f32 = TILE_SIZE * 2.5;
+const BROOM_WIDTH: f32 = TILE_SIZE * 1.0;
+const SWING_TIME: f32 = 0.25;
+const SWING_ARC: f32 = std::f32::consts::PI;
+/// Seconds at the start of a swing during which a deflect counts as a parry.
+const PARRY_WINDOW: f32 = 0.08;
+const PARRY_DAMAGE_MULT: f32 = 2.0;
+const PARRY_SPEED_MULT: f32 = 1.3;
+const PARRY_TINT: Color = Color::srgb(1.0, 0.9, 0.3);
+/// Seconds the broom binding must be held for a charged sweep.
+const CHARGE_TIME: f32 = 0.6;
+const CHARGED_SWING_TIME: f32 = 0.35;
+const CHARGED_LENGTH_MULT: f32 = 1.4;
+const CHARGED_ARC: f32 = std::f32::consts::PI * 1.5;
+/// Dealt once per enemy per swing. Enough to drop a fresh chaser, which the
+/// old 10-per-frame contact damage also did at 60 fps.
+const SWING_DAMAGE: f32 = 50.0;
+const SWING_KNOCKBACK: f32 = 150.0;
+const CHARGED_DAMAGE: f32 = 100.0;
+/// Enough to carry an enemy a few tiles — and out through a breach.
+const CHARGED_KNOCKBACK: f32 = 900.0;
+const CHARGED_TINT: Color = Color::srgb(0.6, 0.85, 1.0);
+
 use crate::bullet::{Bullet, BulletOwner, HitEnemies};
 use crate::GameEntity;
 
@@ -27,82 +79,79 @@ impl Plugin for BroomPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Update, broom_input.run_if(in_state(GameState::Playing)).run_if(not(resource_exists::<crate::pause::IsPaused>)))
            .add_systems(Update, broom_swing_system.run_if(in_state(GameState::Playing)))
-           .add_systems(Update, broom_hit_enemies_system.run_if(in_state(GameState::Playing)))
+           .add_systems(Update, broom_hit_enemies_system.in_set(crate::enemies::EnemyDamageSet).run_if(in_state(GameState::Playing)))
            .add_systems(Update, broom_push_tables_system.run_if(in_state(GameState::Playing)))
            .add_systems(Update, broom_fix_window.run_if(in_state(GameState::Playing)))
            .add_systems(Update, broom_hit_bullets_system.run_if(in_state(GameState::Playing)));
     }
 }
 
+/// Deflects enemy bullets back as player shots. Caught inside the parry
+/// window they instead fly at the nearest enemy, faster and harder-hitting.
 pub fn broom_hit_bullets_system(
     mut commands: Commands,
-    broom_query: Query<(&Transform, &Collider), With<Broom>>,
-    mut bullet_query: Query<(Entity, &Transform, &Collider, &mut crate::bullet::Velocity, &mut BulletOwner), With<Bullet>>,
+    broom_query: Query<(&Transform, &Collider, &BroomSwing), With<Broom>>,
+    mut bullet_query: Query<
+        (Entity, &Transform, &crate::bullet::PrevPosition, &Collider, &mut crate::bullet::Velocity, &mut BulletOwner, &mut crate::weapons::BulletDamage, &mut Sprite),
+        (With<Bullet>, Without<Broom>),
+    >,
+    enemies: Query<&Transform, (With<Enemy>, With<crate::enemies::ActiveEnemy>, Without<Bullet>)>,
 ) {
-    let (broom_transform, broom_collider) = match broom_query.single() {
+    let (broom_transform, broom_collider, swing) = match broom_query.single() {
         Ok(b) => b,
         Err(_) => return,
     };
+    let parry = swing.timer.elapsed_secs() <= PARRY_WINDOW;
 
     let broom_center = broom_transform.translation.truncate();
     let broom_half = broom_collider.half_extents;
 
-    for (bullet_entity, bullet_transform, bullet_collider, mut vel, mut owner) in bullet_query.iter_mut() {
+    for (bullet_entity, bullet_transform, prev, bullet_collider, mut vel, mut owner, mut damage, mut sprite) in bullet_query.iter_mut() {
         if !matches!(*owner, BulletOwner::Enemy) { continue; }
 
         let bullet_center = bullet_transform.translation.truncate();
         let bullet_half = bullet_collider.half_extents;
 
-        let overlap =
-            (broom_center.x - bullet_center.x).abs() < (broom_half.x + bullet_half.x) &&
-            (broom_center.y - bullet_center.y).abs() < (broom_half.y + bullet_half.y);
-
-        if overlap {
-            vel.0 = -vel.0;
-            *owner = BulletOwner::Player;
-            commands.entity(bullet_entity).insert(HitEnemies::default());
+        // Sweep the bullet's last step so fast shots can't pass through the broom.
+        let from = prev.0.unwrap_or(bullet_center);
+        if crate::bullet::sweep_aabb(from, bullet_center, bullet_half, broom_center, broom_half).is_none() {
+            continue;
+        }
+        *owner = BulletOwner::Player;
+        commands.entity(bullet_entity).insert(HitEnemies::default());
+
+        let target = enemies
+            .iter()
+            .map(|e| e.translation.truncate())
+            .min_by(|a, b| a.distance_squared(bullet_center).total_cmp(&b.distance_squared(bullet_center)));
+        match target {
+            Some(target) if parry => {
+                let speed = vel.0.length() * PARRY_SPEED_MULT;
+                vel.0 = (target - bullet_center).normalize_or(-vel.0.normalize_or_zero()) * speed;
+                damage.0 *= PARRY_DAMAGE_MULT;
+                sprite.color = PARRY_TINT;
+            }
+            _ => vel.0 = -vel.0,
         }
     }
 }
 
-fn broom_input(
-    mouse_buttons: Res<ButtonInput<MouseButton>>,
-    keys: Res<ButtonInput<KeyCode>>,
-    bindings: Res<crate::settings::KeyBindings>,
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    player_query: Query<&Transform, (With<Player>, Without<Broom>)>,
-    broom_q: Query<Entity, (With<Broom>, Without<Player>)>,
-    q_window: Query<&bevy::window::Window, With<PrimaryWindow>>,
-    q_camera: Query<(&Camera, &GlobalTransform)>,
-) {
-    if !bindings.broom.just_pressed(&keys, &mouse_buttons) || !broom_q.is_empty() {
-        return;
-    }
-
-    let Some(player_tf) = player_query.iter().next() else { return };
-    let Ok(window) = q_window.single() else { return };
-    let Ok((camera, cam_transform)) = q_camera.single() else { return };
-    let Some(cursor_screen) = window.cursor_position() else { return };
-    let Ok(world_cursor) = camera.viewport_to_world_2d(cam_transform, cursor_screen) else { return };
-
-    let player_pos = player_tf.translation.truncate();
-    let to_mouse = world_cursor - player_pos;
-    let mouse_angle = to_mouse.y.atan2(to_mouse.x);
-
-    let broom_length = TILE_SIZE * 2.5;
-    let broom_width  = TILE_SIZE * 1.0;
-
-    let dir = Vec2::new(mouse_angle.cos(), mouse_angle.sin());
-    let broom_pos = player_tf.translation
-        + Vec3::new(dir.x * broom_length / 2.0, dir.y * broom_length / 2.0, 1.0);
-
-    let broom_image: Handle<Image> = asset_server.load("Broom.png");
+/// Spawns a swing towards `angle`. Charged swings are longer, sweep wider and
+/// hit harder; `BroomStats::reach` scales both kinds.
+fn spawn_broom(commands: &mut Commands, image: Handle<Image>, player_pos: Vec3, angle: f32, stats: &BroomStats, charged: bool) {
+    let (length, arc, time) = if charged {
+        (BROOM_LENGTH * CHARGED_LENGTH_MULT * stats.reach, CHARGED_ARC * stats.reach, CHARGED_SWING_TIME)
+    } else {
+        (BROOM_LENGTH * stats.reach, SWING_ARC * stats.reach, SWING_TIME)
+    };
+    let dir = Vec2::from_angle(angle);
+    let broom_pos = player_pos + (dir * length / 2.0).extend(1.0);
 
     commands.spawn((
         Sprite {
-            image: broom_image,
-            custom_size: Some(Vec2::new(broom_length, broom_width)),
+            image,
+            color: if charged { CHARGED_TINT } else { Color::WHITE },
+            custom_size: Some(Vec2::new(length, BROOM_WIDTH)),
             anchor: bevy::sprite::Anchor::CenterLeft,
             ..default()
         },
@@ -112,17 +161,58 @@ fn broom_input(
         },
         Broom,
         BroomSwing {
-            timer: Timer::from_seconds(0.25, TimerMode::Once),
+            timer: Timer::from_seconds(time, TimerMode::Once),
             active: true,
-            base_angle: mouse_angle,
+            base_angle: angle,
+            charged,
+            length,
+            arc,
+            hit: HashSet::new(),
         },
         // Collider kept for bullet-deflect size query; Collidable intentionally
         // omitted so the sweeping broom is NOT treated as a wall by collision systems.
-        Collider::from_size(Vec2::new(broom_length, broom_width)),
+        Collider::from_size(Vec2::new(length, BROOM_WIDTH)),
         GameEntity,
     ));
 }
 
+/// Tapping the broom binding swings straight away; holding it past
+/// `CHARGE_TIME` and letting go follows up with a charged sweep.
+fn broom_input(
+    time: Res<Time>,
+    mut charge: Local<f32>,
+    actions: Res<PlayerActions>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    player_query: Query<(&Transform, &BroomStats), (With<Player>, Without<Broom>)>,
+    broom_q: Query<Entity, (With<Broom>, Without<Player>)>,
+) {
+    let held = actions.pressed(Action::Broom);
+    let tapped = actions.just_pressed(Action::Broom) && broom_q.is_empty();
+    let charged = !held && *charge >= CHARGE_TIME;
+    if held {
+        *charge += time.delta_secs();
+    } else {
+        *charge = 0.0;
+    }
+    if !tapped && !charged {
+        return;
+    }
+
+    let Some((player_tf, stats)) = player_query.iter().next() else { return };
+    let Some(world_cursor) = actions.aim else { return };
+
+    let to_mouse = world_cursor - player_tf.translation.truncate();
+    let mouse_angle = to_mouse.y.atan2(to_mouse.x);
+
+    if charged {
+        for entity in &broom_q {
+            commands.entity(entity).despawn();
+        }
+    }
+    spawn_broom(&mut commands, asset_server.load("Broom.png"), player_tf.translation, mouse_angle, stats, charged);
+}
+
 
 fn broom_swing_system(
     time: Res<Time>,
@@ -135,15 +225,11 @@ fn broom_swing_system(
             swing.timer.tick(time.delta());
 
             if swing.active {
-                let broom_length = TILE_SIZE * 2.5;
-
-                let sweep = (-90.0_f32).to_radians()
-                    + (swing.timer.elapsed_secs() / swing.timer.duration().as_secs_f32())
-                    * (180.0_f32).to_radians();
+                let sweep = -swing.arc / 2.0 + swing.timer.fraction() * swing.arc;
 
                 broom_tf.rotation = Quat::from_rotation_z(swing.base_angle + sweep);
                 broom_tf.translation =
-                    player_tf.translation + broom_tf.rotation * Vec3::new(broom_length / 2.0, 0.0, 0.0);
+                    player_tf.translation + broom_tf.rotation * Vec3::new(swing.length / 2.0, 0.0, 0.0);
 
                 if swing.timer.finished() {
                     commands.entity(entity).despawn();
@@ -154,32 +240,62 @@ fn broom_swing_system(
 }
 
 
+/// Hits each enemy the broom touches once per swing: damage, knockback away
+/// from the player and, with the upgrade, a stun. The Reaper only takes the
+/// damage, and only while it's vulnerable.
 pub fn broom_hit_enemies_system(
-    mut enemies: Query<(&mut Health, &Transform), (With<Enemy>, Without<Broom>)>,
-    broom_query: Query<(&Transform, &Collider), (With<Broom>, Without<Enemy>)>,
+    mut enemies: Query<
+        (Entity, &mut crate::enemies::Health, &Transform, Option<&mut Velocity>, Option<&crate::status::StatusEffects>, Has<crate::enemies::Reaper>),
+        (With<Enemy>, Without<Broom>),
+    >,
+    mut broom_query: Query<(&Transform, &Collider, &mut BroomSwing), (With<Broom>, Without<Enemy>)>,
+    player_query: Query<(&Transform, &BroomStats), (With<Player>, Without<Enemy>, Without<Broom>)>,
+    mut statuses: EventWriter<crate::status::ApplyStatus>,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
+    reaper_state: Res<crate::enemies::reaper::ReaperState>,
 ) {
     let enemy_half = Vec2::splat(crate::enemies::ENEMY_SIZE * 0.5);
-    if let Some((broom_tf, broom_col)) = broom_query.iter().next() {
-        for (mut health, enemy_tf) in enemies.iter_mut() {
-            if aabb_overlap(
+    let Ok((player_tf, stats)) = player_query.single() else { return };
+    if let Some((broom_tf, broom_col, mut swing)) = broom_query.iter_mut().next() {
+        for (entity, mut health, enemy_tf, vel, status, is_reaper) in enemies.iter_mut() {
+            if !aabb_overlap(
                 broom_tf.translation.x,
                 broom_tf.translation.y,
                 broom_col.half_extents,
                 enemy_tf.translation.x,
                 enemy_tf.translation.y,
                 enemy_half,
-            ) {
-                health.0 -= 10.0;
+            ) || (is_reaper && !reaper_state.vulnerable) || !swing.hit.insert(entity) {
+                continue;
+            }
+
+            let (damage, knockback) = if swing.charged {
+                (CHARGED_DAMAGE, CHARGED_KNOCKBACK)
+            } else {
+                (SWING_DAMAGE, SWING_KNOCKBACK)
+            };
+            let dealt = damage * crate::status::damage_taken_multiplier(status);
+            health.0 -= dealt;
+            stat_events.write(crate::run_history::StatEvent::DamageDealt(crate::run_history::DamageSource::Broom, dealt));
+            if is_reaper {
+                continue;
+            }
+            if let Some(mut vel) = vel {
+                **vel += (enemy_tf.translation - player_tf.translation).truncate().normalize_or_zero() * knockback;
+            }
+            if stats.stun > 0.0 {
+                statuses.write(crate::status::ApplyStatus {
+                    target: entity,
+                    kind: crate::status::StatusKind::Stun,
+                    duration: stats.stun,
+                    stacks: 1,
+                });
             }
         }
     }
 }
 
 
-
-
-
-
 fn broom_push_tables_system(
     broom_query: Query<(&Transform, &Collider, &BroomSwing), With<Broom>>,
     player_query: Query<&Transform, With<Player>>,
@@ -219,7 +335,9 @@ fn broom_push_tables_system(
 pub fn broom_fix_window(
     mut window_query: Query<(&mut Health, &mut GlassState, &Transform, &crate::collidable::Collider), (With<Window>, Without<Broom>)>,
     broom_query: Query<(&Transform, &Collider), (With<Broom>, Without<Window>)>,
+    player_query: Query<&BroomStats, With<Player>>,
 ) {
+    let repair = player_query.single().map_or(BroomStats::default().repair, |s| s.repair);
     if let Some((broom_tf, broom_col)) = broom_query.iter().next() {
         for (mut health, state, window_tf, window_col) in window_query.iter_mut() {
             if aabb_overlap(
@@ -231,9 +349,85 @@ pub fn broom_fix_window(
                 window_col.half_extents,
             ) {
                 if *state == GlassState::Broken {
-                    health.0 += 20.0;
+                    health.0 += repair;
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn one_swing_hits_once() {
+        let mut app = App::new();
+        app.add_event::<crate::status::ApplyStatus>()
+            .add_event::<crate::run_history::StatEvent>()
+            .init_resource::<Time>()
+            .init_resource::<crate::enemies::reaper::ReaperState>()
+            .add_systems(Update, broom_hit_enemies_system);
+        app.world_mut().spawn((Player, Transform::default(), BroomStats::default()));
+        app.world_mut().spawn(swing());
+        let enemy = app.world_mut().spawn((
+            Enemy,
+            crate::enemies::Health(200.0),
+            Transform::from_xyz(BROOM_LENGTH / 2.0, 0.0, 0.0),
+        )).id();
+
+        // Overlapping for the whole swing still only lands one hit.
+        run_swing(&mut app);
+
+        assert_eq!(app.world().get::<crate::enemies::Health>(enemy).unwrap().0, 200.0 - SWING_DAMAGE);
+    }
+
+    #[test]
+    fn reaper_shrugs_off_the_broom_until_vulnerable() {
+        let mut app = App::new();
+        app.add_event::<crate::status::ApplyStatus>()
+            .add_event::<crate::run_history::StatEvent>()
+            .init_resource::<Time>()
+            .init_resource::<crate::enemies::reaper::ReaperState>()
+            .add_systems(Update, broom_hit_enemies_system);
+        app.world_mut().spawn((Player, Transform::default(), BroomStats::default()));
+        let reaper = app.world_mut().spawn((
+            Enemy,
+            crate::enemies::Reaper,
+            crate::enemies::Health(500.0),
+            Transform::from_xyz(BROOM_LENGTH / 2.0, 0.0, 0.0),
+        )).id();
+
+        app.world_mut().spawn(swing());
+        run_swing(&mut app);
+        assert_eq!(app.world().get::<crate::enemies::Health>(reaper).unwrap().0, 500.0);
+
+        app.world_mut().resource_mut::<crate::enemies::reaper::ReaperState>().vulnerable = true;
+        run_swing(&mut app);
+        assert!(app.world().get::<crate::enemies::Health>(reaper).unwrap().0 < 500.0);
+    }
+
+    fn swing() -> impl Bundle {
+        (
+            Broom,
+            Transform::from_xyz(BROOM_LENGTH / 2.0, 0.0, 0.0),
+            Collider::from_size(Vec2::new(BROOM_LENGTH, BROOM_WIDTH)),
+            BroomSwing {
+                timer: Timer::from_seconds(SWING_TIME, TimerMode::Once),
+                active: true,
+                base_angle: 0.0,
+                charged: false,
+                length: BROOM_LENGTH,
+                arc: SWING_ARC,
+                hit: HashSet::new(),
+            },
+        )
+    }
+
+    fn run_swing(app: &mut App) {
+        for _ in 0..15 {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        }
+    }
+}
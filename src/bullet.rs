@@ -1,17 +1,25 @@
 use crate::Player;
 
 use crate::player::{Health, MaxHealth, MoveSpeed, Shield};
-use crate::room::{LevelState, RoomVec};
-use crate::weapons::{BulletDamage, BulletRes, BeamRifleRes, WeaponInventory, WeaponSounds, SfxCooldown, fire_weapon};
+use crate::weapons::{BulletDamage, BulletMods, FireMode, WeaponInventory, WeaponRegistry, SfxCooldown, fire_weapon};
+use crate::status::{ApplyStatus, OnHitStatus, StatusEffects};
+use crate::replay::{Action, PlayerActions};
+use crate::run_history::{DamageSource, StatEvent};
 use crate::window;
 use crate::{GameState, TILE_SIZE};
 use crate::table;
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::prelude::*;
 use std::collections::HashSet;
 
 #[derive(Component)]
+#[require(PrevPosition)]
 pub struct Bullet;
 
+/// Where the bullet was before the last `move_bullets` step. Collision sweeps
+/// from here to the current position; `None` until the bullet has moved once.
+#[derive(Component, Default)]
+pub struct PrevPosition(pub Option<Vec2>);
+
 pub struct BulletPlugin;
 
 #[derive(Component)]
@@ -34,6 +42,17 @@ pub struct MarkedForDespawn;
 #[derive(Component)]
 pub struct Piercing(pub u32);
 
+/// Scales damage down with distance from where the bullet was fired.
+#[derive(Component)]
+pub struct DamageFalloff {
+    pub origin: Vec2,
+    pub falloff: crate::weapons::registry::Falloff,
+}
+
+/// Speed added to an enemy on hit, pushing it away from the bullet.
+#[derive(Component)]
+pub struct Knockback(pub f32);
+
 /// Tracks enemies already hit so a bullet can't hit the same one twice.
 #[derive(Component, Default)]
 pub struct HitEnemies(pub HashSet<Entity>);
@@ -41,13 +60,24 @@ pub struct HitEnemies(pub HashSet<Entity>);
 #[derive(Component, Deref, DerefMut)]
 pub struct Velocity(pub Vec2);
 
+/// The enemy that fired this bullet, so on-hit effects can be credited to it.
+#[derive(Component)]
+pub struct BulletSource(pub Entity);
+
 impl Plugin for BulletPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Update, shoot_bullet_on_click.run_if(in_state(GameState::Playing)).run_if(not(resource_exists::<crate::pause::IsPaused>))) // Mouse shooting
             .add_systems(Update, move_bullets.run_if(in_state(GameState::Playing)))
             .add_systems(
                 Update,
-                bullet_collision.run_if(in_state(GameState::Playing)),
+                pull_bullets_toward_breaches
+                    .before(move_bullets)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not(resource_exists::<crate::PlanetLevelMarker>)),
+            )
+            .add_systems(
+                Update,
+                bullet_collision.in_set(crate::enemies::EnemyDamageSet).run_if(in_state(GameState::Playing)),
             )
             .add_systems(
                 Last,
@@ -63,44 +93,44 @@ impl Plugin for BulletPlugin {
     }
 }
 
-fn cursor_to_world(cursor_pos: Vec2, camera: (&Camera, &GlobalTransform)) -> Option<Vec2> {
+pub fn cursor_to_world(cursor_pos: Vec2, camera: (&Camera, &GlobalTransform)) -> Option<Vec2> {
     camera.0.viewport_to_world_2d(camera.1, cursor_pos).ok()
 }
 
 // Mouse shooting - uses weapon stats from player
 pub fn shoot_bullet_on_click(
     mut commands: Commands,
-    keys: Res<ButtonInput<KeyCode>>,
-    buttons: Res<ButtonInput<MouseButton>>,
-    bindings: Res<crate::settings::KeyBindings>,
-    mut q_player: Query<(&Transform, &mut WeaponInventory), With<crate::player::Player>>,
-    q_window: Query<&Window, With<PrimaryWindow>>,
-    q_camera: Query<(&Camera, &GlobalTransform)>,
-    bullet_res: Res<BulletRes>,
-    beam_res: Res<BeamRifleRes>,
-    weapon_sounds: Res<WeaponSounds>,
+    actions: Res<PlayerActions>,
+    mut q_player: Query<(&Transform, &mut Velocity, &mut WeaponInventory), With<crate::player::Player>>,
+    registry: Res<WeaponRegistry>,
     mut sfx_cooldown: ResMut<SfxCooldown>,
+    time: Res<Time>,
+    mut rng: ResMut<crate::RunRng>,
 ) {
-    let Ok((player_transform, mut inventory)) = q_player.single_mut() else {
+    let Ok((player_transform, mut player_vel, mut inventory)) = q_player.single_mut() else {
         return;
     };
 
-    if bindings.shoot.pressed(&keys, &buttons) && inventory.current().can_shoot() {
-        let window = match q_window.single() {
-            Ok(win) => win,
-            Err(_) => return,
-        };
-
-        let Some(cursor_pos) = window.cursor_position() else {
-            return;
-        };
-
-        let (camera, cam_transform) = match q_camera.single() {
-            Ok(c) => c,
-            Err(_) => return,
-        };
+    let held = actions.pressed(Action::Fire);
+    let fire_mode = &registry.get_or_default(&inventory.current().id).def.fire_mode;
+    let fire = match *fire_mode {
+        // Charge while held, fire on release.
+        FireMode::Charge { max_time, .. } => {
+            let weapon = inventory.current_mut();
+            if held && weapon.can_shoot() {
+                weapon.charge = (weapon.charge + time.delta_secs()).min(max_time);
+                false
+            } else {
+                !held && weapon.charge > 0.0
+            }
+        }
+        // Beams fire continuously from `weapons::beam::fire_beam`.
+        FireMode::Beam { .. } => false,
+        _ => held && inventory.current().can_shoot(),
+    };
 
-        let Some(world_pos) = cursor_to_world(cursor_pos, (camera, cam_transform)) else {
+    if fire {
+        let Some(world_pos) = actions.aim else {
             return;
         };
 
@@ -113,23 +143,65 @@ pub fn shoot_bullet_on_click(
 
         let spawn_pos = player_pos + dir_vec * 16.0;
 
-        fire_weapon(
+        let recoil = fire_weapon(
             &mut commands,
             &mut inventory,
-            &bullet_res,
-            &beam_res,
-            &weapon_sounds,
+            &registry,
             &mut sfx_cooldown,
             spawn_pos,
             dir_vec,
+            world_pos,
+            &mut rng,
         );
+        **player_vel += recoil;
+    }
+}
+
+/// Sideways pull a breach puts on a bullet right at the hole, in px/s². Speed
+/// is kept, so bullets curve towards the hole rather than speeding up.
+const BULLET_SUCTION: f32 = 900.0;
+/// Bullets further than this from a breach don't feel it. The pull fades
+/// linearly out to here.
+const BULLET_SUCTION_RADIUS: f32 = TILE_SIZE * 4.0;
+
+/// Bends bullets passing close to a breach towards it while the room still
+/// has air venting, unless they're vacuum-proof.
+fn pull_bullets_toward_breaches(
+    time: Res<Time>,
+    rooms: Res<crate::room::RoomVec>,
+    mut bullet_q: Query<(&Transform, &mut Velocity, Option<&BulletMods>), (With<Bullet>, Without<MarkedForDespawn>)>,
+) {
+    if rooms.0.iter().all(|r| r.breaches.is_empty()) {
+        return;
+    }
+    for (transform, mut vel, mods) in &mut bullet_q {
+        if mods.is_some_and(|m| m.vacuum_proof) {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        let Some(room) = rooms.0.iter().find(|r| r.bounds_check(pos)) else { continue };
+        if room.air_pressure <= 0.0 {
+            continue;
+        }
+        let pull: Vec2 = room.breaches.iter()
+            .map(|&b| {
+                let to_breach = b - pos;
+                let falloff = (1.0 - to_breach.length() / BULLET_SUCTION_RADIUS).max(0.0);
+                to_breach.normalize_or_zero() * falloff
+            })
+            .sum();
+        if pull == Vec2::ZERO {
+            continue;
+        }
+        let speed = vel.0.length();
+        vel.0 = (vel.0 + pull * BULLET_SUCTION * time.delta_secs()).normalize_or_zero() * speed;
     }
 }
 
 pub fn move_bullets(
     mut commands: Commands,
     mut bullet_q: Query<
-        (Entity, &mut Transform, &Velocity),
+        (Entity, &mut Transform, &mut PrevPosition, &Velocity),
         (With<Bullet>, Without<MarkedForDespawn>),
     >,
     time: Res<Time>,
@@ -142,7 +214,8 @@ pub fn move_bullets(
         (4000.0_f32, 4000.0_f32)
     };
 
-    for (entity, mut transform, vel) in bullet_q.iter_mut() {
+    for (entity, mut transform, mut prev, vel) in bullet_q.iter_mut() {
+        prev.0 = Some(transform.translation.truncate());
         transform.translation += (vel.0 * time.delta_secs()).extend(0.0);
 
         let p = transform.translation;
@@ -167,165 +240,201 @@ fn animate_bullet(
     }
 }
 
+/// Something a bullet's swept path ran into this frame.
+enum BulletHit {
+    Enemy(Entity),
+    Player,
+    Table(Entity),
+    Window(Entity),
+    /// Wall tile centre and half-extents, for ricochet normals.
+    Wall(Vec2, Vec2),
+}
+
 pub fn bullet_collision(
     mut commands: Commands,
     mut bullet_query: Query<
-        (Entity, &Transform, &BulletOwner, &BulletDamage, Option<&mut Piercing>, Option<&mut HitEnemies>),
+        (Entity, &mut Transform, &mut PrevPosition, &mut Velocity, &BulletOwner, &BulletDamage, Option<&mut Piercing>, Option<&mut HitEnemies>, Option<&BulletSource>, Option<&OnHitStatus>, Option<&Knockback>, Option<&DamageFalloff>, Option<&mut BulletMods>, Option<&Sprite>),
         (With<Bullet>, Without<MarkedForDespawn>),
     >,
     mut enemy_query: Query<
-        (Entity, &Transform, &mut crate::enemies::Health, Option<&crate::collidable::Collider>),
-        (With<crate::enemies::Enemy>, Without<crate::enemies::Reaper>),
+        (Entity, &Transform, &mut crate::enemies::Health, Option<&crate::collidable::Collider>, Option<&StatusEffects>, Option<&mut crate::enemies::Velocity>, Has<crate::enemies::Reaper>),
+        With<crate::enemies::Enemy>,
     >,
     mut player_query: Query<
-        (&Transform, &mut Health, &mut MaxHealth, &mut MoveSpeed, &mut crate::player::Armor, &mut Shield),
+        (Entity, &Transform, &mut Health, &mut MaxHealth, &mut MoveSpeed, &mut crate::player::Armor, &mut Shield, Option<&StatusEffects>),
         With<Player>,
     >,
     mut table_query: Query<
-        (&Transform, &mut table::Health, &table::TableState),
+        (Entity, &Transform, &mut table::Health, &table::TableState),
         With<table::Table>,
     >,
     mut window_query: Query<
-        (&Transform, &mut window::Health, &window::GlassState),
+        (Entity, &Transform, &mut window::Health, &window::GlassState),
         With<window::Window>,
     >,
     wall_grid: Res<crate::map::WallGrid>,
-    lvlstate: Res<LevelState>,
-    rooms: Res<RoomVec>,
+    mut enemy_hits: EventWriter<crate::enemies::EnemyHitPlayerEvent>,
+    mut statuses: EventWriter<ApplyStatus>,
+    mut stat_events: EventWriter<StatEvent>,
+    reaper_state: Res<crate::enemies::reaper::ReaperState>,
 ) {
-    let bullet_half = Vec2::splat(8.0);
-
-    let Ok((player_tf, mut hp, _maxhp, _movspd, armor, mut shield)) = player_query.single_mut() else {
+    let Ok((player_entity, player_tf, mut hp, _maxhp, _movspd, armor, mut shield, player_status)) = player_query.single_mut() else {
         return;
     };
-
-    let _final_room = matches!(*lvlstate, LevelState::InRoom(_, _, _)) && rooms.0.len() == 1;
-
-    'bullet_loop: for (bullet_entity, bullet_tf, owner, damage, mut piercing, mut hit_enemies_opt) in &mut bullet_query {
-        let bullet_pos = bullet_tf.translation;
-
-        // Bullet hits enemy
-        if matches!(owner, BulletOwner::Player) {
-            if let Some(ref mut hit_enemies) = hit_enemies_opt {
-            for (enemy_entity, enemy_tf, mut health, collider_opt) in &mut enemy_query {
-                if hit_enemies.0.contains(&enemy_entity) {
-                    continue;
-                }
-                let enemy_pos = enemy_tf.translation;
-                let enemy_half = collider_opt
-                    .map(|c| c.half_extents)
-                    .unwrap_or_else(|| Vec2::splat(crate::enemies::ENEMY_SIZE * 0.5));
-                if aabb_overlap(
-                    bullet_pos.x,
-                    bullet_pos.y,
-                    bullet_half,
-                    enemy_pos.x,
-                    enemy_pos.y,
-                    enemy_half,
-                ) {
-                    hit_enemies.0.insert(enemy_entity);
-                    health.0 -= damage.0;
-                    match &mut piercing {
-                        None => {
-                            // Non-piercing bullet: stop on first hit
-                            commands.entity(bullet_entity).try_insert(MarkedForDespawn);
-                            continue 'bullet_loop;
-                        }
-                        Some(p) if p.0 == 0 => {
-                            // Used up all pierce slots: stop on this hit
-                            commands.entity(bullet_entity).try_insert(MarkedForDespawn);
-                            continue 'bullet_loop;
-                        }
-                        Some(p) => {
-                            // Consume one pierce slot and keep going
-                            p.0 -= 1;
-                        }
+    let player_pos = player_tf.translation.truncate();
+
+    let mut hits: Vec<(f32, BulletHit)> = Vec::new();
+    for (bullet_entity, mut bullet_tf, mut prev, mut bullet_vel, owner, damage, mut piercing, mut hit_enemies_opt, source, on_hit, knockback, falloff, mut mods, sprite) in &mut bullet_query {
+        // Sweep from last frame's position so fast bullets can't skip thin colliders.
+        let to = bullet_tf.translation.truncate();
+        let from = prev.0.unwrap_or(to);
+        let bullet_half = Vec2::splat(8.0 * mods.as_ref().map_or(1.0, |m| m.hitbox_scale));
+        let player_owned = matches!(owner, BulletOwner::Player);
+
+        hits.clear();
+        if player_owned {
+            if let Some(hit_enemies) = &hit_enemies_opt {
+                for (enemy_entity, enemy_tf, _, collider_opt, _, _, is_reaper) in &enemy_query {
+                    // Shots pass through the reaper until it's vulnerable.
+                    if hit_enemies.0.contains(&enemy_entity) || (is_reaper && !reaper_state.vulnerable) {
+                        continue;
+                    }
+                    let enemy_half = collider_opt
+                        .map(|c| c.half_extents)
+                        .unwrap_or_else(|| Vec2::splat(crate::enemies::ENEMY_SIZE * 0.5));
+                    if let Some(t) = sweep_aabb(from, to, bullet_half, enemy_tf.translation.truncate(), enemy_half) {
+                        hits.push((t, BulletHit::Enemy(enemy_entity)));
                     }
                 }
             }
-            } // if let Some(hit_enemies)
-        }
-
-        // Bullet hits player
-        if matches!(owner, BulletOwner::Enemy) {
-            let player_pos = player_tf.translation;
-            let player_half = Vec2::splat(TILE_SIZE);
-            if aabb_overlap(
-                bullet_pos.x,
-                bullet_pos.y,
-                bullet_half,
-                player_pos.x,
-                player_pos.y,
-                player_half,
-            ) {
-                if shield.current >= 1.0 {
-                    shield.current -= 1.0;
-                } else {
-                    hp.0 -= damage.0 * crate::player::armor_factor(armor.0);
-                }
-                commands.entity(bullet_entity).try_insert(MarkedForDespawn);
-                continue 'bullet_loop;
-            }
-        }
-
-        // Bullet hits table
-        if matches!(owner, BulletOwner::Player) {
-            for (table_tf, mut table_health, state) in &mut table_query {
+            for (table_entity, table_tf, _, state) in &table_query {
                 if *state != table::TableState::Intact {
                     continue;
                 }
-                let table_pos = table_tf.translation;
-                let table_half = Vec2::splat(TILE_SIZE * 0.5);
-                if aabb_overlap(
-                    bullet_pos.x,
-                    bullet_pos.y,
-                    bullet_half,
-                    table_pos.x,
-                    table_pos.y,
-                    table_half,
-                ) {
-                    table_health.0 -= damage.0; // Use bullet damage
-                    commands.entity(bullet_entity).try_insert(MarkedForDespawn);
-                    continue 'bullet_loop;
+                if let Some(t) = sweep_aabb(from, to, bullet_half, table_tf.translation.truncate(), Vec2::splat(TILE_SIZE * 0.5)) {
+                    hits.push((t, BulletHit::Table(table_entity)));
                 }
             }
-        }
-
-        // Bullet hits window
-        if matches!(owner, BulletOwner::Player) {
-            for (window_tf, mut window_health, state) in &mut window_query {
+            for (window_entity, window_tf, _, state) in &window_query {
                 if *state != window::GlassState::Intact {
                     continue;
                 }
-                let window_pos = window_tf.translation;
-                let window_half = Vec2::splat(TILE_SIZE * 0.5);
-                if aabb_overlap(
-                    bullet_pos.x,
-                    bullet_pos.y,
-                    bullet_half,
-                    window_pos.x,
-                    window_pos.y,
-                    window_half,
-                ) {
-                    window_health.0 -= damage.0; // Use bullet damage
-                    commands.entity(bullet_entity).try_insert(MarkedForDespawn);
-                    continue 'bullet_loop;
+                if let Some(t) = sweep_aabb(from, to, bullet_half, window_tf.translation.truncate(), Vec2::splat(TILE_SIZE * 0.5)) {
+                    hits.push((t, BulletHit::Window(window_entity)));
                 }
             }
+        } else if let Some(t) = sweep_aabb(from, to, bullet_half, player_pos, Vec2::splat(TILE_SIZE)) {
+            hits.push((t, BulletHit::Player));
         }
 
+        let radius = (from.distance(to) * 0.5 / wall_grid.cell_size).ceil() as i32 + 2;
+        if let Some((t, wall_pos, wall_half)) = wall_grid
+            .nearby(from.midpoint(to), radius)
+            .into_iter()
+            .filter_map(|(wall_pos, wall_half)| {
+                sweep_aabb(from, to, bullet_half, wall_pos, wall_half).map(|t| (t, wall_pos, wall_half))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+        {
+            hits.push((t, BulletHit::Wall(wall_pos, wall_half)));
+        }
 
-        for (wall_pos, wall_half) in wall_grid.nearby(bullet_pos.truncate(), 2) {
-            if aabb_overlap(
-                bullet_pos.x,
-                bullet_pos.y,
-                bullet_half,
-                wall_pos.x,
-                wall_pos.y,
-                wall_half,
-            ) {
-                commands.entity(bullet_entity).try_insert(MarkedForDespawn);
-                continue 'bullet_loop;
+        // Resolve in the order the bullet reaches them; anything but a pierced enemy stops it.
+        hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for &(t, ref hit) in &hits {
+            let hit_pos = from.lerp(to, t);
+            match *hit {
+                BulletHit::Enemy(enemy_entity) => {
+                    let Ok((_, enemy_tf, mut health, _, enemy_status, enemy_vel, is_reaper)) = enemy_query.get_mut(enemy_entity) else { continue };
+                    if let Some(hit_enemies) = &mut hit_enemies_opt {
+                        hit_enemies.0.insert(enemy_entity);
+                    }
+                    let falloff_mult = falloff.map_or(1.0, |f| f.falloff.multiplier(f.origin.distance(hit_pos)));
+                    let dealt = damage.0 * falloff_mult * crate::status::damage_taken_multiplier(enemy_status);
+                    health.0 -= dealt;
+                    stat_events.write(StatEvent::DamageDealt(DamageSource::Gun, dealt));
+                    // The reaper takes the damage but shrugs off statuses and knockback.
+                    if let Some(s) = on_hit.filter(|_| !is_reaper) {
+                        statuses.write(ApplyStatus { target: enemy_entity, kind: s.kind, duration: s.duration, stacks: s.stacks });
+                    }
+                    if let (Some(kb), Some(mut vel), false) = (knockback, enemy_vel, is_reaper) {
+                        **vel += (enemy_tf.translation.truncate() - hit_pos).normalize_or_zero() * kb.0;
+                    }
+                    if let (Some(m), Some(sprite)) = (mods.as_deref_mut(), sprite)
+                        && m.split
+                    {
+                        m.split = false;
+                        crate::weapons::mods::spawn_split_bullets(
+                            &mut commands, sprite, bullet_tf.scale.x, hit_pos, bullet_vel.0, damage.0, enemy_entity, *m, on_hit.copied(),
+                        );
+                    }
+                    match &mut piercing {
+                        Some(p) if p.0 > 0 => {
+                            // Consume one pierce slot and keep going
+                            p.0 -= 1;
+                        }
+                        _ => {
+                            // Non-piercing or out of pierce slots: stop on this hit
+                            commands.entity(bullet_entity).try_insert(MarkedForDespawn);
+                            break;
+                        }
+                    }
+                }
+                BulletHit::Player => {
+                    if shield.current >= 1.0 {
+                        shield.current -= 1.0;
+                    } else {
+                        let dealt = damage.0 * crate::player::armor_factor(armor.0)
+                            * crate::status::damage_taken_multiplier(player_status);
+                        hp.0 -= dealt;
+                        stat_events.write(StatEvent::DamageTaken(DamageSource::EnemyShot, dealt));
+                        if let Some(src) = source {
+                            enemy_hits.write(crate::enemies::EnemyHitPlayerEvent { enemy: src.0, damage: dealt });
+                        }
+                        if let Some(s) = on_hit {
+                            statuses.write(ApplyStatus { target: player_entity, kind: s.kind, duration: s.duration, stacks: s.stacks });
+                        }
+                    }
+                    commands.entity(bullet_entity).try_insert(MarkedForDespawn);
+                    break;
+                }
+                BulletHit::Table(table_entity) => {
+                    if let Ok((_, _, mut table_health, _)) = table_query.get_mut(table_entity) {
+                        table_health.0 -= damage.0; // Use bullet damage
+                    }
+                    commands.entity(bullet_entity).try_insert(MarkedForDespawn);
+                    break;
+                }
+                BulletHit::Window(window_entity) => {
+                    if let Ok((_, _, mut window_health, _)) = window_query.get_mut(window_entity) {
+                        window_health.0 -= damage.0; // Use bullet damage
+                    }
+                    commands.entity(bullet_entity).try_insert(MarkedForDespawn);
+                    break;
+                }
+                BulletHit::Wall(wall_pos, wall_half) => {
+                    if let Some(m) = mods.as_deref_mut()
+                        && m.ricochets_left > 0
+                    {
+                        // Reflect off whichever face the bullet came through and
+                        // restart the sweep from the contact point.
+                        m.ricochets_left -= 1;
+                        let d = (hit_pos - wall_pos) / (wall_half + bullet_half);
+                        let normal = if d.x.abs() > d.y.abs() {
+                            Vec2::new(d.x.signum(), 0.0)
+                        } else {
+                            Vec2::new(0.0, d.y.signum())
+                        };
+                        let v = bullet_vel.0;
+                        bullet_vel.0 = v - 2.0 * v.dot(normal) * normal;
+                        let bounce_pos = hit_pos + normal * 0.5;
+                        bullet_tf.translation = bounce_pos.extend(bullet_tf.translation.z);
+                        prev.0 = Some(bounce_pos);
+                        break;
+                    }
+                    commands.entity(bullet_entity).try_insert(MarkedForDespawn);
+                    break;
+                }
             }
         }
     }
@@ -350,3 +459,28 @@ pub fn aabb_overlap(ax: f32, ay: f32, a_half: Vec2, bx: f32, by: f32, b_half: Ve
     (ax - bx).abs() < (a_half.x + b_half.x) && (ay - by).abs() < (a_half.y + b_half.y)
 }
 
+/// Sweeps a box of `a_half` from `from` to `to` against a static box at `b`.
+/// Returns the fraction of the move (0..=1) at which they first overlap.
+pub fn sweep_aabb(from: Vec2, to: Vec2, a_half: Vec2, b: Vec2, b_half: Vec2) -> Option<f32> {
+    let half = a_half + b_half;
+    let delta = to - from;
+    let mut t_enter = 0.0_f32;
+    let mut t_exit = 1.0_f32;
+    for axis in 0..2 {
+        let (start, d, lo, hi) = (from[axis], delta[axis], b[axis] - half[axis], b[axis] + half[axis]);
+        if d.abs() < f32::EPSILON {
+            if start <= lo || start >= hi {
+                return None;
+            }
+        } else {
+            let (t0, t1) = ((lo - start) / d, (hi - start) / d);
+            t_enter = t_enter.max(t0.min(t1));
+            t_exit = t_exit.min(t0.max(t1));
+            if t_enter >= t_exit {
+                return None;
+            }
+        }
+    }
+    Some(t_enter)
+}
+
@@ -0,0 +1,377 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::enemies::{has_los, Enemy};
+use crate::map::{LevelRes, MapGridMeta, WallGrid};
+use crate::minimap::{coarse_cell, hallway_cells, MinimapVisible, VisitedCells};
+use crate::player::Player;
+use crate::replay::PlayerActions;
+use crate::room::{LevelState, RoomVec};
+use crate::settings::{CornerMapSettings, MapCorner};
+use crate::window::GlassState;
+use crate::{GameEntity, GameState, TILE_SIZE};
+
+/// Tiles visible across the corner map, whatever its pixel size.
+const VIEW_TILES: f32 = 36.0;
+/// Tiles per hallway fog cell; matches the full map.
+const HALLWAY_CELL: i32 = 3;
+/// Side of an enemy or breach blip, in pixels.
+const BLIP: f32 = 6.0;
+
+// Components
+
+/// The clipped square in the screen corner.
+#[derive(Component)]
+struct CornerMapRoot;
+
+/// Turns in rotating mode. Larger than the root so the corners stay filled.
+#[derive(Component)]
+struct CornerMapRotor;
+
+/// Holds the whole level; slides so the player stays in the middle.
+#[derive(Component)]
+struct CornerMapLayer;
+
+#[derive(Component)]
+struct CornerRoomNode(usize);
+
+#[derive(Component)]
+struct CornerHallwayNode(i32, i32);
+
+/// Pooled marker for an enemy or breach. Hidden when unused.
+#[derive(Component)]
+struct CornerMapBlip;
+
+// Plugin
+
+pub struct CornerMapPlugin;
+
+impl Plugin for CornerMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Playing), rebuild_corner_map)
+            .add_systems(
+                Update,
+                rebuild_corner_map
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(resource_changed::<CornerMapSettings>),
+            )
+            .add_systems(
+                Update,
+                (update_corner_map_view, update_corner_map_blips).chain()
+                    .after(rebuild_corner_map)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(|settings: Res<CornerMapSettings>| settings.corner != MapCorner::Off),
+            );
+    }
+}
+
+// Setup
+
+/// Corner-map pixels per world tile.
+fn pixels_per_tile(settings: &CornerMapSettings) -> f32 {
+    settings.size.px() / VIEW_TILES
+}
+
+/// World position → pixels inside the corner-map layer.
+fn world_to_layer(pos: Vec2, grid: &MapGridMeta, ppt: f32) -> Vec2 {
+    let map_px_w = grid.cols as f32 * TILE_SIZE;
+    let map_px_h = grid.rows as f32 * TILE_SIZE;
+    Vec2::new(
+        (pos.x + map_px_w * 0.5) / TILE_SIZE * ppt,
+        (map_px_h * 0.5 - pos.y) / TILE_SIZE * ppt,
+    )
+}
+
+fn faded(color: Color, opacity: f32) -> Color {
+    color.with_alpha(color.alpha() * opacity)
+}
+
+/// Spawns the corner map for the current settings, replacing any old one.
+fn rebuild_corner_map(
+    mut commands: Commands,
+    settings: Res<CornerMapSettings>,
+    rooms: Res<RoomVec>,
+    level: Res<LevelRes>,
+    grid: Res<MapGridMeta>,
+    existing: Query<Entity, With<CornerMapRoot>>,
+) {
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+    if settings.corner == MapCorner::Off {
+        return;
+    }
+
+    let size = settings.size.px();
+    let ppt = pixels_per_tile(&settings);
+    // Diagonal of the root, so a rotated rotor still covers every corner.
+    let rotor = size * std::f32::consts::SQRT_2;
+    let cell = HALLWAY_CELL as f32 * ppt;
+
+    // Offsets keep clear of the health/weapon HUD along the edges.
+    let (top, right, bottom, left) = match settings.corner {
+        MapCorner::TopRight    => (Val::Px(72.0),  Val::Px(10.0), Val::Auto,      Val::Auto),
+        MapCorner::BottomRight => (Val::Auto,      Val::Px(10.0), Val::Px(120.0), Val::Auto),
+        MapCorner::BottomLeft  => (Val::Auto,      Val::Auto,     Val::Px(120.0), Val::Px(10.0)),
+        MapCorner::TopLeft     => (Val::Px(120.0), Val::Auto,     Val::Auto,      Val::Px(10.0)),
+        MapCorner::Off => unreachable!(),
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top,
+                right,
+                bottom,
+                left,
+                width: Val::Px(size),
+                height: Val::Px(size),
+                border: UiRect::all(Val::Px(2.0)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(faded(Color::srgba(0.02, 0.02, 0.08, 0.85), settings.opacity)),
+            BorderColor(faded(Color::srgb(0.35, 0.45, 0.7), settings.opacity)),
+            ZIndex(40),
+            CornerMapRoot,
+            GameEntity,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px((size - rotor) * 0.5 - 2.0),
+                    top: Val::Px((size - rotor) * 0.5 - 2.0),
+                    width: Val::Px(rotor),
+                    height: Val::Px(rotor),
+                    ..default()
+                },
+                CornerMapRotor,
+            ))
+            .with_children(|rotor| {
+                rotor
+                    .spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            width: Val::Px(grid.cols as f32 * ppt),
+                            height: Val::Px(grid.rows as f32 * ppt),
+                            ..default()
+                        },
+                        CornerMapLayer,
+                    ))
+                    .with_children(|layer| {
+                        for (i, room) in rooms.0.iter().enumerate() {
+                            let at = world_to_layer(room.top_left_corner, &grid, ppt);
+                            let far = world_to_layer(room.bot_right_corner, &grid, ppt);
+                            layer.spawn((
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Val::Px(at.x),
+                                    top: Val::Px(at.y),
+                                    width: Val::Px((far.x - at.x).abs().max(3.0)),
+                                    height: Val::Px((far.y - at.y).abs().max(3.0)),
+                                    border: UiRect::all(Val::Px(1.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::NONE),
+                                BorderColor(Color::NONE),
+                                CornerRoomNode(i),
+                            ));
+                        }
+
+                        for (cell_col, cell_row) in hallway_cells(&rooms, &level, &grid) {
+                            let tile_col = (cell_col * HALLWAY_CELL + HALLWAY_CELL / 2) as f32;
+                            let tile_row = (cell_row * HALLWAY_CELL + HALLWAY_CELL / 2) as f32;
+                            layer.spawn((
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Val::Px(tile_col * ppt - cell * 0.5),
+                                    top: Val::Px(tile_row * ppt - cell * 0.5),
+                                    width: Val::Px(cell),
+                                    height: Val::Px(cell),
+                                    ..default()
+                                },
+                                BackgroundColor(faded(Color::srgba(0.30, 0.30, 0.45, 0.85), settings.opacity)),
+                                Visibility::Hidden,
+                                CornerHallwayNode(cell_col, cell_row),
+                            ));
+                        }
+                    });
+            });
+
+            // The player sits still in the middle; the map moves around them.
+            root.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(size * 0.5 - 5.0),
+                    top: Val::Px(size * 0.5 - 5.0),
+                    width: Val::Px(6.0),
+                    height: Val::Px(6.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(1.0, 1.0, 0.0)),
+                ZIndex(2),
+            ));
+        });
+}
+
+// Systems
+
+/// Keeps the player centred, turns the map in rotating mode and reveals fog.
+fn update_corner_map_view(
+    settings: Res<CornerMapSettings>,
+    rooms: Res<RoomVec>,
+    grid: Res<MapGridMeta>,
+    lvlstate: Res<LevelState>,
+    visited: Res<VisitedCells>,
+    full_map: Res<MinimapVisible>,
+    actions: Res<PlayerActions>,
+    player_q: Query<&Transform, With<Player>>,
+    mut root_q: Query<&mut Visibility, (With<CornerMapRoot>, Without<CornerHallwayNode>)>,
+    mut rotor_q: Query<&mut Transform, (With<CornerMapRotor>, Without<Player>)>,
+    mut layer_q: Query<&mut Node, With<CornerMapLayer>>,
+    mut room_nodes: Query<(&CornerRoomNode, &mut BackgroundColor, &mut BorderColor)>,
+    mut hallway_nodes: Query<(&CornerHallwayNode, &mut Visibility), Without<CornerMapRoot>>,
+    fresh_hallways: Query<(), Added<CornerHallwayNode>>,
+    mut heading: Local<f32>,
+    mut revealed: Local<usize>,
+) {
+    let Ok(mut root_vis) = root_q.single_mut() else { return };
+    // The full map already covers the screen.
+    *root_vis = if full_map.0 { Visibility::Hidden } else { Visibility::Inherited };
+    if full_map.0 { return; }
+
+    let Ok(player_tf) = player_q.single() else { return };
+    let player = player_tf.translation.truncate();
+    let ppt = pixels_per_tile(&settings);
+
+    if let Ok(mut layer) = layer_q.single_mut() {
+        let centre = settings.size.px() * std::f32::consts::SQRT_2 * 0.5;
+        let at = world_to_layer(player, &grid, ppt);
+        layer.left = Val::Px(centre - at.x);
+        layer.top = Val::Px(centre - at.y);
+    }
+
+    if let Ok(mut rotor_tf) = rotor_q.single_mut() {
+
+        if settings.rotating {
+            if let Some(aim) = actions.aim {
+                let dir = aim - player;
+                if dir.length_squared() > 1.0 {
+                    *heading = dir.y.atan2(dir.x);
+                }
+            }
+            rotor_tf.rotation = Quat::from_rotation_z(*heading - std::f32::consts::FRAC_PI_2);
+        } else {
+            rotor_tf.rotation = Quat::IDENTITY;
+        }
+    }
+
+    let current_room = match *lvlstate {
+        LevelState::InRoom(i, _, _) | LevelState::EnteredRoom(i) => Some(i),
+        LevelState::NotRoom => None,
+    };
+    for (node, mut bg, mut border) in &mut room_nodes {
+        let Some(room) = rooms.0.get(node.0) else { continue };
+        let low_air = room.visited && room.air_pressure < crate::room::LOW_AIR_PRESSURE;
+        border.0 = if low_air { faded(Color::srgb(0.9, 0.2, 0.2), settings.opacity) } else { Color::NONE };
+        let color = if current_room == Some(node.0) {
+            Color::srgba(0.55, 0.5, 0.15, 0.9)
+        } else if !room.visited {
+            Color::NONE
+        } else if room.cleared {
+            Color::srgba(0.15, 0.5, 0.15, 0.85)
+        } else {
+            Color::srgba(0.35, 0.35, 0.55, 0.85)
+        };
+        bg.0 = faded(color, settings.opacity);
+    }
+
+    // Fog only changes when cells are added, or when a rebuild spawns fresh hidden nodes.
+    if visited.0.len() != *revealed || !fresh_hallways.is_empty() {
+        for (node, mut vis) in &mut hallway_nodes {
+            if visited.0.contains(&(node.0, node.1)) {
+                *vis = Visibility::Inherited;
+            }
+        }
+        *revealed = visited.0.len();
+    }
+}
+
+/// Enemies the player can see and discovered breaches, drawn as blips.
+fn update_corner_map_blips(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<CornerMapSettings>,
+    grid: Res<MapGridMeta>,
+    wall_grid: Res<WallGrid>,
+    rooms: Res<RoomVec>,
+    visited: Res<VisitedCells>,
+    full_map: Res<MinimapVisible>,
+    player_q: Query<&Transform, With<Player>>,
+    enemies: Query<&Transform, With<Enemy>>,
+    windows: Query<(&Transform, &GlassState), With<crate::window::Window>>,
+    layer_q: Query<Entity, With<CornerMapLayer>>,
+    mut blips: Query<(&mut Node, &mut BackgroundColor, &mut Visibility), With<CornerMapBlip>>,
+) {
+    if full_map.0 { return; }
+    let Ok(layer) = layer_q.single() else { return };
+    let Ok(player_tf) = player_q.single() else { return };
+    let player = player_tf.translation.truncate();
+    let ppt = pixels_per_tile(&settings);
+    let range = VIEW_TILES * 0.5 * TILE_SIZE;
+    let player_tile = wall_grid.world_to_tile(player);
+    let no_blockers = HashSet::new();
+
+    let mut marks: Vec<(Vec2, Color)> = enemies
+        .iter()
+        .map(|t| t.translation.truncate())
+        .filter(|p| p.distance(player) <= range)
+        .filter(|p| has_los(player_tile, wall_grid.world_to_tile(*p), &wall_grid, &no_blockers))
+        .map(|p| (p, Color::srgb(1.0, 0.25, 0.25)))
+        .collect();
+
+    let pulse = 0.55 + 0.45 * (time.elapsed_secs() * 6.0).sin().abs();
+    marks.extend(
+        windows.iter()
+            .filter(|(_, glass)| **glass == GlassState::Broken)
+            .map(|(t, _)| t.translation.truncate())
+            .filter(|p| {
+                visited.0.contains(&coarse_cell(*p, &grid))
+                    || rooms.0.iter().any(|r| r.visited && r.bounds_check(*p))
+            })
+            .map(|p| (p, Color::srgba(0.3, 0.8, 1.0, pulse))),
+    );
+
+    let mut pool = blips.iter_mut();
+    for (pos, color) in marks {
+        let at = world_to_layer(pos, &grid, ppt) - Vec2::splat(BLIP * 0.5);
+        let color = faded(color, settings.opacity.max(0.6));
+        match pool.next() {
+            Some((mut node, mut bg, mut vis)) => {
+                node.left = Val::Px(at.x);
+                node.top = Val::Px(at.y);
+                bg.0 = color;
+                *vis = Visibility::Inherited;
+            }
+            None => {
+                commands.entity(layer).with_child((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(at.x),
+                        top: Val::Px(at.y),
+                        width: Val::Px(BLIP),
+                        height: Val::Px(BLIP),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                    ZIndex(1),
+                    CornerMapBlip,
+                ));
+            }
+        }
+    }
+    for (_, _, mut vis) in pool {
+        *vis = Visibility::Hidden;
+    }
+}
@@ -1,34 +1,65 @@
+use bevy::log::tracing::{self, field::{Field, Visit}};
+use bevy::log::tracing_subscriber::{Layer, layer::Context};
+use bevy::log::BoxedLayer;
+use bevy::prelude::*;
+use bevy::render::renderer::RenderAdapterInfo;
+use std::collections::VecDeque;
+use std::fmt::Write as _;
 use std::path::PathBuf;
+use std::sync::Mutex;
+use crate::{GameState, PlanetCount, RunSeed, StationLevel, FONT_PATH};
+use crate::locale::{tr, tr_args};
 
-fn log_path() -> PathBuf {
-    dirs::config_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("cleanup_crew")
-        .join("crash.log")
+// ── Crash reports ────────────────────────────────────────────────────────────
+//
+// The panic hook can't reach into the ECS, so the game copies what a report
+// needs into statics as it runs: a snapshot of the run each frame, the GPU
+// adapter once at startup, and the most recent log lines via a tracing layer.
+// Each crash writes its own report under crashes/ and leaves a marker so the
+// next launch can say so on the menu.
+
+/// How many recent log lines each report includes.
+const LOG_LINES: usize = 60;
+
+fn crash_dir() -> PathBuf {
+    crate::settings::config_dir().join("crashes")
+}
+
+/// Holds the path of a report nobody has been told about yet.
+fn unseen_marker() -> PathBuf {
+    crash_dir().join("unseen")
 }
 
-/// Installs a panic hook that appends crash info to the log file before
-/// re-running the default hook (so the program still terminates normally).
+/// What the game was doing, refreshed every frame.
+#[derive(Clone, Default)]
+struct CrashContext {
+    state: Option<GameState>,
+    station_level: u32,
+    planet_count: u32,
+    seed: u64,
+    room: Option<usize>,
+    entities: u32,
+    enemies: usize,
+    bullets: usize,
+}
+
+static CONTEXT: Mutex<Option<CrashContext>> = Mutex::new(None);
+static ADAPTER: Mutex<Option<String>> = Mutex::new(None);
+static LOG_RING: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+/// Installs a panic hook that writes a crash report before re-running the
+/// default hook (so the program still terminates normally).
 pub fn install() {
     let prev = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
-        let _ = write_entry(info);
+        let _ = write_report(info);
         prev(info);
     }));
 }
 
-fn write_entry(info: &std::panic::PanicHookInfo) -> std::io::Result<()> {
-    use std::io::Write;
-
-    let path = log_path();
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)?;
-    }
-
-    let mut file = std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&path)?;
+fn write_report(info: &std::panic::PanicHookInfo) -> std::io::Result<()> {
+    let dir = crash_dir();
+    std::fs::create_dir_all(&dir)?;
 
     let secs = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -48,10 +79,282 @@ fn write_entry(info: &std::panic::PanicHookInfo) -> std::io::Result<()> {
         "(non-string panic payload)".to_string()
     };
 
-    writeln!(file, "--- CRASH [unix={secs}] ---")?;
-    writeln!(file, "Location : {location}")?;
-    writeln!(file, "Message  : {message}")?;
-    writeln!(file)?;
+    let thread = std::thread::current();
+    let mut out = String::new();
+    let _ = writeln!(out, "--- CRASH [unix={secs}] ---");
+    let _ = writeln!(out, "Version  : {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(out, "OS       : {} {} ({})", std::env::consts::OS, std::env::consts::ARCH, std::env::consts::FAMILY);
+    let _ = writeln!(out, "GPU      : {}", peek(&ADAPTER).flatten().unwrap_or_else(|| "unknown".to_string()));
+    let _ = writeln!(out, "Thread   : {}", thread.name().unwrap_or("unnamed"));
+    let _ = writeln!(out, "Location : {location}");
+    let _ = writeln!(out, "Message  : {message}");
+    let _ = writeln!(out);
+
+    // The panicking thread may be the one holding a lock; never wait on it.
+    match peek(&CONTEXT).flatten() {
+        Some(ctx) => {
+            let state = ctx.state.map(|s| format!("{s:?}")).unwrap_or_else(|| "(not started)".to_string());
+            let room = ctx.room.map(|r| r.to_string()).unwrap_or_else(|| "none".to_string());
+            let _ = writeln!(out, "State    : {state}");
+            let _ = writeln!(out, "Station  : {}", ctx.station_level + 1);
+            let _ = writeln!(out, "Planets  : {} cleared", ctx.planet_count);
+            let _ = writeln!(out, "Seed     : {:016x}", ctx.seed);
+            let _ = writeln!(out, "Room     : {room}");
+            let _ = writeln!(out, "Entities : {} ({} enemies, {} bullets)", ctx.entities, ctx.enemies, ctx.bullets);
+        }
+        None => {
+            let _ = writeln!(out, "State    : unavailable");
+        }
+    }
+    let _ = writeln!(out);
+
+    let _ = writeln!(out, "--- Last {LOG_LINES} log lines ---");
+    for line in peek(&LOG_RING).unwrap_or_default() {
+        let _ = writeln!(out, "{line}");
+    }
+
+    let path = dir.join(format!("crash-{secs}.txt"));
+    std::fs::write(&path, out)?;
+    std::fs::write(unseen_marker(), path.to_string_lossy().as_bytes())
+}
+
+/// Clones a static without blocking, recovering the value from a poisoned lock.
+fn peek<T: Clone>(m: &Mutex<T>) -> Option<T> {
+    match m.try_lock() {
+        Ok(v) => Some(v.clone()),
+        Err(std::sync::TryLockError::Poisoned(e)) => Some(e.into_inner().clone()),
+        Err(std::sync::TryLockError::WouldBlock) => None,
+    }
+}
+
+// ── Log ring buffer ──────────────────────────────────────────────────────────
+
+/// For `LogPlugin::custom_layer`: keeps the last `LOG_LINES` log events.
+pub fn log_layer(_app: &mut App) -> Option<BoxedLayer> {
+    Some(Box::new(LogRingLayer))
+}
+
+struct LogRingLayer;
+
+impl<S: tracing::Subscriber> Layer<S> for LogRingLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let meta = event.metadata();
+        let mut line = format!("{:>5} {}:", meta.level(), meta.target());
+        event.record(&mut LineVisitor(&mut line));
+
+        let Ok(mut ring) = LOG_RING.lock() else { return };
+        if ring.len() == LOG_LINES {
+            ring.pop_front();
+        }
+        ring.push_back(line);
+    }
+}
+
+struct LineVisitor<'a>(&'a mut String);
+
+impl Visit for LineVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.0, " {value:?}");
+        } else {
+            let _ = write!(self.0, " {}={value:?}", field.name());
+        }
+    }
+}
+
+// ── Context snapshot ─────────────────────────────────────────────────────────
+
+fn record_adapter(adapter: Option<Res<RenderAdapterInfo>>) {
+    let Some(adapter) = adapter else { return };
+    let desc = format!("{} ({:?}, driver {} {})", adapter.name, adapter.backend, adapter.driver, adapter.driver_info);
+    if let Ok(mut slot) = ADAPTER.lock() {
+        *slot = Some(desc);
+    }
+}
+
+fn record_context(
+    state: Res<State<GameState>>,
+    station_level: Res<StationLevel>,
+    planet_count: Res<PlanetCount>,
+    seed: Res<RunSeed>,
+    level_state: Option<Res<crate::room::LevelState>>,
+    entities: &bevy::ecs::entity::Entities,
+    enemies: Query<(), With<crate::enemies::Enemy>>,
+    bullets: Query<(), With<crate::bullet::Bullet>>,
+) {
+    let room = level_state.and_then(|ls| match *ls {
+        crate::room::LevelState::EnteredRoom(i) | crate::room::LevelState::InRoom(i, _, _) => Some(i),
+        crate::room::LevelState::NotRoom => None,
+    });
+    let ctx = CrashContext {
+        state: Some(*state.get()),
+        station_level: station_level.0,
+        planet_count: planet_count.0,
+        seed: seed.0,
+        room,
+        entities: entities.len(),
+        enemies: enemies.iter().count(),
+        bullets: bullets.iter().count(),
+    };
+    if let Ok(mut slot) = CONTEXT.lock() {
+        *slot = Some(ctx);
+    }
+}
+
+// ── Crashed-last-time notice ─────────────────────────────────────────────────
+
+/// The report left by the previous launch, until the player dismisses it.
+#[derive(Resource)]
+pub struct PreviousCrash(pub PathBuf);
+
+#[derive(Component)]
+struct CrashNotice;
+
+#[derive(Component)]
+enum CrashNoticeButton {
+    OpenFolder,
+    Dismiss,
+}
+
+/// Takes the marker left by a crash, if any, so it's only reported once.
+fn take_previous_crash() -> Option<PreviousCrash> {
+    let marker = unseen_marker();
+    let path = std::fs::read_to_string(&marker).ok()?;
+    let _ = std::fs::remove_file(&marker);
+    Some(PreviousCrash(PathBuf::from(path.trim())))
+}
+
+fn show_crash_notice(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    crash: Res<PreviousCrash>,
+) {
+    let font: Handle<Font> = assets.load(FONT_PATH);
+    let file = crash.0.file_name().map(|f| f.to_string_lossy().into_owned()).unwrap_or_default();
 
-    Ok(())
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                bottom: Val::Px(24.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ZIndex(500),
+            CrashNotice,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    padding: UiRect::axes(Val::Px(18.0), Val::Px(12.0)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.3, 0.05, 0.05, 0.92)),
+                BorderColor(Color::srgba(1.0, 0.35, 0.3, 0.9)),
+                BorderRadius::all(Val::Px(6.0)),
+            ))
+            .with_children(|panel| {
+                panel.spawn((
+                    Text::new(tr("crash.title")),
+                    TextFont { font: font.clone(), font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+                panel.spawn((
+                    Text::new(tr_args("crash.saved", &[("file", &file)])),
+                    TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                    TextColor(Color::srgb(1.0, 0.8, 0.75)),
+                ));
+                panel
+                    .spawn(Node { column_gap: Val::Px(12.0), ..default() })
+                    .with_children(|row| {
+                        for (label, which) in [
+                            ("crash.open_folder", CrashNoticeButton::OpenFolder),
+                            ("crash.dismiss", CrashNoticeButton::Dismiss),
+                        ] {
+                            row.spawn((
+                                Button,
+                                which,
+                                Node {
+                                    padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgba(0.15, 0.02, 0.02, 0.9)),
+                                BorderColor(Color::srgba(1.0, 0.35, 0.3, 0.6)),
+                                BorderRadius::all(Val::Px(4.0)),
+                            ))
+                            .with_children(|b| {
+                                b.spawn((
+                                    Text::new(tr(label)),
+                                    TextFont { font: font.clone(), font_size: 16.0, ..default() },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                        }
+                    });
+            });
+        });
+}
+
+fn handle_crash_notice_buttons(
+    mut commands: Commands,
+    interactions: Query<(&Interaction, &CrashNoticeButton), (Changed<Interaction>, With<Button>)>,
+    notice_q: Query<Entity, With<CrashNotice>>,
+    crash: Res<PreviousCrash>,
+) {
+    for (interaction, which) in &interactions {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let CrashNoticeButton::OpenFolder = which {
+            let dir = crash.0.parent().map(PathBuf::from).unwrap_or_else(crash_dir);
+            if let Err(e) = open_folder(&dir) {
+                warn!("Could not open '{}': {e}", dir.display());
+            }
+        }
+        for e in &notice_q {
+            commands.entity(e).despawn();
+        }
+        commands.remove_resource::<PreviousCrash>();
+    }
+}
+
+fn open_folder(dir: &std::path::Path) -> std::io::Result<()> {
+    let program = if cfg!(target_os = "windows") {
+        "explorer"
+    } else if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    std::process::Command::new(program).arg(dir).spawn().map(|_| ())
+}
+
+fn cleanup_crash_notice(mut commands: Commands, notice_q: Query<Entity, With<CrashNotice>>) {
+    for e in &notice_q {
+        commands.entity(e).despawn();
+    }
+}
+
+// ── Plugin ───────────────────────────────────────────────────────────────────
+
+pub struct CrashLogPlugin;
+
+impl Plugin for CrashLogPlugin {
+    fn build(&self, app: &mut App) {
+        if let Some(crash) = take_previous_crash() {
+            app.insert_resource(crash);
+        }
+        app.add_systems(Startup, record_adapter)
+            .add_systems(Last, record_context)
+            .add_systems(
+                OnEnter(GameState::Menu),
+                show_crash_notice.run_if(resource_exists::<PreviousCrash>),
+            )
+            .add_systems(Update, handle_crash_notice_buttons.run_if(resource_exists::<PreviousCrash>))
+            .add_systems(OnExit(GameState::Menu), cleanup_crash_notice);
+    }
 }
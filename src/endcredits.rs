@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
 use crate::GameState;
+use crate::locale::tr;
 
 #[derive(Component)]
 struct SlideTimer(Timer);
@@ -89,7 +90,7 @@ fn start_slide(
         ))
         .with_children(|b| {
             b.spawn((
-                Text::new("Back to Menu"),
+                Text::new(tr("credits.back")),
                 TextFont { font_size: 22.0, ..default() },
                 TextColor(Color::WHITE),
             ));
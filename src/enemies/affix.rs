@@ -0,0 +1,519 @@
+use bevy::prelude::*;
+use rand::{Rng, random_range};
+use std::f32::consts::TAU;
+
+use crate::{GameEntity, GameState, RunRng};
+use crate::fluiddynamics::PulledByFluid;
+use crate::player::{Armor, DashInvincibility, Player, Shield};
+use crate::table;
+use crate::window;
+use super::{
+    Enemy, EnemyDamageSet, EnemyMoveSpeed, EnemyRes, Health, MaxHealth, Velocity,
+    BAR_HEIGHT, BAR_WIDTH, BAR_Y_OFFSET,
+};
+use super::ranger::RangedEnemyAI;
+use super::turret::TurretAI;
+
+// ── Affix definitions ──────────────────────────────────────────────────────
+
+/// Elite modifier rolled onto a regular enemy at spawn. Any archetype can carry
+/// any combination; effects stack on top of the station health multiplier.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Affix {
+    /// Regenerating shield layer that soaks damage before health.
+    Shielded,
+    /// Explodes on death, hurting the player and flinging nearby tables.
+    Volatile,
+    /// Ignores breach suction (`PulledByFluid` is removed).
+    Anchored,
+    /// Splits into two smaller chasers on death.
+    Splitter,
+    /// Moves and fires faster.
+    Hasted,
+    /// Heals itself for a share of the damage it deals to the player.
+    Vampiric,
+}
+
+impl Affix {
+    pub const ALL: [Affix; 6] = [
+        Affix::Shielded,
+        Affix::Volatile,
+        Affix::Anchored,
+        Affix::Splitter,
+        Affix::Hasted,
+        Affix::Vampiric,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Affix::Shielded => "Shielded",
+            Affix::Volatile => "Volatile",
+            Affix::Anchored => "Anchored",
+            Affix::Splitter => "Splitter",
+            Affix::Hasted   => "Hasted",
+            Affix::Vampiric => "Vampiric",
+        }
+    }
+
+    /// Colour used for the sprite tint, aura and health-bar pip.
+    pub fn color(self) -> Color {
+        match self {
+            Affix::Shielded => Color::srgb(0.35, 0.75, 1.0),
+            Affix::Volatile => Color::srgb(1.0, 0.45, 0.1),
+            Affix::Anchored => Color::srgb(0.6, 0.6, 0.65),
+            Affix::Splitter => Color::srgb(0.55, 1.0, 0.35),
+            Affix::Hasted   => Color::srgb(1.0, 0.95, 0.3),
+            Affix::Vampiric => Color::srgb(0.8, 0.1, 0.35),
+        }
+    }
+}
+
+/// The affixes an elite enemy carries. Inserted right after spawn; `init_elites`
+/// applies the stat changes and visuals the frame it is added.
+#[derive(Component, Clone)]
+pub struct Affixes(pub Vec<Affix>);
+
+impl Affixes {
+    pub fn has(&self, affix: Affix) -> bool {
+        self.0.contains(&affix)
+    }
+}
+
+/// Shield layer for `Affix::Shielded`. Absorbs damage before health and
+/// regenerates after a short delay without being hit.
+#[derive(Component)]
+pub struct AffixShield {
+    pub current: f32,
+    pub max: f32,
+    /// Health seen last frame — any drop since then is treated as incoming damage.
+    last_health: f32,
+    regen_delay: Timer,
+}
+
+#[derive(Component)]
+struct AffixAura {
+    color: Color,
+}
+
+/// Marker on the thin shield strip drawn above an elite's health bar.
+#[derive(Component)]
+struct AffixShieldBarFg;
+
+// ── Events ─────────────────────────────────────────────────────────────────
+
+/// Written by `check_enemy_health` when an elite dies so its death effects
+/// (explosion, split, bonus drop) can run after the entity is gone.
+#[derive(Event)]
+pub struct EliteDeathEvent {
+    pub pos: Vec3,
+    pub max_health: f32,
+    pub affixes: Vec<Affix>,
+}
+
+// ── Plugin ─────────────────────────────────────────────────────────────────
+
+pub struct AffixPlugin;
+
+impl Plugin for AffixPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<EliteDeathEvent>()
+            .add_systems(
+                Update,
+                (
+                    init_elites,
+                    animate_auras,
+                    absorb_shield_damage
+                        .after(init_elites)
+                        .after(EnemyDamageSet)
+                        .before(super::check_enemy_health),
+                    vampiric_heal.after(absorb_shield_damage),
+                    handle_elite_deaths.after(super::check_enemy_health),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+// ── Tuning ─────────────────────────────────────────────────────────────────
+
+const SHIELD_FRACTION: f32 = 0.5;
+const SHIELD_REGEN_DELAY: f32 = 3.0;
+const SHIELD_REGEN_RATE: f32 = 0.2; // fraction of max per second
+const HASTE_SPEED_MULT: f32 = 1.45;
+const HASTE_FIRE_MULT: f32 = 0.7;
+const VAMPIRIC_LIFESTEAL: f32 = 1.5;
+const VOLATILE_RADIUS: f32 = 110.0;
+const VOLATILE_DAMAGE: f32 = 25.0;
+const VOLATILE_WINDOW_DAMAGE: f32 = 30.0;
+const VOLATILE_PUSH: f32 = 600.0;
+const SPLIT_HEALTH_FRACTION: f32 = 0.35;
+const SPLIT_SCALE: f32 = 0.7;
+/// Extra reward-drop chance contributed by each affix on an elite.
+const DROP_CHANCE_PER_AFFIX: f32 = 0.2;
+const PIP_SIZE: f32 = 5.0;
+
+// ── Rolling ────────────────────────────────────────────────────────────────
+
+/// Rolls the affix list for a freshly spawned enemy. Most enemies roll none;
+/// the elite chance and maximum affix count grow with station depth.
+pub fn roll_affixes(station_level: u32, rooms_cleared: usize, rng: &mut RunRng) -> Vec<Affix> {
+    let elite_chance = (0.08 + station_level as f32 * 0.04 + rooms_cleared as f32 * 0.01).min(0.5);
+    if rng.random::<f32>() >= elite_chance {
+        return Vec::new();
+    }
+
+    let max_affixes = (1 + station_level as usize / 2).min(3);
+    let count = rng.random_range(1..=max_affixes);
+
+    let mut pool = Affix::ALL.to_vec();
+    let mut rolled = Vec::with_capacity(count);
+    for _ in 0..count {
+        let affix = pool.swap_remove(rng.random_range(0..pool.len()));
+        rolled.push(affix);
+    }
+    rolled
+}
+
+/// Blends the colours of all affixes into a single sprite tint.
+fn blended_tint(affixes: &[Affix]) -> Color {
+    let sum = affixes.iter().fold(Vec3::ZERO, |acc, a| {
+        let c = a.color().to_srgba();
+        acc + Vec3::new(c.red, c.green, c.blue)
+    });
+    let avg = sum / affixes.len().max(1) as f32;
+    // Lerp toward white so the underlying sprite stays readable.
+    let t = avg * 0.6 + Vec3::splat(0.4);
+    Color::srgb(t.x, t.y, t.z)
+}
+
+// ── Systems ────────────────────────────────────────────────────────────────
+
+/// Applies stat changes and visuals to enemies whose `Affixes` were just inserted.
+fn init_elites(
+    mut commands: Commands,
+    mut q: Query<
+        (
+            Entity,
+            &Affixes,
+            &mut Sprite,
+            &mut Health,
+            &mut MaxHealth,
+            Option<&mut EnemyMoveSpeed>,
+            Option<&mut RangedEnemyAI>,
+            Option<&mut TurretAI>,
+        ),
+        Added<Affixes>,
+    >,
+) {
+    for (entity, affixes, mut sprite, mut health, mut max_health, spd, ranged_ai, turret_ai) in &mut q {
+        if affixes.0.is_empty() {
+            continue;
+        }
+
+        // Elites are tougher in general, on top of their specific affixes.
+        max_health.0 *= 1.0 + 0.25 * affixes.0.len() as f32;
+        health.0 = max_health.0;
+
+        sprite.color = blended_tint(&affixes.0);
+
+        if affixes.has(Affix::Hasted) {
+            if let Some(mut spd) = spd {
+                spd.0 *= HASTE_SPEED_MULT;
+            }
+            if let Some(mut ai) = ranged_ai {
+                let d = ai.fire_cooldown.duration().mul_f32(HASTE_FIRE_MULT);
+                ai.fire_cooldown.set_duration(d);
+            }
+            if let Some(mut ai) = turret_ai {
+                let d = ai.fire_cooldown.duration().mul_f32(HASTE_FIRE_MULT);
+                ai.fire_cooldown.set_duration(d);
+            }
+        }
+
+        let mut ec = commands.entity(entity);
+        if affixes.has(Affix::Anchored) {
+            ec.remove::<PulledByFluid>();
+        }
+        if affixes.has(Affix::Shielded) {
+            let max = max_health.0 * SHIELD_FRACTION;
+            ec.insert(AffixShield {
+                current: max,
+                max,
+                last_health: health.0,
+                regen_delay: Timer::from_seconds(SHIELD_REGEN_DELAY, TimerMode::Once),
+            });
+        }
+
+        let has_shield = affixes.has(Affix::Shielded);
+        let list = affixes.0.clone();
+        ec.with_children(|parent| spawn_affix_bar_children(parent, &list, has_shield));
+    }
+}
+
+/// Spawns one coloured pip per affix above the health bar, an optional shield
+/// strip, and a rotating aura ring behind the enemy. Kept apart from
+/// `spawn_health_bar_children` because affixes are rolled after the enemy (and
+/// its bar) has spawned.
+fn spawn_affix_bar_children(parent: &mut ChildSpawnerCommands, affixes: &[Affix], shielded: bool) {
+    let pip_y = BAR_Y_OFFSET + BAR_HEIGHT + PIP_SIZE * 0.5 + 1.0;
+    let start_x = -BAR_WIDTH * 0.5 + PIP_SIZE * 0.5;
+    for (i, affix) in affixes.iter().enumerate() {
+        parent.spawn((
+            Sprite::from_color(affix.color(), Vec2::splat(PIP_SIZE)),
+            Transform::from_xyz(start_x + i as f32 * (PIP_SIZE + 2.0), pip_y, 2.0),
+        ));
+    }
+
+    if shielded {
+        parent.spawn((
+            Sprite {
+                color: Affix::Shielded.color(),
+                custom_size: Some(Vec2::new(BAR_WIDTH, BAR_HEIGHT * 0.5)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, BAR_Y_OFFSET - BAR_HEIGHT, 2.0),
+            AffixShieldBarFg,
+        ));
+    }
+
+    const RADIUS: f32 = 26.0;
+    const SEGMENTS: usize = 12;
+    let color = affixes[0].color().with_alpha(0.7);
+    parent.spawn((
+        Transform::from_xyz(0.0, 0.0, -0.5),
+        Visibility::Inherited,
+        AffixAura { color },
+    ))
+    .with_children(|aura| {
+        for i in 0..SEGMENTS {
+            let angle = i as f32 * TAU / SEGMENTS as f32;
+            let offset = Vec2::new(angle.cos(), angle.sin()) * RADIUS;
+            aura.spawn((
+                Sprite::from_color(color, Vec2::splat(4.0)),
+                Transform::from_translation(offset.extend(0.0)),
+            ));
+        }
+    });
+}
+
+/// Spins and pulses elite auras.
+fn animate_auras(
+    time: Res<Time>,
+    mut aura_q: Query<(&mut Transform, &AffixAura, &Children)>,
+    mut sprite_q: Query<&mut Sprite>,
+) {
+    let pulse = 0.45 + 0.35 * (time.elapsed_secs() * 4.0).sin().abs();
+    for (mut tf, aura, children) in &mut aura_q {
+        tf.rotation *= Quat::from_rotation_z(2.0 * time.delta_secs());
+        for child in children.iter() {
+            if let Ok(mut sprite) = sprite_q.get_mut(child) {
+                sprite.color = aura.color.with_alpha(pulse);
+            }
+        }
+    }
+}
+
+/// Converts any health lost since last frame into shield damage first, then
+/// regenerates the shield once the enemy has gone a while without being hit.
+fn absorb_shield_damage(
+    time: Res<Time>,
+    mut q: Query<(&mut Health, &mut AffixShield, &Children), With<Enemy>>,
+    mut bar_q: Query<(&mut Sprite, &mut Transform), With<AffixShieldBarFg>>,
+) {
+    for (mut health, mut shield, children) in &mut q {
+        let lost = shield.last_health - health.0;
+        if lost > 0.0 {
+            let absorbed = lost.min(shield.current);
+            shield.current -= absorbed;
+            health.0 += absorbed;
+            shield.regen_delay.reset();
+        } else {
+            shield.regen_delay.tick(time.delta());
+            if shield.regen_delay.finished() && shield.current < shield.max {
+                shield.current = (shield.current + shield.max * SHIELD_REGEN_RATE * time.delta_secs()).min(shield.max);
+            }
+        }
+        shield.last_health = health.0;
+
+        let ratio = if shield.max > 0.0 { (shield.current / shield.max).clamp(0.0, 1.0) } else { 0.0 };
+        for child in children.iter() {
+            if let Ok((mut sprite, mut tf)) = bar_q.get_mut(child) {
+                sprite.custom_size = Some(Vec2::new(ratio * BAR_WIDTH, BAR_HEIGHT * 0.5));
+                tf.translation.x = -(1.0 - ratio) * BAR_WIDTH * 0.5;
+            }
+        }
+    }
+}
+
+/// Heals Vampiric elites for a share of the damage they land on the player.
+fn vampiric_heal(
+    mut events: EventReader<super::EnemyHitPlayerEvent>,
+    mut q: Query<(&Affixes, &mut Health, &MaxHealth, Option<&mut AffixShield>)>,
+) {
+    for ev in events.read() {
+        let Ok((affixes, mut health, max_health, shield)) = q.get_mut(ev.enemy) else { continue };
+        if !affixes.has(Affix::Vampiric) || health.0 <= 0.0 {
+            continue;
+        }
+        health.0 = (health.0 + ev.damage * VAMPIRIC_LIFESTEAL).min(max_health.0);
+        // Keep the shield tracker in sync so the heal isn't mistaken for anything else.
+        if let Some(mut shield) = shield {
+            shield.last_health = health.0;
+        }
+    }
+}
+
+/// Runs death effects for elites: Volatile explosions, Splitter children and
+/// bonus reward drops.
+fn handle_elite_deaths(
+    mut commands: Commands,
+    mut events: EventReader<EliteDeathEvent>,
+    enemy_res: Res<EnemyRes>,
+    reward_res: Res<crate::rewards::RewardRes>,
+    reward_font: Res<crate::rewards::RewardFont>,
+    reward_registry: Res<crate::rewards::RewardRegistry>,
+    mut player_q: Query<
+        (&Transform, &mut crate::player::Health, &Armor, &mut Shield, Option<&DashInvincibility>),
+        With<Player>,
+    >,
+    mut table_q: Query<(&Transform, &mut Velocity), (With<table::Table>, Without<Enemy>)>,
+    mut enemy_q: Query<(&Transform, &mut Velocity), (With<Enemy>, Without<table::Table>)>,
+    mut window_q: Query<(&Transform, &mut window::Health, &window::GlassState), With<window::Window>>,
+    planet: Option<Res<crate::PlanetLevelMarker>>,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
+    mut rng: ResMut<RunRng>,
+) {
+    for ev in events.read() {
+        let pos = ev.pos.truncate();
+
+        if ev.affixes.contains(&Affix::Volatile) {
+            if let Ok((p_tf, mut hp, armor, mut shield, dash_inv)) = player_q.single_mut() {
+                let p_pos = p_tf.translation.truncate();
+                if dash_inv.is_none() && p_pos.distance(pos) <= VOLATILE_RADIUS {
+                    if shield.current >= 1.0 {
+                        shield.current -= 1.0;
+                    } else {
+                        let taken = VOLATILE_DAMAGE * crate::player::armor_factor(armor.0);
+                        hp.0 -= taken;
+                        stat_events.write(crate::run_history::StatEvent::DamageTaken(crate::run_history::DamageSource::Explosion, taken));
+                    }
+                }
+            }
+            for (tf, mut vel) in &mut table_q {
+                let d = tf.translation.truncate() - pos;
+                if d.length() <= VOLATILE_RADIUS {
+                    vel.velocity += d.normalize_or_zero() * VOLATILE_PUSH;
+                }
+            }
+            for (tf, mut vel) in &mut enemy_q {
+                let d = tf.translation.truncate() - pos;
+                if d.length() <= VOLATILE_RADIUS {
+                    vel.velocity += d.normalize_or_zero() * VOLATILE_PUSH * 0.5;
+                }
+            }
+            for (tf, mut wh, state) in &mut window_q {
+                if *state == window::GlassState::Intact
+                    && tf.translation.truncate().distance(pos) <= VOLATILE_RADIUS
+                {
+                    wh.0 -= VOLATILE_WINDOW_DAMAGE;
+                }
+            }
+            spawn_explosion_burst(&mut commands, pos);
+        }
+
+        if ev.affixes.contains(&Affix::Splitter) {
+            // check_enemy_health has already counted these two toward the room.
+            let child_hp = ev.max_health * SPLIT_HEALTH_FRACTION;
+            let mult = child_hp / super::chaser::BASE_HEALTH;
+            for side in [-1.0_f32, 1.0] {
+                let at = ev.pos + Vec3::new(side * 14.0, 0.0, 0.0);
+                let child = super::spawn_enemy_at(&mut commands, &enemy_res, at, true, mult, 40.0);
+                commands.entity(child).insert(Transform {
+                    translation: at,
+                    scale: Vec3::splat(SPLIT_SCALE),
+                    ..default()
+                });
+            }
+        }
+
+        if planet.is_none() {
+            let chance = DROP_CHANCE_PER_AFFIX * ev.affixes.len() as f32;
+            if rng.random::<f32>() < chance {
+                crate::rewards::spawn_reward(&mut commands, ev.pos, &reward_res, &reward_registry, &reward_font, &mut rng);
+            }
+        }
+    }
+}
+
+fn spawn_explosion_burst(commands: &mut Commands, pos: Vec2) {
+    for _ in 0..24 {
+        let angle = random_range(0.0..TAU);
+        let speed = random_range(120.0_f32..=360.0_f32);
+        let dir = Vec2::new(angle.cos(), angle.sin());
+        commands.spawn((
+            Sprite::from_color(
+                Color::srgba(1.0, random_range(0.3..=0.7_f32), 0.1, 1.0),
+                Vec2::splat(random_range(3.0..=6.0_f32)),
+            ),
+            Transform::from_translation(pos.extend(crate::Z_ENTITIES + 5.0)),
+            crate::air_particles::DashParticle {
+                velocity: dir * speed,
+                lifetime: Timer::from_seconds(random_range(0.3_f32..=0.6_f32), TimerMode::Once),
+            },
+            GameEntity,
+        ));
+    }
+}
+
+/// Number of extra enemies a dying elite adds to its room (Splitter children).
+pub fn extra_spawns_on_death(affixes: &Affixes) -> usize {
+    if affixes.has(Affix::Splitter) { 2 } else { 0 }
+}
+
+/// Rolls and attaches affixes to a freshly spawned room enemy.
+pub fn maybe_make_elite(commands: &mut Commands, entity: Entity, station_level: u32, rooms_cleared: usize, rng: &mut RunRng) {
+    let affixes = roll_affixes(station_level, rooms_cleared, rng);
+    if !affixes.is_empty() {
+        commands.entity(entity).insert(Affixes(affixes));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::status::{StatusEffects, StatusKind, tick_status_effects};
+    use std::time::Duration;
+
+    #[test]
+    fn shield_soaks_burn_ticks() {
+        let mut app = App::new();
+        app.add_event::<crate::run_history::StatEvent>()
+            .init_resource::<Time>()
+            .add_systems(Update, (
+                tick_status_effects.in_set(EnemyDamageSet),
+                absorb_shield_damage.after(EnemyDamageSet),
+            ));
+        let mut status = StatusEffects::default();
+        status.apply(StatusKind::Burn, 1.0, 1);
+        let enemy = app.world_mut().spawn((
+            Enemy,
+            Health(100.0),
+            status,
+            AffixShield {
+                current: 50.0,
+                max: 50.0,
+                last_health: 100.0,
+                regen_delay: Timer::from_seconds(SHIELD_REGEN_DELAY, TimerMode::Once),
+            },
+        )).with_children(|p| { p.spawn(AffixShieldBarFg); }).id();
+
+        for _ in 0..30 {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        }
+
+        let world = app.world();
+        assert_eq!(world.get::<Health>(enemy).unwrap().0, 100.0);
+        assert!(world.get::<AffixShield>(enemy).unwrap().current < 50.0);
+    }
+}
@@ -48,6 +48,9 @@ pub fn load(mut commands: Commands, asset_server: Res<AssetServer>) {
 
 // ── Spawn ──────────────────────────────────────────────────────────────────
 
+/// Chaser health before station/room multipliers.
+pub const BASE_HEALTH: f32 = 50.0;
+
 pub fn spawn_at(
     commands: &mut Commands,
     res: &EnemyRes,
@@ -55,8 +58,8 @@ pub fn spawn_at(
     active: bool,
     health_multiplier: f32,
     speed_bonus: f32,
-) {
-    let hp = 50.0 * health_multiplier;
+) -> Entity {
+    let hp = BASE_HEALTH * health_multiplier;
     let mut e = commands.spawn((
         Sprite::from_image(res.frames[0].clone()),
         Transform { translation: at, ..Default::default() },
@@ -76,6 +79,7 @@ pub fn spawn_at(
     if active {
         e.insert(ActiveEnemy);
     }
+    e.id()
 }
 
 // ── Systems ────────────────────────────────────────────────────────────────
@@ -1,6 +1,9 @@
+pub mod affix;
 pub mod chaser;
+pub mod nest;
 pub mod ranger;
 pub mod reaper;
+pub mod saboteur;
 pub mod turret;
 
 // Re-export sub-module items so callers can keep using `enemies::X`
@@ -13,12 +16,16 @@ pub use ranger::{
     RangedAnimationTimer, RangedEnemy, RangedEnemyAI, RangedEnemyFrames,
     RangedEnemyRes, RangerShootEvent, spawn_ranged_enemy_at,
 };
+pub use affix::{Affix, Affixes, EliteDeathEvent};
+pub use nest::{Nest, spawn_nest_at};
 pub use reaper::Reaper;
+pub use saboteur::{SaboteurEnemy, spawn_saboteur_enemy_at};
 pub use turret::{TurretEnemy, TurretRes, TurretShootEvent, spawn_turret_enemy_at};
 
 use bevy::prelude::*;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::cmp::Reverse;
+use rand::Rng;
 use crate::{GameState, TILE_SIZE};
 use crate::collidable::{Collider, Collidable};
 use crate::player::Player;
@@ -68,13 +75,21 @@ impl Health {
 #[derive(Component)]
 pub struct MaxHealth(pub f32);
 
+/// Written whenever an enemy lands damage on the player (contact or projectile),
+/// so on-hit behaviours like Vampiric can react without touching every damage path.
+#[derive(Event)]
+pub struct EnemyHitPlayerEvent {
+    pub enemy: Entity,
+    pub damage: f32,
+}
+
 /// Marker on the foreground fill sprite of an enemy's world-space health bar.
 #[derive(Component)]
 pub struct EnemyHealthBarFg;
 
-const BAR_WIDTH: f32 = 34.0;
-const BAR_HEIGHT: f32 = 4.0;
-const BAR_Y_OFFSET: f32 = 62.0;
+pub(crate) const BAR_WIDTH: f32 = 34.0;
+pub(crate) const BAR_HEIGHT: f32 = 4.0;
+pub(crate) const BAR_Y_OFFSET: f32 = 62.0;
 
 /// Spawns the two bar sprites (background + fill) as children of an enemy entity.
 pub fn spawn_health_bar_children(parent: &mut ChildSpawnerCommands) {
@@ -156,18 +171,51 @@ pub struct EnemyPathfinder {
 
 impl EnemyPathfinder {
     pub fn new() -> Self {
-        // Stagger recompute timers so enemies don't all run A* the same frame.
-        let offset = rand::random::<f32>() * PATH_RECOMPUTE_SECS;
-        let mut timer = Timer::from_seconds(PATH_RECOMPUTE_SECS, TimerMode::Repeating);
-        timer.set_elapsed(std::time::Duration::from_secs_f32(offset));
         Self {
             waypoints: Vec::new(),
-            timer,
+            timer: Timer::from_seconds(PATH_RECOMPUTE_SECS, TimerMode::Repeating),
             open: BinaryHeap::new(),
             came_from: HashMap::new(),
             g: HashMap::new(),
         }
     }
+
+    /// Drops waypoints the enemy has already reached.
+    fn advance(&mut self, pos: Vec2) {
+        while let Some(&wp) = self.waypoints.first() {
+            if pos.distance(wp) < TILE_SIZE * 0.8 {
+                self.waypoints.remove(0);
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Replans from `from` to `to`. Waypoints are left empty when there is
+    /// line-of-sight. Returns false if A* found no route within `max_nodes`.
+    fn repath(
+        &mut self,
+        from: Vec2,
+        to: Vec2,
+        wall_grid: &crate::map::WallGrid,
+        blocked: &HashSet<(i32, i32)>,
+        max_nodes: usize,
+        padding: i32,
+    ) -> bool {
+        let start = wall_grid.world_to_tile(from);
+        let goal = wall_grid.world_to_tile(to);
+        if has_los(start, goal, wall_grid, blocked) {
+            self.waypoints.clear();
+            return true;
+        }
+        let tiles = a_star(start, goal, wall_grid, blocked, max_nodes, padding,
+            &mut self.open, &mut self.came_from, &mut self.g);
+        self.waypoints = tiles
+            .into_iter()
+            .map(|(c, r)| wall_grid.tile_to_world(c, r))
+            .collect();
+        !self.waypoints.is_empty()
+    }
 }
 
 // Plugin
@@ -183,18 +231,21 @@ impl Plugin for EnemyPlugin {
             .add_systems(Startup, turret::load)
             .add_event::<RangerShootEvent>()
             .add_event::<TurretShootEvent>()
+            .add_event::<EnemyHitPlayerEvent>()
             .add_systems(Update, chaser::animate.run_if(in_state(GameState::Playing)))
             .add_systems(
                 Update,
                 (
                     update_table_blocked_tiles,
-                    compute_enemy_paths.after(update_table_blocked_tiles),
+                    stagger_new_pathfinders.before(saboteur::ai),
+                    compute_enemy_paths.after(update_table_blocked_tiles).after(stagger_new_pathfinders),
                     ranger::ai.after(compute_enemy_paths),
                     turret::ai.after(compute_enemy_paths),
+                    saboteur::ai.after(update_table_blocked_tiles),
                     ranger::spawn_ranger_bullets.after(ranger::ai),
                     turret::spawn_turret_bullets.after(turret::ai),
-                    move_enemy.after(ranger::ai).after(turret::ai),
-                    move_reaper_freely.after(ranger::ai),
+                    move_enemy.after(ranger::ai).after(turret::ai).after(saboteur::ai),
+                    move_reaper.after(ranger::ai),
                     collide_enemies_with_enemies.after(move_enemy),
                     wall_correction_for_enemies.after(collide_enemies_with_enemies),
                     enemies_collide_with_tables.after(wall_correction_for_enemies),
@@ -203,32 +254,42 @@ impl Plugin for EnemyPlugin {
             )
             .add_systems(Update, kill_enemies_outside_station.run_if(in_state(GameState::Playing)))
             .add_systems(Update, check_enemy_health.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                Update,
+                (nest::emit_waves, nest::collapse_orphaned_spawns.before(check_enemy_health))
+                    .run_if(in_state(GameState::Playing)),
+            )
         .add_systems(Update, update_enemy_health_bars.run_if(in_state(GameState::Playing)))
             .add_systems(Update, chaser::animate_hit)
-            .add_systems(Update, table_hits_enemy)
+            .add_systems(Update, table_hits_enemy.in_set(EnemyDamageSet))
             .add_systems(Update, ranger::animate.run_if(in_state(GameState::Playing)))
             .add_systems(Update, turret::animate.run_if(in_state(GameState::Playing)));
     }
 }
 
+/// Every system that takes health off enemies. Anything reacting to the
+/// damage dealt this frame, like elite shields, runs after the whole set.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnemyDamageSet;
+
 // Shared systems
 
 fn update_enemy_health_bars(
     enemy_q: Query<(&Health, &MaxHealth, &Children), With<Enemy>>,
     mut fg_q: Query<(&mut Sprite, &mut Transform), With<EnemyHealthBarFg>>,
+    accessibility: Res<crate::settings::AccessibilitySettings>,
 ) {
     for (health, max_health, children) in &enemy_q {
         let ratio = (health.0 / max_health.0).clamp(0.0, 1.0);
         let fill_w = ratio * BAR_WIDTH;
         // Anchor left edge: shift left by half the missing width
         let fill_x = -(1.0 - ratio) * BAR_WIDTH * 0.5;
-        let r = (1.0 - ratio).min(1.0);
-        let g = ratio.min(1.0);
+        let color = accessibility.health_color(ratio);
 
         for child in children.iter() {
             if let Ok((mut sprite, mut tf)) = fg_q.get_mut(child) {
                 sprite.custom_size = Some(Vec2::new(fill_w.max(0.0), BAR_HEIGHT));
-                sprite.color = Color::srgb(r, g, 0.0);
+                sprite.color = color;
                 tf.translation.x = fill_x;
             }
         }
@@ -240,7 +301,7 @@ fn kill_enemies_outside_station(
     wall_grid: Res<crate::map::WallGrid>,
     lvlstate: Res<LevelState>,
     rooms: Res<RoomVec>,
-    mut enemy_query: Query<(&Transform, &mut Health), (With<Enemy>, Without<Reaper>)>,
+    mut enemy_query: Query<(&Transform, &mut Health, Option<&mut affix::AffixShield>), (With<Enemy>, Without<Reaper>)>,
 ) {
     let tile = crate::TILE_SIZE;
     let x_min = grid_meta.x0 - tile * 0.5;
@@ -250,28 +311,26 @@ fn kill_enemies_outside_station(
 
     let active_room = if let LevelState::InRoom(i, _, _) = *lvlstate { Some(i) } else { None };
 
-    for (tf, mut hp) in &mut enemy_query {
+    for (tf, mut hp, shield) in &mut enemy_query {
         if hp.0 <= 0.0 { continue; }
         let p = tf.translation.truncate();
 
         // Outside grid bounds entirely.
-        if p.x < x_min || p.x > x_max || p.y < y_min || p.y > y_max {
-            hp.0 = 0.0;
-            continue;
-        }
+        let outside_grid = p.x < x_min || p.x > x_max || p.y < y_min || p.y > y_max;
 
         // Center tile is a wall or window — enemy has clipped inside geometry.
         let (col, row) = wall_grid.world_to_tile(p);
-        if wall_grid.is_wall_tile(col, row) {
-            hp.0 = 0.0;
-            continue;
-        }
+        let in_wall = wall_grid.is_wall_tile(col, row);
 
         // During active combat, any enemy that has escaped the locked room is
         // unreachable and would soft-lock the player.
-        if let Some(idx) = active_room {
-            if !rooms.0[idx].bounds_check(p) {
-                hp.0 = 0.0;
+        let escaped = active_room.is_some_and(|idx| !rooms.0[idx].bounds_check(p));
+
+        if outside_grid || in_wall || escaped {
+            hp.0 = 0.0;
+            // An elite shield layer must not soak this kill.
+            if let Some(mut shield) = shield {
+                shield.current = 0.0;
             }
         }
     }
@@ -281,7 +340,7 @@ fn kill_enemies_outside_station(
 
 /// Bresenham line-of-sight check on the tile grid.
 /// Returns true if the straight line from `from` to `to` passes through no wall or blocked tile.
-fn has_los(
+pub(crate) fn has_los(
     from: (i32, i32),
     to: (i32, i32),
     wall_grid: &crate::map::WallGrid,
@@ -389,6 +448,17 @@ fn a_star(
     Vec::new()
 }
 
+/// Staggers fresh recompute timers so enemies don't all run A* the same frame.
+fn stagger_new_pathfinders(
+    mut query: Query<&mut EnemyPathfinder, Added<EnemyPathfinder>>,
+    mut rng: ResMut<crate::RunRng>,
+) {
+    for mut pathfinder in &mut query {
+        let offset = rng.random::<f32>() * PATH_RECOMPUTE_SECS;
+        pathfinder.timer.set_elapsed(std::time::Duration::from_secs_f32(offset));
+    }
+}
+
 /// Recomputes paths for enemies that have no line-of-sight to the player.
 /// Also advances waypoints as the enemy moves through them.
 fn compute_enemy_paths(
@@ -396,27 +466,22 @@ fn compute_enemy_paths(
     player_q: Query<&Transform, (With<Player>, Without<Enemy>)>,
     mut enemy_q: Query<
         (&Transform, &mut EnemyPathfinder),
-        (With<Enemy>, With<ActiveEnemy>, Without<Reaper>),
+        (With<Enemy>, With<ActiveEnemy>, Without<Reaper>, Without<SaboteurEnemy>),
     >,
     wall_grid: Res<crate::map::WallGrid>,
     blocked_cache: Res<TableBlockedTiles>,
+    decoy: Res<crate::items::DecoyTarget>,
 ) {
     let Ok(player_tf) = player_q.single() else { return };
-    let player_pos = player_tf.translation.truncate();
-    let goal = wall_grid.world_to_tile(player_pos);
+    // Enemies chase an active decoy beacon instead of the player.
+    let player_pos = decoy.0.unwrap_or(player_tf.translation.truncate());
     let blocked = &blocked_cache.tiles;
 
     for (enemy_tf, mut pathfinder) in &mut enemy_q {
         let pos = enemy_tf.translation.truncate();
 
         // Advance past waypoints the enemy has already reached.
-        while let Some(&wp) = pathfinder.waypoints.first() {
-            if pos.distance(wp) < TILE_SIZE * 0.8 {
-                pathfinder.waypoints.remove(0);
-            } else {
-                break;
-            }
-        }
+        pathfinder.advance(pos);
 
         pathfinder.timer.tick(time.delta());
         if !pathfinder.timer.just_finished() {
@@ -429,38 +494,58 @@ fn compute_enemy_paths(
             continue;
         }
 
-        let start = wall_grid.world_to_tile(pos);
-
-        if has_los(start, goal, &wall_grid, blocked) {
-            pathfinder.waypoints.clear();
-        } else {
-            let pf = &mut *pathfinder;
-            let tiles = a_star(start, goal, &wall_grid, blocked, PATH_MAX_NODES, PATH_SEARCH_PAD,
-                &mut pf.open, &mut pf.came_from, &mut pf.g);
-            pf.waypoints = tiles
-                .into_iter()
-                .map(|(c, r)| wall_grid.tile_to_world(c, r))
-                .collect();
-        }
+        pathfinder.repath(pos, player_pos, &wall_grid, blocked, PATH_MAX_NODES, PATH_SEARCH_PAD);
     }
 }
 
-fn check_enemy_health(
+pub(crate) fn check_enemy_health(
     mut commands: Commands,
-    enemy_query: Query<(Entity, &Health, &Transform), With<Enemy>>,
+    enemy_query: Query<(Entity, &Health, &MaxHealth, &Transform, Option<&Affixes>, Option<&Nest>, Has<Reaper>), With<Enemy>>,
     key_holder_q: Query<(), With<crate::key_chest::KeyHolder>>,
     mut rooms: ResMut<RoomVec>,
     lvlstate: Res<LevelState>,
     mut last_kill_pos: ResMut<LastKillPos>,
     key_res: Option<Res<crate::key_chest::KeyChestRes>>,
+    mut elite_deaths: EventWriter<EliteDeathEvent>,
+    kind_q: Query<(Has<ranger::RangedEnemy>, Has<TurretEnemy>, Has<SaboteurEnemy>)>,
+    mut run_events: EventWriter<crate::profile::RunEvent>,
 ) {
-    for (entity, health, transform) in enemy_query.iter() {
+    for (entity, health, max_health, transform, affixes, nest, is_reaper) in enemy_query.iter() {
         if health.0 <= 0.0 {
-            if let LevelState::InRoom(index, _, _) = *lvlstate {
-                rooms.0[index].numofenemies -= 1;
+            use crate::profile::EnemyKind;
+            let kind = match (is_reaper, nest.is_some(), kind_q.get(entity)) {
+                (true, _, _) => EnemyKind::Reaper,
+                (_, true, _) => EnemyKind::Nest,
+                (_, _, Ok((true, _, _))) => EnemyKind::Ranger,
+                (_, _, Ok((_, true, _))) => EnemyKind::Turret,
+                (_, _, Ok((_, _, true))) => EnemyKind::Saboteur,
+                _ => EnemyKind::Chaser,
+            };
+            run_events.write(crate::profile::RunEvent::EnemyKilled(kind));
+
+            let extra = affixes.map_or(0, affix::extra_spawns_on_death);
+            // The reaper roams between rooms and was never part of the count.
+            if let LevelState::InRoom(index, _, _) = *lvlstate
+                && !is_reaper
+            {
+                // Splitter children are counted now so the room can't clear
+                // in the gap before they spawn.
+                rooms.0[index].numofenemies = rooms.0[index].numofenemies.saturating_sub(1) + extra;
+            }
+            if let Some(nest) = nest {
+                let room = &mut rooms.0[nest.room];
+                room.nests_remaining = room.nests_remaining.saturating_sub(1);
             }
             last_kill_pos.0 = transform.translation.truncate();
 
+            if let Some(affixes) = affixes {
+                elite_deaths.write(EliteDeathEvent {
+                    pos: transform.translation,
+                    max_health: max_health.0,
+                    affixes: affixes.0.clone(),
+                });
+            }
+
             if key_holder_q.get(entity).is_ok() {
                 if let Some(ref kr) = key_res {
                     crate::key_chest::drop_key(&mut commands, kr, transform.translation);
@@ -482,14 +567,17 @@ fn move_enemy(
             Option<&crate::fluiddynamics::PulledByFluid>,
             Option<&ranger::RangedEnemy>,
             Option<&turret::TurretEnemy>,
+            Option<&SaboteurEnemy>,
             Option<&EnemyMoveSpeed>,
             Option<&EnemyPathfinder>,
+            Option<&crate::status::StatusEffects>,
         ),
         (With<Enemy>, With<ActiveEnemy>, Without<Reaper>),
     >,
     wall_grid: Res<crate::map::WallGrid>,
     grid_query: Query<&crate::fluiddynamics::FluidGrid>,
     door_query: Query<(&Transform, &Collider), (With<Collidable>, Without<crate::map::WallTile>, Without<crate::table::Table>, Without<Enemy>, Without<Player>)>,
+    decoy: Res<crate::items::DecoyTarget>,
 ) {
     let grid_has_breach = if let Ok(grid) = grid_query.single() {
         !grid.breaches.is_empty()
@@ -502,18 +590,23 @@ fn move_enemy(
     let accel = ENEMY_ACCEL * deltat;
     let enemy_half = Vec2::splat(ENEMY_SIZE * 0.5);
 
-    let player_pos = player_transform.translation.truncate();
+    let player_pos = decoy.0.unwrap_or(player_transform.translation.truncate());
+    let doors: Vec<(Vec2, Vec2)> = door_query
+        .iter()
+        .map(|(tf, c)| (tf.translation.truncate(), c.half_extents))
+        .collect();
 
-    for (mut enemy_transform, mut enemy_velocity, _pulled_opt, ranged_opt, turret_opt, spd_opt, pathfinder_opt) in &mut enemy_query {
-        let max_speed = spd_opt.map_or(ENEMY_SPEED, |s| s.0);
+    for (mut enemy_transform, mut enemy_velocity, _pulled_opt, ranged_opt, turret_opt, saboteur_opt, spd_opt, pathfinder_opt, status_opt) in &mut enemy_query {
+        let status_mult = crate::status::speed_multiplier(status_opt);
+        let max_speed = spd_opt.map_or(ENEMY_SPEED, |s| s.0) * status_mult;
         let mut effective_accel = accel;
         if grid_has_breach {
             effective_accel *= 0.15;
         }
 
         // Chasers steer toward the player (or a path waypoint if blocked).
-        // Rangers get their velocity from ranger::ai.
-        if ranged_opt.is_none() && turret_opt.is_none() {
+        // Rangers, turrets and saboteurs get their velocity from their own ai.
+        if ranged_opt.is_none() && turret_opt.is_none() && saboteur_opt.is_none() {
             let target = pathfinder_opt
                 .and_then(|pf| pf.waypoints.first().copied())
                 .unwrap_or(player_pos);
@@ -531,80 +624,88 @@ fn move_enemy(
             }
         }
 
+        // AI-driven enemies set their own velocity, so slows/stuns clamp it here.
+        if status_mult < 1.0 {
+            **enemy_velocity = enemy_velocity.clamp_length_max(max_speed);
+        }
+
         let change = **enemy_velocity * deltat;
-        let mut pos = enemy_transform.translation;
-
-        if change.x != 0.0 {
-            let mut nx = pos.x + change.x;
-            let mut hit_wall_x = false;
-            for (wall_pos, wall_half) in wall_grid.nearby(Vec2::new(nx, pos.y), 3) {
-                if crate::player::aabb_overlap(nx, pos.y, enemy_half, wall_pos.x, wall_pos.y, wall_half) {
-                    let candidate = if change.x > 0.0 {
-                        wall_pos.x - (enemy_half.x + wall_half.x)
-                    } else {
-                        wall_pos.x + (enemy_half.x + wall_half.x)
-                    };
-                    nx = if change.x > 0.0 { nx.min(candidate) } else { nx.max(candidate) };
-                    hit_wall_x = true;
-                }
-            }
-            for (dt, dc) in &door_query {
-                let (cx, cy) = (dt.translation.x, dt.translation.y);
-                if crate::player::aabb_overlap(nx, pos.y, enemy_half, cx, cy, dc.half_extents) {
-                    let candidate = if change.x > 0.0 {
-                        cx - (enemy_half.x + dc.half_extents.x)
-                    } else {
-                        cx + (enemy_half.x + dc.half_extents.x)
-                    };
-                    nx = if change.x > 0.0 { nx.min(candidate) } else { nx.max(candidate) };
-                    hit_wall_x = true;
-                }
+        let (pos, blocked) = slide_against_walls(enemy_transform.translation.truncate(), change, enemy_half, &wall_grid, &doors);
+        if blocked.x { enemy_velocity.velocity.x = 0.0; }
+        if blocked.y { enemy_velocity.velocity.y = 0.0; }
+        enemy_transform.translation.x = pos.x;
+        enemy_transform.translation.y = pos.y;
+    }
+}
+
+/// Moves a box of half-size `half` by `change`, one axis at a time, stopping
+/// it flush against any wall cell or solid door in the way. Returns the new
+/// position and which axes were blocked.
+fn slide_against_walls(
+    mut pos: Vec2,
+    change: Vec2,
+    half: Vec2,
+    wall_grid: &crate::map::WallGrid,
+    doors: &[(Vec2, Vec2)],
+) -> (Vec2, BVec2) {
+    let mut blocked = BVec2::FALSE;
+
+    if change.x != 0.0 {
+        let mut nx = pos.x + change.x;
+        let solids = wall_grid.nearby(Vec2::new(nx, pos.y), 3);
+        for &(solid_pos, solid_half) in solids.iter().chain(doors) {
+            if crate::player::aabb_overlap(nx, pos.y, half, solid_pos.x, solid_pos.y, solid_half) {
+                let candidate = if change.x > 0.0 {
+                    solid_pos.x - (half.x + solid_half.x)
+                } else {
+                    solid_pos.x + (half.x + solid_half.x)
+                };
+                nx = if change.x > 0.0 { nx.min(candidate) } else { nx.max(candidate) };
+                blocked.x = true;
             }
-            if hit_wall_x { enemy_velocity.velocity.x = 0.0; }
-            pos.x = nx;
         }
+        pos.x = nx;
+    }
 
-        if change.y != 0.0 {
-            let mut ny = pos.y + change.y;
-            let mut hit_wall_y = false;
-            for (wall_pos, wall_half) in wall_grid.nearby(Vec2::new(pos.x, ny), 3) {
-                if crate::player::aabb_overlap(pos.x, ny, enemy_half, wall_pos.x, wall_pos.y, wall_half) {
-                    let candidate = if change.y > 0.0 {
-                        wall_pos.y - (enemy_half.y + wall_half.y)
-                    } else {
-                        wall_pos.y + (enemy_half.y + wall_half.y)
-                    };
-                    ny = if change.y > 0.0 { ny.min(candidate) } else { ny.max(candidate) };
-                    hit_wall_y = true;
-                }
+    if change.y != 0.0 {
+        let mut ny = pos.y + change.y;
+        let solids = wall_grid.nearby(Vec2::new(pos.x, ny), 3);
+        for &(solid_pos, solid_half) in solids.iter().chain(doors) {
+            if crate::player::aabb_overlap(pos.x, ny, half, solid_pos.x, solid_pos.y, solid_half) {
+                let candidate = if change.y > 0.0 {
+                    solid_pos.y - (half.y + solid_half.y)
+                } else {
+                    solid_pos.y + (half.y + solid_half.y)
+                };
+                ny = if change.y > 0.0 { ny.min(candidate) } else { ny.max(candidate) };
+                blocked.y = true;
             }
-            for (dt, dc) in &door_query {
-                let (cx, cy) = (dt.translation.x, dt.translation.y);
-                if crate::player::aabb_overlap(pos.x, ny, enemy_half, cx, cy, dc.half_extents) {
-                    let candidate = if change.y > 0.0 {
-                        cy - (enemy_half.y + dc.half_extents.y)
-                    } else {
-                        cy + (enemy_half.y + dc.half_extents.y)
-                    };
-                    ny = if change.y > 0.0 { ny.min(candidate) } else { ny.max(candidate) };
-                    hit_wall_y = true;
-                }
-            }
-            if hit_wall_y { enemy_velocity.velocity.y = 0.0; }
-            pos.y = ny;
         }
-
-        enemy_transform.translation = pos;
+        pos.y = ny;
     }
+
+    (pos, blocked)
 }
 
-fn move_reaper_freely(
+/// The reaper skips enemy-enemy and table collisions but still stops at walls
+/// and sealed doors, so a closed door really does hold it back.
+fn move_reaper(
     time: Res<Time>,
-    mut query: Query<(&mut Transform, &Velocity), With<Reaper>>,
+    mut query: Query<(&mut Transform, &mut Velocity), (With<Enemy>, With<Reaper>)>,
+    wall_grid: Res<crate::map::WallGrid>,
+    door_query: Query<(&Transform, &Collider), (With<Collidable>, Without<crate::map::WallTile>, Without<crate::table::Table>, Without<Enemy>, Without<Player>)>,
 ) {
     let dt = time.delta_secs();
-    for (mut tf, vel) in &mut query {
-        tf.translation += (vel.velocity * dt).extend(0.0);
+    let doors: Vec<(Vec2, Vec2)> = door_query
+        .iter()
+        .map(|(tf, c)| (tf.translation.truncate(), c.half_extents))
+        .collect();
+    for (mut tf, mut vel) in &mut query {
+        let (pos, blocked) = slide_against_walls(tf.translation.truncate(), vel.velocity * dt, reaper::REAPER_MOVE_HALF, &wall_grid, &doors);
+        if blocked.x { vel.velocity.x = 0.0; }
+        if blocked.y { vel.velocity.y = 0.0; }
+        tf.translation.x = pos.x;
+        tf.translation.y = pos.y;
     }
 }
 
@@ -784,7 +885,7 @@ fn collide_enemies_with_enemies(
 
 fn table_hits_enemy(
     mut enemy_query: Query<
-        (&Transform, &mut Health),
+        (&Transform, &mut Health, Option<&crate::status::StatusEffects>),
         (With<Enemy>, Without<Reaper>),
     >,
     table_query: Query<
@@ -792,11 +893,12 @@ fn table_hits_enemy(
         With<table::Table>,
     >,
     active_room: Res<table::ActiveRoom>,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
 ) {
     let Some(active) = active_room.0 else { return; };
     let enemy_half = Vec2::splat(ENEMY_SIZE * 0.5);
 
-    for (enemy_tf, mut health) in &mut enemy_query {
+    for (enemy_tf, mut health, status) in &mut enemy_query {
         let enemy_pos = enemy_tf.translation.truncate();
         for (table_tf, table_col, vel_opt, room) in &table_query {
             if room.0 != active { continue; }
@@ -809,9 +911,49 @@ fn table_hits_enemy(
             ) {
                 let speed = vel_opt.map(|v| v.velocity.length()).unwrap_or(0.0);
                 if speed > 5.0 {
-                    health.0 -= speed * 0.02;
+                    let dealt = speed * 0.02 * crate::status::damage_taken_multiplier(status);
+                    health.0 -= dealt;
+                    stat_events.write(crate::run_history::StatEvent::DamageDealt(crate::run_history::DamageSource::Table, dealt));
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn sealed_door_stops_the_reaper() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(crate::map::WallGrid::from_tiles(&[]))
+            .add_systems(Update, move_reaper);
+
+        let door_half = Vec2::splat(TILE_SIZE * 0.5);
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 0.0, 0.0),
+            Collider { half_extents: door_half },
+            Collidable,
+            crate::map::Door { is_open: false, pos: Vec2::ZERO },
+        ));
+        let reaper = app.world_mut().spawn((
+            Transform::from_xyz(-4.0 * TILE_SIZE, 0.0, 0.0),
+            Velocity { velocity: Vec2::new(400.0, 0.0) },
+            Enemy,
+            Reaper,
+        )).id();
+
+        for _ in 0..60 {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        }
+
+        let x = app.world().get::<Transform>(reaper).unwrap().translation.x;
+        let stop = -(door_half.x + reaper::REAPER_MOVE_HALF.x);
+        assert!((x - stop).abs() < 0.01, "reaper at {x}, door face at {stop}");
+        assert_eq!(app.world().get::<Velocity>(reaper).unwrap().velocity.x, 0.0);
+    }
+}
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::{GameEntity, RunRng, TILE_SIZE};
+use crate::room::{LevelState, RoomObjective, RoomVec};
+use super::{
+    Enemy, Health, MaxHealth, Reaper, EnemyRes, RangedEnemyRes, ENEMY_SIZE,
+    spawn_enemy_at, spawn_ranged_enemy_at, spawn_health_bar_children,
+};
+
+// ── Components ─────────────────────────────────────────────────────────────
+
+/// A stationary, destructible enemy that emits waves while its room is active.
+/// Counts toward the room's `numofenemies` like any other enemy.
+#[derive(Component)]
+pub struct Nest {
+    pub room: usize,
+    pub wave_timer: Timer,
+    pub waves_emitted: u32,
+    pub health_multiplier: f32,
+    pub speed_bonus: f32,
+}
+
+/// Marks an enemy emitted by a nest so each nest can cap its live brood.
+#[derive(Component)]
+pub struct NestSpawn(pub Entity);
+
+// ── Tuning ─────────────────────────────────────────────────────────────────
+
+const NEST_HEALTH: f32 = 150.0;
+const NEST_COLOR: Color = Color::srgb(0.45, 0.15, 0.5);
+const FIRST_WAVE_DELAY: f32 = 2.5;
+const WAVE_INTERVAL: f32 = 7.0;
+const WAVE_SIZE: usize = 2;
+/// A nest stops emitting while this many of its spawns are still alive.
+const MAX_ALIVE_PER_NEST: usize = 4;
+/// Every Nth wave enemy is a ranger instead of a chaser.
+const RANGER_EVERY: u32 = 3;
+
+// ── Spawn ──────────────────────────────────────────────────────────────────
+
+pub fn spawn_at(
+    commands: &mut Commands,
+    at: Vec3,
+    room: usize,
+    health_multiplier: f32,
+    speed_bonus: f32,
+) -> Entity {
+    let hp = NEST_HEALTH * health_multiplier;
+    commands.spawn((
+        Sprite {
+            color: NEST_COLOR,
+            custom_size: Some(Vec2::splat(ENEMY_SIZE * 1.25)),
+            ..default()
+        },
+        Transform { translation: at, ..Default::default() },
+        Enemy,
+        Health::new(hp),
+        MaxHealth(hp),
+        Nest {
+            room,
+            wave_timer: Timer::from_seconds(FIRST_WAVE_DELAY, TimerMode::Once),
+            waves_emitted: 0,
+            health_multiplier,
+            speed_bonus,
+        },
+        GameEntity,
+    ))
+    .with_children(spawn_health_bar_children)
+    .id()
+}
+
+pub use spawn_at as spawn_nest_at;
+
+// ── Systems ────────────────────────────────────────────────────────────────
+
+/// Emits a wave from every nest in the active room when its timer runs out,
+/// adding each spawn to the room's `numofenemies` so clearing stays in sync.
+pub fn emit_waves(
+    mut commands: Commands,
+    time: Res<Time>,
+    lvlstate: Res<LevelState>,
+    mut rooms: ResMut<RoomVec>,
+    mut nests: Query<(Entity, &mut Transform, &mut Nest)>,
+    spawns: Query<&NestSpawn>,
+    enemy_res: Res<EnemyRes>,
+    ranged_res: Res<RangedEnemyRes>,
+    wall_grid: Res<crate::map::WallGrid>,
+    grid: Res<crate::map::MapGridMeta>,
+    mut rng: ResMut<RunRng>,
+) {
+    let LevelState::InRoom(index, _, _) = *lvlstate else { return };
+
+    for (nest_entity, mut tf, mut nest) in &mut nests {
+        if nest.room != index { continue; }
+
+        nest.wave_timer.tick(time.delta());
+        // Swell as the next wave approaches.
+        tf.scale = Vec3::splat(1.0 + 0.15 * nest.wave_timer.fraction());
+        if !nest.wave_timer.finished() { continue; }
+
+        nest.wave_timer = Timer::from_seconds(WAVE_INTERVAL, TimerMode::Once);
+        let alive = spawns.iter().filter(|s| s.0 == nest_entity).count();
+        let count = WAVE_SIZE.min(MAX_ALIVE_PER_NEST.saturating_sub(alive));
+
+        for i in 0..count {
+            let angle = rng.random::<f32>() * std::f32::consts::TAU;
+            let offset = Vec2::from_angle(angle) * TILE_SIZE * 1.5;
+            let floor = crate::room::nearest_floor_pos(tf.translation.truncate() + offset, &wall_grid, &grid);
+            let pos = floor.extend(tf.translation.z);
+
+            let ordinal = nest.waves_emitted * WAVE_SIZE as u32 + i as u32;
+            let enemy = if ordinal % RANGER_EVERY == RANGER_EVERY - 1 {
+                spawn_ranged_enemy_at(&mut commands, &ranged_res, pos, true, nest.health_multiplier, nest.speed_bonus)
+            } else {
+                spawn_enemy_at(&mut commands, &enemy_res, pos, true, nest.health_multiplier, nest.speed_bonus)
+            };
+            commands.entity(enemy).insert(NestSpawn(nest_entity));
+            rooms.0[index].numofenemies += 1;
+        }
+        nest.waves_emitted += 1;
+    }
+}
+
+/// In a destroy-the-nests room, once the last nest falls the remaining brood
+/// dies with it so `playing_room` can clear the room.
+pub fn collapse_orphaned_spawns(
+    lvlstate: Res<LevelState>,
+    rooms: Res<RoomVec>,
+    mut enemies: Query<
+        (&Transform, &mut Health, Option<&mut super::affix::AffixShield>),
+        (With<Enemy>, Without<Nest>, Without<Reaper>),
+    >,
+) {
+    let LevelState::InRoom(index, _, _) = *lvlstate else { return };
+    let room = &rooms.0[index];
+    if room.objective != RoomObjective::DestroyNests || room.nests_remaining > 0 {
+        return;
+    }
+    for (tf, mut hp, shield) in &mut enemies {
+        if room.bounds_check(tf.translation.truncate()) {
+            hp.0 = 0.0;
+            if let Some(mut shield) = shield {
+                shield.current = 0.0;
+            }
+        }
+    }
+}
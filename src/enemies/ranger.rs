@@ -5,7 +5,7 @@ use crate::fluiddynamics::PulledByFluid;
 use crate::player::Player;
 use crate::room::LevelState;
 use crate::collidable::Collider;
-use crate::bullet::{Bullet, BulletOwner, AnimationTimer, AnimationFrameCount};
+use crate::bullet::{Bullet, BulletOwner, BulletSource, AnimationTimer, AnimationFrameCount};
 use crate::weapons::{BulletDamage, EnemyBulletRes, WeaponSounds};
 use super::{Enemy, Velocity, ActiveEnemy, Health, MaxHealth, ENEMY_ACCEL, ENEMY_SPEED, ANIM_TIME, spawn_health_bar_children, Reaper};
 
@@ -44,6 +44,7 @@ pub struct RangedEnemyRes {
 
 #[derive(Event)]
 pub struct RangerShootEvent {
+    pub shooter: Entity,
     pub origin: Vec3,
     pub direction: Vec2,
     pub speed: f32,
@@ -76,7 +77,7 @@ pub fn spawn_at(
     active: bool,
     health_multiplier: f32,
     speed_bonus: f32,
-) {
+) -> Entity {
     let hp = 40.0 * health_multiplier;
     let mut e = commands.spawn((
         Sprite::from_image(res.right_frames[0].clone()),
@@ -107,6 +108,7 @@ pub fn spawn_at(
     if active {
         e.insert(ActiveEnemy);
     }
+    e.id()
 }
 
 // ── Systems ────────────────────────────────────────────────────────────────
@@ -143,23 +145,24 @@ pub fn ai(
     time: Res<Time>,
     player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
     mut enemies: Query<
-        (&Transform, &mut Velocity, &mut RangedEnemyAI, Option<&super::EnemyMoveSpeed>, Option<&super::EnemyPathfinder>),
+        (Entity, &Transform, &mut Velocity, &mut RangedEnemyAI, Option<&super::EnemyMoveSpeed>, Option<&super::EnemyPathfinder>, Option<&crate::status::StatusEffects>),
         (With<RangedEnemy>, Without<Reaper>),
     >,
     mut shoot_writer: EventWriter<RangerShootEvent>,
     lvlstate: Res<LevelState>,
+    decoy: Res<crate::items::DecoyTarget>,
 ) {
     let Ok(player_tf) = player_query.single() else { return };
-    let player_pos = player_tf.translation.truncate();
+    let player_pos = decoy.0.unwrap_or(player_tf.translation.truncate());
 
     let difficulty_mult: f32 = match *lvlstate {
         LevelState::InRoom(idx, _, _) | LevelState::EnteredRoom(idx) => 1.0 + (idx as f32 * 0.10),
         LevelState::NotRoom => 1.0,
     };
 
-    for (enemy_tf, mut vel, mut enemy_ai, spd_opt, pathfinder_opt) in &mut enemies {
+    for (entity, enemy_tf, mut vel, mut enemy_ai, spd_opt, pathfinder_opt, status_opt) in &mut enemies {
         let max_speed = spd_opt.map_or(ENEMY_SPEED, |s| s.0);
-        let scaled_dt = time.delta_secs() * difficulty_mult;
+        let scaled_dt = time.delta_secs() * difficulty_mult * crate::status::fire_rate_multiplier(status_opt);
         enemy_ai.fire_cooldown.tick(Duration::from_secs_f32(scaled_dt));
 
         let enemy_pos = enemy_tf.translation.truncate();
@@ -183,6 +186,7 @@ pub fn ai(
 
             if enemy_ai.fire_cooldown.finished() && dist <= enemy_ai.range {
                 shoot_writer.write(RangerShootEvent {
+                    shooter: entity,
                     origin: enemy_tf.translation,
                     direction: to_player,
                     speed: enemy_ai.projectile_speed,
@@ -226,6 +230,7 @@ pub fn spawn_ranger_bullets(
             crate::bullet::Velocity(dir * ev.speed),
             Bullet,
             BulletOwner::Enemy,
+            BulletSource(ev.shooter),
             Collider { half_extents: Vec2::splat(5.0) },
             BulletDamage(RANGER_BULLET_DAMAGE),
             AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
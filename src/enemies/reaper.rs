@@ -1,12 +1,15 @@
 use bevy::prelude::*;
-use rand::random_range;
+use rand::{Rng, random_range};
+use std::collections::HashSet;
 use std::f32::consts::TAU;
 
 use crate::bullet::{Bullet, BulletOwner, AnimationTimer, AnimationFrameCount};
 use crate::collidable::{Collidable, Collider};
-use crate::enemies::{ActiveEnemy, Enemy, Health, MaxHealth, RangedEnemy, RangedEnemyAI, Velocity, spawn_health_bar_children};
+use crate::enemies::{ActiveEnemy, Enemy, EnemyPathfinder, Health, MaxHealth, RangedEnemy, RangedEnemyAI, Velocity, spawn_health_bar_children};
+use crate::locale::tr;
 use crate::player::Player;
 use crate::room::{LevelState, RoomVec};
+use crate::run_history::{DamageSource, StatEvent};
 use crate::table;
 use crate::weapons::{BulletDamage, EnemyBulletRes, WeaponSounds};
 use crate::{GameState, TILE_SIZE, Z_ENTITIES};
@@ -21,12 +24,18 @@ pub struct Reaper;
 pub struct ReaperState {
     pub timer: Timer,
     pub current_room: Option<usize>,
-    pub spawned_in_room: Option<usize>,
-    /// True when the reaper spawned in the last uncleared room.
-    /// Prevents auto-despawn on room-clear so the player must kill it.
-    pub spawned_in_final_room: bool,
+    /// Set once the reaper has been summoned on this station. From then on it
+    /// hunts the player across rooms and hallways until it is killed.
+    pub summoned: bool,
+    /// True while at most one room is left uncleared. The reaper can only be
+    /// hurt then, so the station can't be won without facing it.
+    pub vulnerable: bool,
     pub summoning_circle: Option<Entity>,
     pub spawn_position: Option<Vec3>,
+    /// Seconds spent on this station; the reaper speeds up with it.
+    pub station_secs: f32,
+    /// Seconds left of being held back by a sealed door.
+    pub repelled_for: f32,
 }
 
 impl Default for ReaperState {
@@ -34,10 +43,12 @@ impl Default for ReaperState {
         Self {
             timer: Timer::from_seconds(7.0, TimerMode::Once),
             current_room: None,
-            spawned_in_room: None,
-            spawned_in_final_room: false,
+            summoned: false,
+            vulnerable: false,
             summoning_circle: None,
             spawn_position: None,
+            station_secs: 0.0,
+            repelled_for: 0.0,
         }
     }
 }
@@ -45,6 +56,28 @@ impl Default for ReaperState {
 #[derive(Resource)]
 pub struct ReaperRes {
     pub image: Handle<Image>,
+    pub warning_tick: Handle<AudioSource>,
+}
+
+// ── Pursuit tuning ─────────────────────────────────────────────────────────
+
+const REAPER_BASE_SPEED: f32 = crate::enemies::ENEMY_SPEED;
+/// Extra top speed gained per second spent on the station.
+const REAPER_SPEED_RAMP: f32 = 1.0;
+/// Stays below the player's base speed so outrunning it is always possible.
+const REAPER_MAX_SPEED: f32 = 420.0;
+/// Station-wide searches need a much larger budget than in-room chasers.
+const REAPER_PATH_NODES: usize = 4000;
+const REAPER_PATH_PAD: i32 = 48;
+const REPEL_SECS: f32 = 4.0;
+/// Half-size used for wall collision. A touch under a tile so the reaper can
+/// slip through one-tile doorways without catching on the frame.
+pub(super) const REAPER_MOVE_HALF: Vec2 = Vec2::splat(TILE_SIZE * 0.4);
+/// Distance at which the HUD warning starts to show.
+const WARN_RADIUS: f32 = 900.0;
+
+fn reaper_speed(station_secs: f32) -> f32 {
+    (REAPER_BASE_SPEED + station_secs * REAPER_SPEED_RAMP).min(REAPER_MAX_SPEED)
 }
 
 // ── Bullet stats ───────────────────────────────────────────────────────────
@@ -65,6 +98,13 @@ struct ReaperWarning {
     timer: Timer,
 }
 
+/// Full-screen red frame that pulses faster as the reaper closes in.
+#[derive(Component)]
+struct ReaperProximityFrame;
+
+#[derive(Component)]
+struct ReaperProximityText;
+
 #[derive(Component)]
 struct SummoningCircle {
     emit_timer: Timer,
@@ -85,16 +125,17 @@ impl Plugin for ReaperPlugin {
         app.init_resource::<ReaperState>()
             .add_event::<ReaperShootEvent>()
             .add_systems(Startup, load_reaper_assets)
+            .add_systems(OnEnter(GameState::Loading), reset_reaper_state)
+            .add_systems(OnEnter(GameState::Playing), spawn_proximity_hud)
             .add_systems(
                 Update,
                 (
                     reaper_room_timer,
                     reaper_warning_lifecycle,
-                    reaper_ai,
+                    reaper_ai.after(reaper_room_timer),
                     spawn_reaper_bullets.after(reaper_ai),
-                    bullet_hits_reaper,
                     table_hits_reaper,
-                    reaper_cleanup_system,
+                    update_proximity_warning.after(reaper_ai),
                     update_summoning_circle,
                     update_summoning_particles,
                 )
@@ -106,7 +147,15 @@ impl Plugin for ReaperPlugin {
 // ── Asset loading ──────────────────────────────────────────────────────────
 
 fn load_reaper_assets(mut commands: Commands, assets: Res<AssetServer>) {
-    commands.insert_resource(ReaperRes { image: assets.load("reaper/reaper1.png") });
+    commands.insert_resource(ReaperRes {
+        image: assets.load("reaper/reaper1.png"),
+        warning_tick: assets.load("audio/click.ogg"),
+    });
+}
+
+/// Each station gets a fresh, unsummoned reaper.
+fn reset_reaper_state(mut state: ResMut<ReaperState>) {
+    *state = ReaperState::default();
 }
 
 // ── Spawn ──────────────────────────────────────────────────────────────────
@@ -130,6 +179,7 @@ fn spawn_reaper(commands: &mut Commands, at: Vec3, res: &ReaperRes) {
         Collider { half_extents: Vec2::splat(TILE_SIZE * 0.5) },
         Collidable,
         crate::fluiddynamics::PulledByFluid { mass: 20.0 },
+        EnemyPathfinder::new(),
         GameEntity,
     )).with_children(|parent| spawn_health_bar_children(parent));
 }
@@ -157,7 +207,7 @@ fn spawn_reaper_warning(commands: &mut Commands, assets: &AssetServer) {
         ))
         .with_children(|root| {
             root.spawn((
-                Text::new("The Reaper has arrived!"),
+                Text::new(tr("reaper.arrived")),
                 TextFont { font, font_size: 40.0, ..default() },
                 TextColor(Color::srgb(1.0, 0.1, 0.1)),
             ));
@@ -202,7 +252,16 @@ fn reaper_room_timer(
     assets: Res<AssetServer>,
     wall_grid: Res<crate::map::WallGrid>,
     grid: Res<crate::map::MapGridMeta>,
+    mut rng: ResMut<crate::RunRng>,
 ) {
+    state.station_secs += time.delta_secs();
+    state.vulnerable = rooms.0.iter().filter(|r| !r.cleared).count() <= 1;
+
+    // Once summoned the reaper persists; there is only ever one per station.
+    if state.summoned {
+        return;
+    }
+
     let current_idx_opt = match *lvlstate {
         LevelState::InRoom(idx, _, _) => Some(idx),
         _ => None,
@@ -212,11 +271,10 @@ fn reaper_room_timer(
         Some(idx) => {
             if state.current_room != Some(idx) {
                 state.current_room = Some(idx);
-                state.spawned_in_room = None;
                 state.timer.reset();
 
                 if let Ok(player_tf) = player_q.single() {
-                    let angle = random_range(0.0..TAU);
+                    let angle = rng.random_range(0.0..TAU);
                     let offset = Vec2::new(angle.cos(), angle.sin()) * 220.0;
                     let raw_pos = player_tf.translation.truncate() + offset;
                     // Snap to nearest floor tile so the Reaper never spawns inside geometry.
@@ -231,9 +289,6 @@ fn reaper_room_timer(
                     state.summoning_circle = Some(circle);
                 }
             }
-            if state.spawned_in_room == Some(idx) {
-                return;
-            }
 
             state.timer.tick(time.delta());
             if state.timer.finished() {
@@ -247,20 +302,16 @@ fn reaper_room_timer(
                         }
                     }
                     state.spawn_position = None;
-                    // Mark whether this is the last uncleared room so cleanup
-                    // knows not to auto-despawn the reaper when the room clears.
-                    let uncleared = rooms.0.iter().filter(|r| !r.cleared).count();
-                    state.spawned_in_final_room = uncleared <= 1;
                     spawn_reaper(&mut commands, spawn_pos, &reaper_res);
                     spawn_reaper_warning(&mut commands, &assets);
-                    state.spawned_in_room = Some(idx);
+                    state.summoned = true;
                 }
             }
         }
         None => {
+            // Escaping the room before the summoning completes still cancels it.
             if state.current_room.is_some() {
                 state.current_room = None;
-                state.spawned_in_room = None;
                 state.timer.reset();
                 if let Some(circle) = state.summoning_circle.take() {
                     if let Ok(mut e) = commands.get_entity(circle) {
@@ -286,39 +337,6 @@ fn reaper_warning_lifecycle(
     }
 }
 
-fn bullet_hits_reaper(
-    mut commands: Commands,
-    bullet_query: Query<(&Transform, Entity, &BulletOwner), With<Bullet>>,
-    mut reaper_query: Query<(&Transform, &mut Health), With<Reaper>>,
-    state: Res<ReaperState>,
-) {
-    if !state.spawned_in_final_room {
-        return;
-    }
-
-    let bullet_half = Vec2::splat(TILE_SIZE * 0.5);
-    let reaper_half = Vec2::splat(TILE_SIZE * 0.5);
-
-    for (bullet_tf, bullet_entity, owner) in &bullet_query {
-        if !matches!(owner, &BulletOwner::Player) {
-            continue;
-        }
-        let bullet_pos = bullet_tf.translation;
-        for (reaper_tf, mut health) in &mut reaper_query {
-            let reaper_pos = reaper_tf.translation;
-            if crate::bullet::aabb_overlap(
-                bullet_pos.x, bullet_pos.y, bullet_half,
-                reaper_pos.x, reaper_pos.y, reaper_half,
-            ) {
-                health.0 -= 25.0;
-                if let Ok(mut entity) = commands.get_entity(bullet_entity) {
-                    entity.despawn();
-                }
-            }
-        }
-    }
-}
-
 fn table_hits_reaper(
     mut reaper_query: Query<(&Transform, &mut Health), With<Reaper>>,
     table_query: Query<
@@ -326,8 +344,9 @@ fn table_hits_reaper(
         With<table::Table>,
     >,
     state: Res<ReaperState>,
+    mut stat_events: EventWriter<StatEvent>,
 ) {
-    if !state.spawned_in_final_room {
+    if !state.vulnerable {
         return;
     }
 
@@ -345,88 +364,70 @@ fn table_hits_reaper(
                 let speed = vel_opt.map(|v| v.velocity.length()).unwrap_or(0.0);
                 if speed > 5.0 {
                     health.0 -= speed * 0.02;
+                    stat_events.write(StatEvent::DamageDealt(DamageSource::Table, speed * 0.02));
                 }
             }
         }
     }
 }
 
-fn reaper_cleanup_system(
-    mut commands: Commands,
-    lvlstate: Res<LevelState>,
-    rooms: Res<RoomVec>,
-    mut state: ResMut<ReaperState>,
-    reaper_q: Query<(Entity, &Health), With<Reaper>>,
-) {
-    let current_idx = if let LevelState::InRoom(idx, _, _) = *lvlstate {
-        Some(idx)
-    } else {
-        None
-    };
-
-    let room_cleared = current_idx
-        .and_then(|idx| rooms.0.get(idx))
-        .map(|r| r.cleared)
-        .unwrap_or(false);
-
-    // Left the room (lvlstate became NotRoom after room cleared or player exited)
-    let left_room = current_idx.is_none();
-
-    for (entity, health) in &reaper_q {
-        let is_dead = health.0 <= 0.0;
-
-        // Final-room reaper only dies when the player kills it — never auto-despawn.
-        // Non-final reapers auto-despawn when the room clears or the player leaves.
-        let should_despawn = is_dead
-            || (!state.spawned_in_final_room && (room_cleared || left_room));
-
-        if should_despawn {
-            commands.entity(entity).despawn();
-            state.spawned_in_room = None;
-            if is_dead {
-                state.spawned_in_final_room = false;
-            }
-        }
-    }
-
-    // Reset room tracking when leaving a non-final room
-    if left_room && !state.spawned_in_final_room {
-        state.current_room = None;
-        state.spawned_in_room = None;
-        state.timer.reset();
-        if let Some(circle) = state.summoning_circle.take() {
-            if let Ok(mut e) = commands.get_entity(circle) {
-                e.despawn();
-            }
-        }
-        state.spawn_position = None;
-    }
-}
-
+/// Hunts the player station-wide. Paths around walls and treats sealed doors
+/// as solid; if a sealed door leaves no route, the reaper is repelled and
+/// backs off for a few seconds before trying again.
 fn reaper_ai(
     time: Res<Time>,
+    mut state: ResMut<ReaperState>,
     player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
-    mut reapers: Query<(&Transform, &mut Velocity, &mut RangedEnemyAI), With<Reaper>>,
+    mut reapers: Query<(&Transform, &mut Velocity, &mut RangedEnemyAI, &mut EnemyPathfinder), With<Reaper>>,
+    sealed_doors: Query<&Transform, (With<crate::map::Door>, With<Collidable>, Without<Reaper>)>,
+    wall_grid: Res<crate::map::WallGrid>,
     mut shoot_writer: EventWriter<ReaperShootEvent>,
 ) {
     let Ok(player_tf) = player_query.single() else { return };
     let player_pos = player_tf.translation.truncate();
+    let speed = reaper_speed(state.station_secs);
+    let accel = crate::enemies::ENEMY_ACCEL * time.delta_secs();
+    state.repelled_for = (state.repelled_for - time.delta_secs()).max(0.0);
+
+    let sealed: HashSet<(i32, i32)> = sealed_doors
+        .iter()
+        .map(|tf| wall_grid.world_to_tile(tf.translation.truncate()))
+        .collect();
 
-    for (tf, mut vel, mut ai) in &mut reapers {
+    for (tf, mut vel, mut ai, mut pathfinder) in &mut reapers {
         ai.fire_cooldown.tick(time.delta());
 
         let pos = tf.translation.truncate();
         let diff = player_pos - pos;
         let dist = diff.length();
         if dist == 0.0 { continue; }
-
         let dir = diff / dist;
+
+        if state.repelled_for > 0.0 {
+            vel.velocity = (vel.velocity - dir * accel).clamp_length_max(speed * 0.5);
+            continue;
+        }
+
+        pathfinder.advance(pos);
+        pathfinder.timer.tick(time.delta());
+        if pathfinder.timer.just_finished()
+            && !pathfinder.repath(pos, player_pos, &wall_grid, &sealed, REAPER_PATH_NODES, REAPER_PATH_PAD)
+        {
+            state.repelled_for = REPEL_SECS;
+            continue;
+        }
+
+        if let Some(wp) = pathfinder.waypoints.first().copied() {
+            // No line of sight — follow the route through the station.
+            let to_wp = (wp - pos).normalize_or_zero();
+            vel.velocity = (vel.velocity + to_wp * accel).clamp_length_max(speed);
+            continue;
+        }
+
         let desired = ai.range * 0.75;
         let delta = dist - desired;
         let move_dir = if delta > 20.0 { dir } else if delta < -20.0 { -dir } else { Vec2::ZERO };
-
-        let accel = crate::enemies::ENEMY_ACCEL * time.delta_secs();
-        vel.velocity = (vel.velocity + move_dir * accel).clamp_length_max(crate::enemies::ENEMY_SPEED);
+        vel.velocity = (vel.velocity + move_dir * accel).clamp_length_max(speed);
 
         if ai.fire_cooldown.finished() && dist <= ai.range {
             shoot_writer.write(ReaperShootEvent {
@@ -439,6 +440,85 @@ fn reaper_ai(
     }
 }
 
+fn spawn_proximity_hud(mut commands: Commands, assets: Res<AssetServer>) {
+    let font: Handle<Font> = assets.load(crate::FONT_PATH);
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            border: UiRect::all(Val::Px(10.0)),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BorderColor(Color::srgba(1.0, 0.05, 0.05, 0.0)),
+        Visibility::Hidden,
+        ZIndex(5),
+        ReaperProximityFrame,
+        GameEntity,
+    ))
+    .with_children(|root| {
+        root.spawn((
+            Text::new(tr("reaper.label")),
+            TextFont { font, font_size: 18.0, ..default() },
+            TextColor(Color::srgb(1.0, 0.15, 0.15)),
+            Node { margin: UiRect::top(Val::Px(14.0)), ..default() },
+            ReaperProximityText,
+        ));
+    });
+}
+
+/// Pulses the red frame and ticks a warning sound, both faster as the reaper
+/// closes in. Notes on the HUD while a sealed door is holding it back.
+fn update_proximity_warning(
+    mut commands: Commands,
+    time: Res<Time>,
+    state: Res<ReaperState>,
+    res: Res<ReaperRes>,
+    player_q: Query<&Transform, With<Player>>,
+    reaper_q: Query<&Transform, With<Reaper>>,
+    mut frame_q: Query<(&mut BorderColor, &mut Visibility), With<ReaperProximityFrame>>,
+    mut text_q: Query<&mut Text, With<ReaperProximityText>>,
+    mut since_tick: Local<f32>,
+) {
+    let Ok((mut border, mut vis)) = frame_q.single_mut() else { return };
+    let closeness = match (player_q.single(), reaper_q.single()) {
+        (Ok(p), Ok(r)) => {
+            let d = p.translation.truncate().distance(r.translation.truncate());
+            (1.0 - d / WARN_RADIUS).clamp(0.0, 1.0)
+        }
+        _ => 0.0,
+    };
+
+    if closeness <= 0.0 {
+        *vis = Visibility::Hidden;
+        *since_tick = 0.0;
+        return;
+    }
+    *vis = Visibility::Inherited;
+
+    let t = time.elapsed_secs();
+    let pulse = 0.5 + 0.5 * (t * (3.0 + 9.0 * closeness)).sin();
+    border.0 = Color::srgba(1.0, 0.05, 0.05, closeness * (0.3 + 0.5 * pulse));
+
+    if let Ok(mut text) = text_q.single_mut() {
+        text.0 = tr(if state.repelled_for > 0.0 { "reaper.repelled" } else { "reaper.label" });
+    }
+
+    *since_tick += time.delta_secs();
+    let interval = 1.2 - 0.95 * closeness;
+    if *since_tick >= interval {
+        *since_tick = 0.0;
+        commands.spawn((
+            AudioPlayer::new(res.warning_tick.clone()),
+            PlaybackSettings {
+                volume: bevy::audio::Volume::Linear(0.15 + 0.45 * closeness),
+                ..PlaybackSettings::DESPAWN
+            },
+        ));
+    }
+}
+
 fn spawn_reaper_bullets(
     mut commands: Commands,
     mut events: EventReader<ReaperShootEvent>,
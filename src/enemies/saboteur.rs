@@ -0,0 +1,221 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::{GameEntity, TILE_SIZE};
+use crate::collidable::Collidable;
+use crate::fluiddynamics::PulledByFluid;
+use crate::player::Player;
+use crate::window;
+use super::{
+    Enemy, Velocity, ActiveEnemy, Health, MaxHealth, EnemyPathfinder, TableBlockedTiles,
+    EnemyRes, AnimationTimer, EnemyFrames, ENEMY_ACCEL, ENEMY_SPEED, ANIM_TIME,
+    spawn_health_bar_children,
+};
+
+// ── Components ─────────────────────────────────────────────────────────────
+
+/// An enemy that ignores the player while there is glass nearby to break.
+#[derive(Component)]
+pub struct SaboteurEnemy;
+
+#[derive(Component)]
+pub struct SaboteurAI {
+    /// Window currently being walked to / smashed.
+    pub target: Option<Entity>,
+    pub attack_timer: Timer,
+}
+
+// ── Tuning ─────────────────────────────────────────────────────────────────
+
+const SABOTEUR_HEALTH: f32 = 40.0;
+const SABOTEUR_SPEED: f32 = ENEMY_SPEED * 0.85;
+const SABOTEUR_TINT: Color = Color::srgb(1.0, 0.55, 0.25);
+
+/// Only windows within this many tiles are considered targets.
+const SEARCH_RADIUS: f32 = TILE_SIZE * 14.0;
+/// Distance to the window centre at which the saboteur stops and attacks.
+const ATTACK_RANGE: f32 = TILE_SIZE * 1.3;
+const ATTACK_INTERVAL: f32 = 0.6;
+/// Glass starts at 50 HP, so an uninterrupted saboteur breaks a pane in ~3s.
+const ATTACK_DAMAGE: f32 = 10.0;
+
+// ── Spawn ──────────────────────────────────────────────────────────────────
+
+pub fn spawn_at(
+    commands: &mut Commands,
+    res: &EnemyRes,
+    at: Vec3,
+    active: bool,
+    health_multiplier: f32,
+    speed_bonus: f32,
+) -> Entity {
+    let hp = SABOTEUR_HEALTH * health_multiplier;
+    let mut sprite = Sprite::from_image(res.frames[0].clone());
+    sprite.color = SABOTEUR_TINT;
+    let mut e = commands.spawn((
+        sprite,
+        Transform { translation: at, ..Default::default() },
+        Enemy,
+        SaboteurEnemy,
+        Velocity::new(),
+        Health::new(hp),
+        MaxHealth(hp),
+        super::EnemyMoveSpeed(SABOTEUR_SPEED + speed_bonus),
+        AnimationTimer(Timer::from_seconds(ANIM_TIME, TimerMode::Repeating)),
+        EnemyFrames { handles: res.frames.clone(), index: 0 },
+        SaboteurAI {
+            target: None,
+            attack_timer: Timer::from_seconds(ATTACK_INTERVAL, TimerMode::Repeating),
+        },
+        EnemyPathfinder::new(),
+        PulledByFluid { mass: 10.0 },
+        GameEntity,
+    ));
+    e.with_children(spawn_health_bar_children);
+    if active {
+        e.insert(ActiveEnemy);
+    }
+    e.id()
+}
+
+pub use spawn_at as spawn_saboteur_enemy_at;
+
+// ── Systems ────────────────────────────────────────────────────────────────
+
+/// Picks the nearest intact window it can path to and chips away at its
+/// `window::Health`. Falls back to chasing the player once no glass is in reach.
+pub fn ai(
+    time: Res<Time>,
+    player_q: Query<&Transform, (With<Player>, Without<Enemy>)>,
+    mut saboteurs: Query<
+        (&Transform, &mut Velocity, &mut SaboteurAI, &mut EnemyPathfinder, Option<&super::EnemyMoveSpeed>, Option<&crate::status::StatusEffects>),
+        (With<SaboteurEnemy>, With<ActiveEnemy>),
+    >,
+    mut windows: Query<(Entity, &Transform, &mut window::Health, &window::GlassState), (With<window::Window>, Without<Enemy>)>,
+    wall_grid: Res<crate::map::WallGrid>,
+    blocked_cache: Res<TableBlockedTiles>,
+    sealed_doors: Query<&Transform, (With<crate::map::Door>, With<Collidable>, Without<Enemy>)>,
+) {
+    let Ok(player_tf) = player_q.single() else { return };
+    let player_pos = player_tf.translation.truncate();
+    let accel = ENEMY_ACCEL * time.delta_secs();
+    // Tables and sealed doors both stand between a saboteur and the glass.
+    let mut blocked = blocked_cache.tiles.clone();
+    blocked.extend(sealed_doors.iter().map(|tf| wall_grid.world_to_tile(tf.translation.truncate())));
+    let blocked = &blocked;
+
+    for (tf, mut vel, mut ai, mut pathfinder, spd_opt, status_opt) in &mut saboteurs {
+        let pos = tf.translation.truncate();
+        let max_speed = spd_opt.map_or(SABOTEUR_SPEED, |s| s.0);
+        pathfinder.timer.tick(time.delta());
+        let repath_due = pathfinder.timer.just_finished();
+
+        // Drop the target once it's broken or gone.
+        let still_intact = ai.target
+            .and_then(|t| windows.get(t).ok())
+            .is_some_and(|(_, _, _, state)| *state == window::GlassState::Intact);
+        let lost_target = ai.target.is_some() && !still_intact;
+        if lost_target {
+            ai.target = None;
+            pathfinder.waypoints.clear();
+            ai.attack_timer.reset();
+        }
+
+        // While chasing the player, look again on every repath in case a
+        // door has opened a route to some glass.
+        let mut retargeted = false;
+        if ai.target.is_none() && (lost_target || repath_due) {
+            let candidates = windows
+                .iter()
+                .filter(|(_, _, _, state)| **state == window::GlassState::Intact)
+                .map(|(e, wtf, _, _)| (e, wtf.translation.truncate()));
+            ai.target = nearest_reachable_window(pos, candidates, &mut pathfinder, &wall_grid, blocked);
+            retargeted = ai.target.is_some();
+        }
+
+        let goal_pos = ai.target
+            .and_then(|t| windows.get(t).ok())
+            .map_or(player_pos, |(_, wtf, _, _)| wtf.translation.truncate());
+
+        // At the glass: stand still and smash it.
+        if let Some(target) = ai.target
+            && pos.distance(goal_pos) <= ATTACK_RANGE
+        {
+            pathfinder.waypoints.clear();
+            **vel = vel.move_towards(Vec2::ZERO, accel);
+            ai.attack_timer.tick(time.delta().mul_f32(crate::status::fire_rate_multiplier(status_opt)));
+            if ai.attack_timer.just_finished()
+                && let Ok((_, _, mut hp, _)) = windows.get_mut(target)
+            {
+                hp.0 -= ATTACK_DAMAGE;
+            }
+            continue;
+        }
+
+        pathfinder.advance(pos);
+
+        if repath_due && !retargeted
+            && !pathfinder.repath(pos, goal_pos, &wall_grid, blocked, super::PATH_MAX_NODES, super::PATH_SEARCH_PAD)
+        {
+            // A table or sealed door cut the route; pick again next repath.
+            ai.target = None;
+        }
+
+        let steer_to = pathfinder.waypoints.first().copied().unwrap_or(goal_pos);
+        let dir = (steer_to - pos).normalize_or_zero();
+        **vel = (**vel + dir * accel).clamp_length_max(max_speed);
+    }
+}
+
+/// The nearest window within `SEARCH_RADIUS` that A* can reach, trying
+/// candidates in distance order. Leaves `pathfinder` routed to the pick.
+fn nearest_reachable_window(
+    pos: Vec2,
+    candidates: impl Iterator<Item = (Entity, Vec2)>,
+    pathfinder: &mut EnemyPathfinder,
+    wall_grid: &crate::map::WallGrid,
+    blocked: &HashSet<(i32, i32)>,
+) -> Option<Entity> {
+    let mut in_range: Vec<(Entity, Vec2, f32)> = candidates
+        .map(|(e, wpos)| (e, wpos, wpos.distance_squared(pos)))
+        .filter(|&(_, _, d2)| d2 <= SEARCH_RADIUS * SEARCH_RADIUS)
+        .collect();
+    in_range.sort_by(|a, b| a.2.total_cmp(&b.2));
+    let found = in_range.into_iter().find(|&(_, wpos, _)| {
+        pathfinder.repath(pos, wpos, wall_grid, blocked, super::PATH_MAX_NODES, super::PATH_SEARCH_PAD)
+    });
+    if found.is_none() {
+        pathfinder.waypoints.clear();
+    }
+    found.map(|(e, _, _)| e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walled_off_window_is_skipped_for_a_reachable_one() {
+        // The near window sits in a ring of wall; the far one is in the open.
+        let (near_tile, far_tile) = ((3, 0), (-8, 0));
+        let mut walls = vec![near_tile];
+        for dc in -1..=1 {
+            for dr in -1..=1 {
+                if (dc, dr) != (0, 0) {
+                    walls.push((near_tile.0 + dc, near_tile.1 + dr));
+                }
+            }
+        }
+        let wall_grid = crate::map::WallGrid::from_tiles(&walls);
+
+        let mut world = World::new();
+        let (near, far) = (world.spawn_empty().id(), world.spawn_empty().id());
+        let candidates = [
+            (near, wall_grid.tile_to_world(near_tile.0, near_tile.1)),
+            (far, wall_grid.tile_to_world(far_tile.0, far_tile.1)),
+        ];
+
+        let mut pathfinder = EnemyPathfinder::new();
+        let pick = nearest_reachable_window(Vec2::ZERO, candidates.into_iter(), &mut pathfinder, &wall_grid, &HashSet::new());
+        assert_eq!(pick, Some(far));
+    }
+}
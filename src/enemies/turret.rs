@@ -5,7 +5,7 @@ use crate::fluiddynamics::PulledByFluid;
 use crate::player::Player;
 use crate::room::LevelState;
 use crate::collidable::Collider;
-use crate::bullet::{Bullet, BulletOwner, AnimationTimer, AnimationFrameCount};
+use crate::bullet::{Bullet, BulletOwner, BulletSource, AnimationTimer, AnimationFrameCount};
 use crate::weapons::{BulletDamage, EnemyBulletRes, WeaponSounds};
 use super::{Enemy, Velocity, ActiveEnemy, Health, MaxHealth, ENEMY_ACCEL, ENEMY_SPEED, ANIM_TIME, spawn_health_bar_children};
 
@@ -41,6 +41,7 @@ pub struct TurretRes {
 
 #[derive(Event)]
 pub struct TurretShootEvent {
+    pub shooter: Entity,
     pub origin: Vec3,
     pub speed: f32,
     /// Current animation frame index (0-7). Odd = 45-degree rotated frame.
@@ -75,7 +76,7 @@ pub fn spawn_at(
     active: bool,
     health_multiplier: f32,
     speed_bonus: f32,
-) {
+) -> Entity {
     let hp = 60.0 * health_multiplier;
     let mut e = commands.spawn((
         Sprite::from_image(res.frames[0].clone()),
@@ -101,6 +102,7 @@ pub fn spawn_at(
     if active {
         e.insert(ActiveEnemy);
     }
+    e.id()
 }
 
 pub use spawn_at as spawn_turret_enemy_at;
@@ -128,29 +130,32 @@ pub fn ai(
     player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
     mut enemies: Query<
         (
+            Entity,
             &Transform,
             &mut Velocity,
             &mut TurretAI,
             &TurretFrames,
             Option<&super::EnemyMoveSpeed>,
             Option<&super::EnemyPathfinder>,
+            Option<&crate::status::StatusEffects>,
         ),
         With<TurretEnemy>,
     >,
     mut shoot_writer: EventWriter<TurretShootEvent>,
     lvlstate: Res<LevelState>,
+    decoy: Res<crate::items::DecoyTarget>,
 ) {
     let Ok(player_tf) = player_query.single() else { return };
-    let player_pos = player_tf.translation.truncate();
+    let player_pos = decoy.0.unwrap_or(player_tf.translation.truncate());
 
     let difficulty_mult: f32 = match *lvlstate {
         LevelState::InRoom(idx, _, _) | LevelState::EnteredRoom(idx) => 1.0 + (idx as f32 * 0.10),
         LevelState::NotRoom => 1.0,
     };
 
-    for (enemy_tf, mut vel, mut ai, frames, spd_opt, pathfinder_opt) in &mut enemies {
+    for (entity, enemy_tf, mut vel, mut ai, frames, spd_opt, pathfinder_opt, status_opt) in &mut enemies {
         let max_speed = spd_opt.map_or(TURRET_SPEED, |s| s.0);
-        let scaled_dt = time.delta_secs() * difficulty_mult;
+        let scaled_dt = time.delta_secs() * difficulty_mult * crate::status::fire_rate_multiplier(status_opt);
         ai.fire_cooldown.tick(Duration::from_secs_f32(scaled_dt));
 
         let enemy_pos = enemy_tf.translation.truncate();
@@ -178,6 +183,7 @@ pub fn ai(
 
             if ai.fire_cooldown.finished() && dist <= ai.range {
                 shoot_writer.write(TurretShootEvent {
+                    shooter: entity,
                     origin: enemy_tf.translation,
                     speed: ai.projectile_speed,
                     frame_index: frames.index,
@@ -223,6 +229,7 @@ pub fn spawn_turret_bullets(
                 crate::bullet::Velocity(dir * ev.speed),
                 Bullet,
                 BulletOwner::Enemy,
+                BulletSource(ev.shooter),
                 Collider { half_extents: Vec2::splat(5.0) },
                 BulletDamage(TURRET_BULLET_DAMAGE),
                 AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+use crate::{GameEntity, TILE_SIZE};
+use crate::enemies::ActiveEnemy;
+use crate::enemies::turret::TurretEnemy;
+use crate::locale::{tr, tr_args};
+use crate::player::{Player, ThrusterFuel};
+use crate::rewards::{RewardFont, RewardPopup};
+use crate::status::{ApplyStatus, StatusKind};
+use crate::window::{GlassState, Health, Window};
+use super::{ItemKind, ItemSlots, UseItemEvent};
+
+// ── Components ───────────────────────────────────────────────────────────────
+
+/// A deployed decoy beacon. Despawns when the timer runs out.
+#[derive(Component)]
+pub struct Decoy(pub Timer);
+
+/// Where enemies should aim instead of the player while a decoy is out.
+#[derive(Resource, Default)]
+pub struct DecoyTarget(pub Option<Vec2>);
+
+/// On the player while an air bubble is up: no tank drain and no breach suction.
+#[derive(Component)]
+pub struct AirBubble(pub Timer);
+
+// ── Tuning ───────────────────────────────────────────────────────────────────
+
+const DECOY_DURATION: f32 = 6.0;
+const DECOY_SIZE: f32 = 14.0;
+const EMP_RADIUS: f32 = TILE_SIZE * 8.0;
+const EMP_STUN: f32 = 4.0;
+const PATCH_RANGE: f32 = TILE_SIZE * 3.0;
+/// Matches the health windows spawn with.
+const PATCH_HEALTH: f32 = 50.0;
+const AIR_BUBBLE_DURATION: f32 = 8.0;
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+fn popup(commands: &mut Commands, font: &Handle<Font>, at: Vec2, text: String, color: Color) {
+    commands.spawn((
+        Text2d::new(text),
+        TextFont { font: font.clone(), font_size: 18.0, ..default() },
+        TextColor(color),
+        Transform::from_translation(Vec3::new(at.x, at.y + TILE_SIZE, 10.0)),
+        RewardPopup { timer: Timer::from_seconds(1.5, TimerMode::Once) },
+        GameEntity,
+    ));
+}
+
+// ── Effects ──────────────────────────────────────────────────────────────────
+
+pub fn deploy_decoy(mut commands: Commands, mut events: EventReader<UseItemEvent>) {
+    for ev in events.read().filter(|e| e.kind == ItemKind::DecoyBeacon) {
+        commands.spawn((
+            Sprite {
+                color: ItemKind::DecoyBeacon.color(),
+                custom_size: Some(Vec2::splat(DECOY_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(ev.pos.extend(crate::Z_ENTITIES)),
+            Decoy(Timer::from_seconds(DECOY_DURATION, TimerMode::Once)),
+            GameEntity,
+        ));
+    }
+}
+
+pub fn fire_emp(
+    mut commands: Commands,
+    mut events: EventReader<UseItemEvent>,
+    turrets: Query<(Entity, &Transform), (With<TurretEnemy>, With<ActiveEnemy>)>,
+    mut statuses: EventWriter<ApplyStatus>,
+    font: Res<RewardFont>,
+) {
+    for ev in events.read().filter(|e| e.kind == ItemKind::Emp) {
+        let mut hit = 0;
+        for (entity, tf) in &turrets {
+            if tf.translation.truncate().distance(ev.pos) <= EMP_RADIUS {
+                statuses.write(ApplyStatus { target: entity, kind: StatusKind::Stun, duration: EMP_STUN, stacks: 1 });
+                hit += 1;
+            }
+        }
+        let text = if hit == 0 { ItemKind::Emp.name() } else { tr_args("item.emp_hits", &[("name", &ItemKind::Emp.name()), ("hits", &hit)]) };
+        popup(&mut commands, &font.0, ev.pos, text, ItemKind::Emp.color());
+    }
+}
+
+/// Seals the nearest broken window in reach. With nothing to patch the item
+/// goes back in the hotbar.
+pub fn apply_breach_patch(
+    mut commands: Commands,
+    mut events: EventReader<UseItemEvent>,
+    mut windows: Query<(&mut Health, &GlassState, &Transform), With<Window>>,
+    mut player_q: Query<&mut ItemSlots, With<Player>>,
+    font: Res<RewardFont>,
+) {
+    for ev in events.read().filter(|e| e.kind == ItemKind::BreachPatch) {
+        let nearest = windows
+            .iter_mut()
+            .filter(|(_, state, tf)| **state == GlassState::Broken && tf.translation.truncate().distance(ev.pos) <= PATCH_RANGE)
+            .min_by(|a, b| {
+                let da = a.2.translation.truncate().distance_squared(ev.pos);
+                let db = b.2.translation.truncate().distance_squared(ev.pos);
+                da.total_cmp(&db)
+            });
+
+        if let Some((mut health, _, _)) = nearest {
+            health.0 = PATCH_HEALTH;
+            popup(&mut commands, &font.0, ev.pos, tr("item.breach_sealed"), ItemKind::BreachPatch.color());
+        } else {
+            if let Ok(mut slots) = player_q.single_mut() {
+                slots.add_consumable(ItemKind::BreachPatch);
+            }
+            popup(&mut commands, &font.0, ev.pos, tr("item.no_breach"), Color::srgb(0.7, 0.7, 0.7));
+        }
+    }
+}
+
+pub fn apply_stim(
+    mut events: EventReader<UseItemEvent>,
+    mut player_q: Query<&mut ThrusterFuel, With<Player>>,
+) {
+    for _ in events.read().filter(|e| e.kind == ItemKind::Stim) {
+        if let Ok(mut fuel) = player_q.single_mut() {
+            fuel.current = fuel.max;
+        }
+    }
+}
+
+pub fn start_air_bubble(
+    mut commands: Commands,
+    mut events: EventReader<UseItemEvent>,
+    player_q: Query<Entity, With<Player>>,
+) {
+    for _ in events.read().filter(|e| e.kind == ItemKind::AirBubble) {
+        if let Ok(player) = player_q.single() {
+            commands.entity(player).insert(AirBubble(Timer::from_seconds(AIR_BUBBLE_DURATION, TimerMode::Once)));
+        }
+    }
+}
+
+// ── Upkeep ───────────────────────────────────────────────────────────────────
+
+/// Expires decoys and points `DecoyTarget` at the newest one still out.
+pub fn tick_decoys(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut decoys: Query<(Entity, &Transform, &mut Decoy, &mut Sprite)>,
+    mut target: ResMut<DecoyTarget>,
+) {
+    let mut newest: Option<(f32, Vec2)> = None;
+    for (entity, tf, mut decoy, mut sprite) in &mut decoys {
+        decoy.0.tick(time.delta());
+        if decoy.0.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        // Blink so it reads as a beacon.
+        let pulse = 0.6 + 0.4 * (decoy.0.elapsed_secs() * 8.0).sin().abs();
+        sprite.color = sprite.color.with_alpha(pulse);
+
+        let left = decoy.0.remaining_secs();
+        if newest.is_none_or(|(best, _)| left > best) {
+            newest = Some((left, tf.translation.truncate()));
+        }
+    }
+    target.0 = newest.map(|(_, pos)| pos);
+}
+
+pub fn tick_air_bubbles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut player_q: Query<(Entity, &mut AirBubble)>,
+) {
+    for (entity, mut bubble) in &mut player_q {
+        bubble.0.tick(time.delta());
+        if bubble.0.finished() {
+            commands.entity(entity).remove::<AirBubble>();
+        }
+    }
+}
+
+pub fn clear_decoy_target(mut target: ResMut<DecoyTarget>) {
+    target.0 = None;
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use crate::GameEntity;
+use crate::locale::tr_args;
+use crate::player::Player;
+use crate::settings::KeyBindings;
+use super::{AirBubble, HOTBAR_SLOTS, ItemSlots};
+
+// ── HUD ─────────────────────────────────────────────────────────────────────
+
+const READY_COLOR: Color = Color::WHITE;
+const COOLDOWN_COLOR: Color = Color::srgb(0.5, 0.5, 0.55);
+const EMPTY_COLOR: Color = Color::srgb(0.35, 0.35, 0.4);
+const BUBBLE_COLOR: Color = Color::srgb(0.4, 0.9, 1.0);
+
+/// Active item name with its key and remaining cooldown.
+#[derive(Component)]
+pub struct ActiveItemText;
+
+/// One hotbar slot readout. Index is the 0-based slot.
+#[derive(Component)]
+pub struct HotbarSlotText(usize);
+
+/// Seconds left on the air bubble, blank when none is up.
+#[derive(Component)]
+pub struct AirBubbleText;
+
+pub fn spawn_item_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font: Handle<Font> = asset_server.load(crate::FONT_PATH);
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                right: Val::Px(10.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexEnd,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            GameEntity,
+        ))
+        .with_children(|col| {
+            col.spawn((
+                Text::new(""),
+                TextFont { font: font.clone(), font_size: 16.0, ..default() },
+                TextColor(BUBBLE_COLOR),
+                AirBubbleText,
+            ));
+            col.spawn((
+                Text::new(""),
+                TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                TextColor(EMPTY_COLOR),
+                ActiveItemText,
+            ));
+            col.spawn((Node { column_gap: Val::Px(12.0), ..default() },))
+                .with_children(|row| {
+                    for i in 0..HOTBAR_SLOTS {
+                        row.spawn((
+                            Text::new(""),
+                            TextFont { font: font.clone(), font_size: 16.0, ..default() },
+                            TextColor(EMPTY_COLOR),
+                            HotbarSlotText(i),
+                        ));
+                    }
+                });
+        });
+}
+
+pub fn update_item_hud(
+    player_q: Query<(&ItemSlots, Option<&AirBubble>), With<Player>>,
+    bindings: Res<KeyBindings>,
+    mut active_q: Query<(&mut Text, &mut TextColor), (With<ActiveItemText>, Without<HotbarSlotText>, Without<AirBubbleText>)>,
+    mut slot_q: Query<(&HotbarSlotText, &mut Text, &mut TextColor), (Without<ActiveItemText>, Without<AirBubbleText>)>,
+    mut bubble_q: Query<&mut Text, (With<AirBubbleText>, Without<ActiveItemText>, Without<HotbarSlotText>)>,
+) {
+    let Ok((slots, bubble)) = player_q.single() else { return };
+
+    if let Ok((mut text, mut color)) = active_q.single_mut() {
+        let key = KeyBindings::display_name(bindings.active_item);
+        (text.0, color.0) = match slots.active {
+            None => (format!("[{key}] -"), EMPTY_COLOR),
+            Some(kind) if slots.cooldown > 0.0 => {
+                (format!("[{key}] {} {}s", kind.name(), slots.cooldown.ceil() as u32), COOLDOWN_COLOR)
+            }
+            Some(kind) => (format!("[{key}] {}", kind.name()), READY_COLOR),
+        };
+    }
+
+    let keys = [bindings.hotbar_1, bindings.hotbar_2, bindings.hotbar_3];
+    for (slot, mut text, mut color) in &mut slot_q {
+        let key = KeyBindings::display_name(keys[slot.0]);
+        (text.0, color.0) = match slots.hotbar[slot.0] {
+            Some(stack) => (format!("[{key}] {} x{}", stack.kind.name(), stack.count), stack.kind.color()),
+            None => (format!("[{key}] -"), EMPTY_COLOR),
+        };
+    }
+
+    if let Ok(mut text) = bubble_q.single_mut() {
+        text.0 = bubble.map_or_else(String::new, |b| tr_args("item.air_bubble_timer", &[("secs", &(b.0.remaining_secs().ceil() as u32))]));
+    }
+}
@@ -0,0 +1,320 @@
+pub mod effects;
+pub mod hud;
+
+use bevy::prelude::*;
+use rand::Rng;
+use crate::{GameEntity, GameState, RunRng, TILE_SIZE};
+use crate::locale::tr;
+use crate::player::{Player, aabb_overlap};
+use crate::rewards::{RewardFont, RewardPopup};
+use crate::replay::{Action, PlayerActions};
+
+pub use effects::{AirBubble, DecoyTarget};
+
+// ── Items ────────────────────────────────────────────────────────────────────
+
+/// Something the player triggers by hand. Active items recharge on a cooldown;
+/// consumables sit in the hotbar and are used up.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum ItemKind {
+    /// Active: drops a beacon that enemies chase instead of the player.
+    DecoyBeacon,
+    /// Active: stuns every turret nearby.
+    Emp,
+    /// Consumable: seals the nearest broken window.
+    BreachPatch,
+    /// Consumable: refills thruster fuel.
+    Stim,
+    /// Consumable: no air drain or breach suction for a few seconds.
+    AirBubble,
+}
+
+impl ItemKind {
+    pub const ALL: [ItemKind; 5] = [
+        ItemKind::DecoyBeacon,
+        ItemKind::Emp,
+        ItemKind::BreachPatch,
+        ItemKind::Stim,
+        ItemKind::AirBubble,
+    ];
+
+    pub fn name(self) -> String {
+        tr(match self {
+            ItemKind::DecoyBeacon => "item.decoy_beacon",
+            ItemKind::Emp         => "item.emp",
+            ItemKind::BreachPatch => "item.breach_patch",
+            ItemKind::Stim        => "item.stim",
+            ItemKind::AirBubble   => "item.air_bubble",
+        })
+    }
+
+    /// Single-letter tag for the pickup sprite.
+    pub fn letter(self) -> &'static str {
+        match self {
+            ItemKind::DecoyBeacon => "D",
+            ItemKind::Emp         => "E",
+            ItemKind::BreachPatch => "P",
+            ItemKind::Stim        => "S",
+            ItemKind::AirBubble   => "A",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            ItemKind::DecoyBeacon => Color::srgb(1.0, 0.6, 0.2),
+            ItemKind::Emp         => Color::srgb(0.5, 0.7, 1.0),
+            ItemKind::BreachPatch => Color::srgb(0.7, 0.9, 0.9),
+            ItemKind::Stim        => Color::srgb(1.0, 0.4, 0.5),
+            ItemKind::AirBubble   => Color::srgb(0.4, 0.9, 1.0),
+        }
+    }
+
+    /// Active items go in the active slot; everything else stacks in the hotbar.
+    pub fn is_active(self) -> bool {
+        matches!(self, ItemKind::DecoyBeacon | ItemKind::Emp)
+    }
+
+    /// Recharge time in seconds after using an active item.
+    pub fn cooldown(self) -> f32 {
+        match self {
+            ItemKind::DecoyBeacon => DECOY_COOLDOWN,
+            ItemKind::Emp         => EMP_COOLDOWN,
+            _ => 0.0,
+        }
+    }
+
+    fn random(rng: &mut RunRng) -> Self {
+        Self::ALL[rng.random_range(0..Self::ALL.len())]
+    }
+}
+
+// ── Components ───────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ItemStack {
+    pub kind: ItemKind,
+    pub count: u32,
+}
+
+/// The player's active-item slot and consumable hotbar.
+#[derive(Component, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ItemSlots {
+    pub active: Option<ItemKind>,
+    /// Seconds until the active item can be used again.
+    pub cooldown: f32,
+    pub hotbar: [Option<ItemStack>; HOTBAR_SLOTS],
+}
+
+impl ItemSlots {
+    /// Tops up a matching stack, else fills the first empty slot.
+    /// Returns false when there's no room.
+    pub fn add_consumable(&mut self, kind: ItemKind) -> bool {
+        if let Some(stack) = self.hotbar.iter_mut().flatten().find(|s| s.kind == kind && s.count < MAX_STACK) {
+            stack.count += 1;
+            return true;
+        }
+        if let Some(slot) = self.hotbar.iter_mut().find(|s| s.is_none()) {
+            *slot = Some(ItemStack { kind, count: 1 });
+            return true;
+        }
+        false
+    }
+
+    /// Takes one item out of a hotbar slot, clearing it once empty.
+    fn take(&mut self, slot: usize) -> Option<ItemKind> {
+        let entry = self.hotbar.get_mut(slot)?;
+        let stack = entry.as_mut()?;
+        let kind = stack.kind;
+        stack.count -= 1;
+        if stack.count == 0 {
+            *entry = None;
+        }
+        Some(kind)
+    }
+}
+
+/// An item lying on the floor, waiting to be walked over.
+#[derive(Component)]
+pub struct ItemPickup(pub ItemKind);
+
+/// Fired when the player triggers an item; handled in `effects`.
+#[derive(Event)]
+pub struct UseItemEvent {
+    pub kind: ItemKind,
+    pub pos: Vec2,
+}
+
+// ── Tuning ───────────────────────────────────────────────────────────────────
+
+pub const HOTBAR_SLOTS: usize = 3;
+const MAX_STACK: u32 = 3;
+const DECOY_COOLDOWN: f32 = 20.0;
+const EMP_COOLDOWN: f32 = 25.0;
+/// Chance a cleared room leaves an item next to the heart.
+const ROOM_DROP_CHANCE: f32 = 0.35;
+const PICKUP_SIZE: f32 = 18.0;
+
+// ── Systems ──────────────────────────────────────────────────────────────────
+
+pub struct ItemsPlugin;
+
+impl Plugin for ItemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<UseItemEvent>()
+            .init_resource::<DecoyTarget>()
+            .add_systems(OnEnter(GameState::Playing), hud::spawn_item_hud)
+            .add_systems(OnExit(GameState::Playing), effects::clear_decoy_target)
+            .add_systems(
+                Update,
+                (
+                    tick_item_cooldown,
+                    pick_up_items,
+                    use_items,
+                    (
+                        effects::deploy_decoy,
+                        effects::fire_emp,
+                        effects::apply_breach_patch,
+                        effects::apply_stim,
+                        effects::start_air_bubble,
+                    ),
+                    effects::tick_decoys,
+                    effects::tick_air_bubbles,
+                    hud::update_item_hud,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+            );
+    }
+}
+
+pub fn spawn_item_pickup(commands: &mut Commands, font: &Handle<Font>, at: Vec2, kind: ItemKind) {
+    commands
+        .spawn((
+            Sprite {
+                color: kind.color(),
+                custom_size: Some(Vec2::splat(PICKUP_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(at.extend(crate::Z_ENTITIES)),
+            ItemPickup(kind),
+            GameEntity,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Text2d::new(kind.letter()),
+                TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                TextColor(Color::BLACK),
+                Transform::from_xyz(0.0, 0.0, 0.1),
+            ));
+        });
+}
+
+/// Called when a room is cleared; sometimes leaves a random item behind.
+pub fn roll_room_drop(commands: &mut Commands, font: &Handle<Font>, at: Vec2, rng: &mut RunRng) {
+    if rng.random::<f32>() < ROOM_DROP_CHANCE {
+        spawn_item_pickup(commands, font, at, ItemKind::random(rng));
+    }
+}
+
+fn tick_item_cooldown(time: Res<Time>, mut player_q: Query<&mut ItemSlots, With<Player>>) {
+    let Ok(mut slots) = player_q.single_mut() else { return };
+    if slots.cooldown > 0.0 {
+        slots.cooldown = (slots.cooldown - time.delta_secs()).max(0.0);
+    }
+}
+
+/// Consumables stack into the hotbar on contact. An active item fills an empty
+/// slot on contact, or swaps with the held one when Interact is pressed.
+fn pick_up_items(
+    mut commands: Commands,
+    mut player_q: Query<(&Transform, &mut ItemSlots), With<Player>>,
+    mut pickups: Query<(Entity, &Transform, &mut ItemPickup, &Children)>,
+    mut letters: Query<&mut Text2d>,
+    mut sprites: Query<&mut Sprite>,
+    actions: Res<PlayerActions>,
+    font: Res<RewardFont>,
+) {
+    let Ok((player_tf, mut slots)) = player_q.single_mut() else { return };
+    let pp = player_tf.translation;
+    let half = Vec2::splat(TILE_SIZE * 0.5);
+
+    for (entity, tf, mut pickup, children) in &mut pickups {
+        let at = tf.translation;
+        if !aabb_overlap(pp.x, pp.y, half, at.x, at.y, Vec2::splat(PICKUP_SIZE * 0.5)) {
+            continue;
+        }
+        let kind = pickup.0;
+
+        let label = if kind.is_active() {
+            match slots.active {
+                None => {
+                    slots.active = Some(kind);
+                    slots.cooldown = 0.0;
+                    commands.entity(entity).despawn();
+                    kind.name()
+                }
+                Some(held) if actions.just_pressed(Action::Interact) => {
+                    // Leave the old item where the new one was.
+                    slots.active = Some(kind);
+                    slots.cooldown = 0.0;
+                    pickup.0 = held;
+                    if let Ok(mut sprite) = sprites.get_mut(entity) {
+                        sprite.color = held.color();
+                    }
+                    for child in children.iter() {
+                        if let Ok(mut text) = letters.get_mut(child) {
+                            text.0 = held.letter().to_string();
+                        }
+                    }
+                    kind.name()
+                }
+                Some(_) => continue,
+            }
+        } else if slots.add_consumable(kind) {
+            commands.entity(entity).despawn();
+            kind.name()
+        } else {
+            continue;
+        };
+
+        commands.spawn((
+            Text2d::new(label),
+            TextFont { font: font.0.clone(), font_size: 18.0, ..default() },
+            TextColor(kind.color()),
+            Transform::from_translation(Vec3::new(at.x, at.y + TILE_SIZE, 10.0)),
+            RewardPopup { timer: Timer::from_seconds(1.5, TimerMode::Once) },
+            GameEntity,
+        ));
+    }
+}
+
+/// Reads the active-item and hotbar actions and fires `UseItemEvent`s.
+/// Ignored while the inventory is open, since it uses the number keys for mods.
+fn use_items(
+    actions: Res<PlayerActions>,
+    minimap: Res<crate::minimap::MinimapVisible>,
+    mut player_q: Query<(&Transform, &mut ItemSlots), With<Player>>,
+    mut events: EventWriter<UseItemEvent>,
+) {
+    if minimap.0 { return; }
+    let Ok((tf, mut slots)) = player_q.single_mut() else { return };
+    let pos = tf.translation.truncate();
+
+    if actions.just_pressed(Action::ActiveItem)
+        && slots.cooldown <= 0.0
+        && let Some(kind) = slots.active
+    {
+        slots.cooldown = kind.cooldown();
+        events.write(UseItemEvent { kind, pos });
+    }
+
+    let hotbar = [Action::Hotbar1, Action::Hotbar2, Action::Hotbar3];
+    for (slot, action) in hotbar.into_iter().enumerate() {
+        if actions.just_pressed(action)
+            && let Some(kind) = slots.take(slot)
+        {
+            events.write(UseItemEvent { kind, pos });
+        }
+    }
+}
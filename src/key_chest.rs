@@ -1,10 +1,13 @@
 use bevy::prelude::*;
-use rand::random_range;
+use rand::{Rng, random_range};
 use crate::{GameEntity, GameState, PlanetLevelMarker, TILE_SIZE, Z_ENTITIES};
 use crate::player::{Player, WeaponBuffStacks, aabb_overlap};
 use crate::enemies::Enemy;
 use crate::room::LevelState;
 
+/// Weapon definition id granted (and permanently unlocked) by the planet chest.
+const CHEST_WEAPON: &str = "beam_rifle";
+
 // ─── Components ──────────────────────────────────────────────────────────────
 
 /// Marker added to one random enemy per planet run; their death spawns the KeyPickup.
@@ -108,6 +111,7 @@ fn assign_key_holder(
     mut key_state: ResMut<LevelKeyState>,
     lvl_state: Res<LevelState>,
     enemy_q: Query<Entity, With<Enemy>>,
+    mut rng: ResMut<crate::RunRng>,
 ) {
     if !key_state.is_planet_run { return; }
     if key_state.key_assigned { return; }
@@ -117,7 +121,7 @@ fn assign_key_holder(
     let enemies: Vec<Entity> = enemy_q.iter().collect();
     if enemies.is_empty() { return; }
 
-    let pick = enemies[random_range(0..enemies.len())];
+    let pick = enemies[rng.random_range(0..enemies.len())];
     commands.entity(pick).insert(KeyHolder);
     key_state.key_assigned = true;
 }
@@ -145,16 +149,17 @@ fn pickup_key(
 /// Press the interact key near a chest while holding the key to open it.
 fn interact_with_chest(
     mut commands: Commands,
-    input: Res<ButtonInput<KeyCode>>,
+    actions: Res<crate::replay::PlayerActions>,
     mut key_state: ResMut<LevelKeyState>,
     player_q: Query<&Transform, With<Player>>,
     chest_q: Query<(Entity, &Transform), With<Chest>>,
     mut inventory_q: Query<&mut crate::weapons::WeaponInventory, With<Player>>,
     buff_stacks_q: Query<&WeaponBuffStacks, With<Player>>,
-    bindings: Res<crate::settings::KeyBindings>,
-    mut unlocked: ResMut<crate::BeamRifleUnlocked>,
+    mut run_events: EventWriter<crate::profile::RunEvent>,
+    weapon_registry: Res<crate::weapons::WeaponRegistry>,
+    reward_registry: Res<crate::rewards::RewardRegistry>,
 ) {
-    if !input.just_pressed(bindings.interact) { return; }
+    if !actions.just_pressed(crate::replay::Action::Interact) { return; }
     if !key_state.has_key { return; }
 
     let Ok(player_tf) = player_q.single() else { return };
@@ -168,16 +173,18 @@ fn interact_with_chest(
             commands.entity(entity).despawn();
             key_state.has_key = false;
             if let Ok(mut inv) = inventory_q.single_mut() {
-                let new_type = crate::weapons::WeaponType::BeamRifle;
-                let already_owned = inv.weapons.iter().any(|w| w.weapon_type == new_type);
+                let already_owned = inv.weapons.iter().any(|w| w.id == CHEST_WEAPON);
                 if !already_owned {
-                    let mut new_weapon = crate::weapons::Weapon::new(new_type);
+                    let mut new_weapon = weapon_registry.weapon(CHEST_WEAPON);
                     if let Ok(stacks) = buff_stacks_q.single() {
-                        for _ in 0..stacks.atk_speed {
-                            crate::rewards::atk_speed::apply(&mut new_weapon);
+                        use crate::rewards::RewardKind;
+                        for i in 0..stacks.atk_speed {
+                            let scale = reward_registry.scale(RewardKind::AtkSpeed, i);
+                            crate::rewards::atk_speed::apply(&mut new_weapon, scale);
                         }
-                        for _ in 0..stacks.damage {
-                            crate::rewards::damage_up::apply(&mut new_weapon);
+                        for i in 0..stacks.damage {
+                            let scale = reward_registry.scale(RewardKind::DamageUp, i);
+                            crate::rewards::damage_up::apply(&mut new_weapon, scale);
                         }
                         for _ in 0..stacks.piercing {
                             crate::rewards::piercing::apply(&mut new_weapon);
@@ -186,11 +193,8 @@ fn interact_with_chest(
                     inv.weapons.push(new_weapon);
                 }
             }
-            // Permanently unlock Beam Rifle for future run selection.
-            if !unlocked.0 {
-                unlocked.0 = true;
-                crate::settings::save_beam_rifle_unlock();
-            }
+            // Counts toward the Beam Rifle unlock for future runs.
+            run_events.write(crate::profile::RunEvent::ChestOpened);
             break;
         }
     }
@@ -0,0 +1,263 @@
+use bevy::asset::io::file::FileAssetReader;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::sync::{Mutex, RwLock};
+
+// ── String tables ─────────────────────────────────────────────────────────────
+//
+// Every player-facing string lives in assets/lang/<locale>.ron:
+//
+//   (
+//       name: "English",
+//       strings: {
+//           "menu.quit": "Quit",
+//           "menu.continue_station": "Continue Run — Station {station}",
+//       },
+//   )
+//
+// `tr` looks a key up in the selected locale, then in English, and finally
+// returns the key itself so a gap is visible on screen rather than blank.
+// `{name}` placeholders are filled by `tr_args`. Run the game with
+// `--check-lang` to list keys each locale is missing.
+
+/// Locale every other one falls back to. Its file must hold every key.
+pub const FALLBACK_LOCALE: &str = "en";
+const LANG_DIR: &str = "lang";
+
+#[derive(serde::Deserialize)]
+struct LangFile {
+    name: String,
+    strings: HashMap<String, String>,
+}
+
+#[derive(Default)]
+struct StringTable {
+    strings: HashMap<String, String>,
+    fallback: HashMap<String, String>,
+}
+
+static TABLE: RwLock<Option<StringTable>> = RwLock::new(None);
+/// Keys already reported as missing, so each is logged once.
+static REPORTED: Mutex<Option<HashSet<String>>> = Mutex::new(None);
+
+fn lang_dir() -> PathBuf {
+    FileAssetReader::get_base_path().join("assets").join(LANG_DIR)
+}
+
+fn read_lang(code: &str) -> Result<LangFile, String> {
+    let path = lang_dir().join(format!("{code}.ron"));
+    std::fs::read_to_string(&path)
+        .map_err(|e| format!("'{}': {e}", path.display()))
+        .and_then(|s| ron::from_str::<LangFile>(&s).map_err(|e| format!("'{}': {e}", path.display())))
+}
+
+/// Every locale file in assets/lang as (code, display name), English first.
+pub fn available_locales() -> Vec<(String, String)> {
+    let Ok(entries) = std::fs::read_dir(lang_dir()) else {
+        warn!("Could not open language directory '{}'", lang_dir().display());
+        return vec![(FALLBACK_LOCALE.to_string(), "English".to_string())];
+    };
+    let mut locales: Vec<(String, String)> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "ron"))
+        .filter_map(|p| {
+            let code = p.file_stem()?.to_str()?.to_string();
+            match read_lang(&code) {
+                Ok(file) => Some((code, file.name)),
+                Err(e) => {
+                    warn!("Skipping language file {e}");
+                    None
+                }
+            }
+        })
+        .collect();
+    locales.sort_by(|a, b| (a.0 != FALLBACK_LOCALE).cmp(&(b.0 != FALLBACK_LOCALE)).then_with(|| a.1.cmp(&b.1)));
+    if locales.is_empty() {
+        locales.push((FALLBACK_LOCALE.to_string(), "English".to_string()));
+    }
+    locales
+}
+
+/// Loads `code` (plus the English fallback) as the active string table.
+pub fn set_locale(code: &str) {
+    let fallback = match read_lang(FALLBACK_LOCALE) {
+        Ok(file) => file.strings,
+        Err(e) => {
+            warn!("Could not load fallback language {e}");
+            HashMap::new()
+        }
+    };
+    let strings = if code == FALLBACK_LOCALE {
+        fallback.clone()
+    } else {
+        match read_lang(code) {
+            Ok(file) => {
+                let missing = fallback.keys().filter(|k| !file.strings.contains_key(*k)).count();
+                if missing > 0 {
+                    info!("Language '{code}' is missing {missing} strings; using English for those");
+                }
+                file.strings
+            }
+            Err(e) => {
+                warn!("Could not load language {e}; using English");
+                fallback.clone()
+            }
+        }
+    };
+    if let Ok(mut table) = TABLE.write() {
+        *table = Some(StringTable { strings, fallback });
+    }
+}
+
+/// The string for `key` in the current language.
+pub fn tr(key: &str) -> String {
+    let found = TABLE.read().ok().and_then(|table| {
+        let table = table.as_ref()?;
+        table.strings.get(key).or_else(|| table.fallback.get(key)).cloned()
+    });
+    found.unwrap_or_else(|| {
+        if let Ok(mut reported) = REPORTED.lock() {
+            if reported.get_or_insert_with(HashSet::new).insert(key.to_string()) {
+                warn!("Missing string '{key}'");
+            }
+        }
+        key.to_string()
+    })
+}
+
+/// `tr` with `{name}` placeholders replaced by the matching argument.
+pub fn tr_args(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut text = tr(key);
+    for (name, value) in args {
+        text = text.replace(&format!("{{{name}}}"), &value.to_string());
+    }
+    text
+}
+
+/// Placeholder names used by a string, e.g. `{station}` → "station".
+fn placeholders(text: &str) -> HashSet<&str> {
+    text.split('{').skip(1).filter_map(|s| s.split_once('}').map(|(name, _)| name)).collect()
+}
+
+/// Compares every locale with English and returns one line per problem:
+/// missing keys, keys English doesn't have, and mismatched placeholders.
+pub fn check_locales() -> Vec<String> {
+    let english = match read_lang(FALLBACK_LOCALE) {
+        Ok(file) => file.strings,
+        Err(e) => return vec![format!("cannot read fallback language {e}")],
+    };
+    let mut problems = Vec::new();
+    for (code, _) in available_locales() {
+        if code == FALLBACK_LOCALE { continue; }
+        let file = match read_lang(&code) {
+            Ok(file) => file,
+            Err(e) => {
+                problems.push(format!("{code}: unreadable {e}"));
+                continue;
+            }
+        };
+        let mut keys: Vec<&String> = english.keys().collect();
+        keys.sort();
+        for key in keys {
+            match file.strings.get(key) {
+                None => problems.push(format!("{code}: missing '{key}'")),
+                Some(text) if placeholders(text) != placeholders(&english[key]) => {
+                    problems.push(format!("{code}: '{key}' placeholders differ from English"));
+                }
+                Some(_) => {}
+            }
+        }
+        let mut extra: Vec<&String> = file.strings.keys().filter(|k| !english.contains_key(*k)).collect();
+        extra.sort();
+        for key in extra {
+            problems.push(format!("{code}: unknown key '{key}'"));
+        }
+    }
+    problems
+}
+
+/// Handles `--check-lang`: prints the report and exits, failing if anything is missing.
+pub fn run_checker_if_requested() {
+    if !std::env::args().any(|a| a == "--check-lang") { return; }
+    let problems = check_locales();
+    for line in &problems {
+        println!("{line}");
+    }
+    println!("{} problem(s) found", problems.len());
+    std::process::exit(if problems.is_empty() { 0 } else { 1 });
+}
+
+// ── Resources ─────────────────────────────────────────────────────────────────
+
+/// Locale code the player picked, e.g. "en". Saved in config.ron.
+#[derive(Resource, Clone, PartialEq, Debug)]
+pub struct Language(pub String);
+
+/// Locales found in assets/lang, as (code, display name).
+#[derive(Resource)]
+pub struct Locales(pub Vec<(String, String)>);
+
+impl Locales {
+    pub fn name_of<'a>(&'a self, code: &'a str) -> &'a str {
+        self.0.iter().find(|(c, _)| c == code).map_or(code, |(_, name)| name.as_str())
+    }
+
+    /// The locale `by` steps away from `code`, wrapping around.
+    pub fn step(&self, code: &str, by: isize) -> String {
+        if self.0.is_empty() { return code.to_string(); }
+        let i = self.0.iter().position(|(c, _)| c == code).unwrap_or(0) as isize;
+        self.0[(i + by).rem_euclid(self.0.len() as isize) as usize].0.clone()
+    }
+}
+
+// ── Components ────────────────────────────────────────────────────────────────
+
+/// UI text that is relabelled when the language changes.
+#[derive(Component)]
+pub struct LocalizedText(pub &'static str);
+
+/// A `Text` for `key` that follows language changes.
+pub fn loc_text(key: &'static str) -> (Text, LocalizedText) {
+    (Text::new(tr(key)), LocalizedText(key))
+}
+
+// ── Plugin ────────────────────────────────────────────────────────────────────
+
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Locales(available_locales()))
+            .add_systems(Update, apply_language.run_if(resource_changed::<Language>));
+    }
+}
+
+/// Loads the picked language and relabels every `LocalizedText`. Displays that
+/// format their own text from `tr` run after this.
+pub fn apply_language(language: Res<Language>, mut text_q: Query<(&LocalizedText, &mut Text)>) {
+    set_locale(&language.0);
+    for (localized, mut text) in &mut text_q {
+        *text = Text::new(tr(localized.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_locale_matches_english() {
+        let problems = check_locales();
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+    }
+
+    #[test]
+    fn placeholders_are_filled_by_name() {
+        assert_eq!(placeholders("Run {n} of {total}"), HashSet::from(["n", "total"]));
+        set_locale(FALLBACK_LOCALE);
+        assert_eq!(tr_args("hud.station", &[("station", &3)]), "Station 3");
+    }
+}
@@ -3,6 +3,8 @@ use crate::player::{Health, Player, ThrusterFuel};
 use bevy::{prelude::*, window::{PresentMode, WindowMode}};
 use bevy::render::{RenderPlugin, settings::{RenderCreation, WgpuSettings, Backends}};
 use bevy::audio::Volume;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use crate::air::{init_air_grid, spawn_pressure_labels, update_pressure_labels, update_air_on_window_break};
 use crate::room::RoomVec;
 
@@ -28,6 +30,7 @@ pub mod rewards;
 pub mod heart;
 pub mod weapons;
 pub mod minimap;
+pub mod corner_map;
 pub mod pause;
 pub mod settings;
 pub mod key_chest;
@@ -36,6 +39,13 @@ pub mod station_color;
 pub mod station_symbol;
 pub mod air_particles;
 pub mod setup;
+pub mod status;
+pub mod items;
+pub mod run_save;
+pub mod profile;
+pub mod run_history;
+pub mod replay;
+pub mod locale;
 
 pub const FONT_PATH: &str = "fonts/BitcountSingleInk-VariableFont_CRSV,ELSH,ELXP,SZP1,SZP2,XPN1,XPN2,YPN1,YPN2,slnt,wght.ttf";
 pub const SYMBOL_FONT_PATH: &str = "fonts/NotoSansMono-VariableFont_wdth,wght.ttf";
@@ -150,33 +160,68 @@ pub struct TestPlanetMode;
 pub struct PlanetCount(pub u32);
 
 /// The weapon the player selected on the setup screen. Used by spawn_player on fresh runs.
-#[derive(Resource, Clone, Copy, PartialEq)]
-pub struct SelectedWeapon(pub weapons::WeaponType);
+/// Holds a weapon definition id from `assets/weapons`.
+#[derive(Resource, Clone, PartialEq)]
+pub struct SelectedWeapon(pub String);
 
 impl Default for SelectedWeapon {
-    fn default() -> Self { Self(weapons::WeaponType::Zapper) }
+    fn default() -> Self { Self(weapons::DEFAULT_WEAPON.to_string()) }
+}
+
+/// Seed for the current run. Each station's layout is derived from it, so a
+/// resumed run rebuilds the same station it was saved on.
+#[derive(Resource, Clone, Copy)]
+pub struct RunSeed(pub u64);
+
+impl Default for RunSeed {
+    fn default() -> Self { Self(rand::random()) }
+}
+
+impl RunSeed {
+    /// Layout seed for the station at `level`.
+    pub fn station_seed(self, level: u32) -> u64 {
+        self.0 ^ (level as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// Seed for `RunRng` on the level loaded at `level` after `planets` planets.
+    pub fn roll_seed(self, level: u32, planets: u32) -> u64 {
+        self.station_seed(level) ^ (planets as u64 + 1).wrapping_mul(0xD1B5_4A32_D192_ED03)
+    }
+}
+
+/// Gameplay rolls for the current run: drops, weapon spread and enemy AI.
+/// Reseeded from `RunSeed` as each level loads, so a replay rolls the same
+/// numbers as the run it recorded. Cosmetic effects keep the thread RNG.
+#[derive(Resource, Deref, DerefMut)]
+pub struct RunRng(pub StdRng);
+
+impl Default for RunRng {
+    fn default() -> Self { Self(StdRng::seed_from_u64(rand::random())) }
 }
 
 /// The run the player selected on the setup screen (0 = "Run 1"). No gameplay effect yet.
 #[derive(Resource, Clone, Copy, Default)]
 pub struct SelectedRun(pub u32);
 
-/// Whether the player has ever unlocked the Beam Rifle (by opening the planet chest).
-/// Persisted to config.ron so it survives between sessions.
-#[derive(Resource, Clone, Copy)]
-pub struct BeamRifleUnlocked(pub bool);
-
 /// Per-weapon state saved between stations.
-#[derive(Clone)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct SavedWeapon {
-    pub weapon_type: weapons::WeaponType,
+    /// Weapon definition id, resolved through the `WeaponRegistry` on respawn.
+    pub id: String,
     pub fire_rate: f32,
     pub damage: f32,
     pub piercing_pickups: u32,
+    pub heat: f32,
+    pub overheated: bool,
+    pub ammo: u32,
+    pub reload_left: f32,
+    pub cooling: f32,
+    pub mods: Vec<crate::weapons::WeaponMod>,
 }
 
 /// Saved player buffs carried between stations on "Continue".
-#[derive(Resource, Clone)]
+/// Also written to the run save so a run can be resumed after quitting.
+#[derive(Resource, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SavedPlayerBuffs {
     pub max_health: f32,
     pub health: f32,
@@ -190,9 +235,14 @@ pub struct SavedPlayerBuffs {
     pub vacuum_mass: f32,
     /// All weapons in inventory with their accumulated stats.
     pub weapons: Vec<SavedWeapon>,
+    /// Weapon mods picked up but not installed.
+    pub mod_stash: Vec<crate::weapons::WeaponMod>,
     pub atk_speed_stacks: u32,
     pub damage_stacks: u32,
     pub piercing_stacks: u32,
+    pub broom: broom::BroomStats,
+    pub reward_stacks: rewards::RewardStacks,
+    pub items: items::ItemSlots,
     /// Code digits collected from the 3 stations in the current cycle (index = station-in-cycle).
     pub station_codes: [Option<u8>; 3],
     /// Color indices collected from the 3 stations (0=RED 1=GRN 2=BLU 3=YLW).
@@ -226,8 +276,12 @@ enum GameState {
 
 fn main() {
     crash_log::install();
-    let (saved_volume, saved_mode, saved_bindings) = settings::load_config();
-    let beam_rifle_unlocked = settings::load_beam_rifle_unlock();
+    locale::run_checker_if_requested();
+    // Profile first: it picks up the Beam Rifle flag from an old config.ron
+    // before load_config migrates that file.
+    let profile = profile::load_profile();
+    let config = settings::load_config();
+    locale::set_locale(&config.language);
 
     App::new()
         .add_plugins(
@@ -250,6 +304,11 @@ fn main() {
                         ..default()
                     }),
                     ..default()
+                })
+                // Keeps recent log lines around for crash reports.
+                .set(bevy::log::LogPlugin {
+                    custom_layer: crash_log::log_layer,
+                    ..default()
                 }),
         )
         .insert_resource(bevy::render::camera::ClearColor(Color::srgb(0.02, 0.02, 0.06)))
@@ -261,9 +320,15 @@ fn main() {
         .init_resource::<PlanetCount>()
         .init_resource::<SelectedWeapon>()
         .init_resource::<SelectedRun>()
-        .insert_resource(saved_mode)
-        .insert_resource(saved_bindings)
-        .insert_resource(BeamRifleUnlocked(beam_rifle_unlocked))
+        .init_resource::<RunSeed>()
+        .init_resource::<RunRng>()
+        .insert_resource(config.window_mode)
+        .insert_resource(config.bindings)
+        .insert_resource(config.corner_map)
+        .insert_resource(config.accessibility)
+        .insert_resource(locale::Language(config.language))
+        .insert_resource(settings::SettingsWarnings(config.warnings))
+        .insert_resource(profile)
         .add_plugins((
             procgen::ProcGen,
             map::MapPlugin,
@@ -295,12 +360,22 @@ fn main() {
             station_color::StationColorPlugin,
             station_symbol::StationSymbolPlugin,
             setup::SetupPlugin,
+            enemies::affix::AffixPlugin,
+            status::StatusPlugin,
+            items::ItemsPlugin,
+            run_save::RunSavePlugin,
+            profile::ProfilePlugin,
+            run_history::RunHistoryPlugin,
+            replay::ReplayPlugin,
+            crash_log::CrashLogPlugin,
+            corner_map::CornerMapPlugin,
+            locale::LocalePlugin,
         ))
         .add_systems(Startup, (setup_camera, rewards::load_reward_font, load_click_sound))
         .add_systems(Update, play_button_click.run_if(resource_exists::<ClickSoundRes>))
         .add_systems(OnEnter(GameState::Menu), log_state_change)
         .add_systems(OnEnter(GameState::Setup), log_state_change)
-        .add_systems(OnEnter(GameState::Loading), log_state_change)
+        .add_systems(OnEnter(GameState::Loading), (log_state_change, seed_run_rng))
         .add_systems(OnEnter(GameState::EndCredits), log_state_change)
         .add_systems(OnEnter(GameState::Playing), log_state_change)
         .add_systems(
@@ -362,12 +437,13 @@ fn main() {
             Update,
             (
                 rewards::player_pickup_reward,
+                rewards::update_reward_tooltips,
                 rewards::tick_reward_popups,
             ).run_if(in_state(GameState::Playing)),
         )
         
         .insert_resource(DamageCooldown(Timer::from_seconds(0.5, TimerMode::Once)))
-        .insert_resource(GameMusicVolume(saved_volume))
+        .insert_resource(GameMusicVolume(config.volume))
         .init_resource::<MusicMuted>()
         .run();
 }
@@ -430,7 +506,7 @@ fn check_win(
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text::new("Station cleared! Return to your ship."),
+                Text::new(locale::tr("hud.station_cleared_hint")),
                 TextFont { font, font_size: 30.0, ..default() },
                 TextColor(Color::srgb(1.0, 1.0, 0.3)),
             ));
@@ -448,16 +524,18 @@ fn check_return_to_airlock(
         &Health, &player::MaxHealth, &player::MoveSpeed, &weapons::WeaponInventory,
         &player::NumOfCleared, &player::Armor, &player::AirTank,
         &player::Regen, &player::Shield, &fluiddynamics::PulledByFluid,
-        &Transform, &player::WeaponBuffStacks,
+        &Transform, &player::WeaponBuffStacks, &broom::BroomStats, &rewards::RewardStacks,
+        &items::ItemSlots,
     ), With<Player>>,
     level_complete: Option<Res<LevelComplete>>,
     codes: Option<Res<station_code::StationCodes>>,
     colors: Option<Res<station_color::StationColors>>,
     symbols: Option<Res<station_symbol::StationSymbols>>,
+    mut run_events: EventWriter<profile::RunEvent>,
 ) {
     if level_complete.is_none() { return; }
 
-    let Ok((health, max_hp, move_spd, inventory, _num_cleared, armor, tank, regen, shield, pull, transform, buff_stacks))
+    let Ok((health, max_hp, move_spd, inventory, _num_cleared, armor, tank, regen, shield, pull, transform, buff_stacks, broom_stats, reward_stacks, item_slots))
         = player_q.single() else { return; };
     let player_pos = transform.translation.truncate();
     let in_airlock = rooms.0.iter().any(|r| r.is_airlock && r.bounds_check(player_pos));
@@ -477,18 +555,29 @@ fn check_return_to_airlock(
         shield_max: shield.max,
         vacuum_mass: pull.mass,
         weapons: inventory.weapons.iter().map(|w| SavedWeapon {
-            weapon_type: w.weapon_type,
+            id: w.id.clone(),
             fire_rate: w.fire_rate,
             damage: w.damage,
             piercing_pickups: w.piercing_pickups,
+            heat: w.heat,
+            overheated: w.overheated,
+            ammo: w.ammo,
+            reload_left: w.reload_left,
+            cooling: w.cooling,
+            mods: w.mods.clone(),
         }).collect(),
+        mod_stash: inventory.stash.clone(),
         atk_speed_stacks: buff_stacks.atk_speed,
         damage_stacks:     buff_stacks.damage,
         piercing_stacks:   buff_stacks.piercing,
+        broom: *broom_stats,
+        reward_stacks: reward_stacks.clone(),
+        items: item_slots.clone(),
         station_codes:   codes.map(|c| c.codes).unwrap_or([None; 3]),
         station_colors:  colors.map(|c| c.colors).unwrap_or([None; 3]),
         station_symbols: symbols.map(|s| s.symbols).unwrap_or([None; 3]),
     });
+    run_events.write(profile::RunEvent::StationCleared);
     next_state.set(GameState::Win);
 }
 
@@ -542,7 +631,7 @@ fn load_win(
         ))
         .with_children(|r| {
             r.spawn((
-                Text::new(format!("Station {} Cleared!", station_level.0 + 1)),
+                Text::new(locale::tr_args("end.station_cleared", &[("station", &(station_level.0 + 1))])),
                 TextFont { font: font.clone(), font_size: 36.0, ..default() },
                 TextColor(Color::srgb(1.0, 1.0, 0.2)),
             ));
@@ -584,7 +673,7 @@ fn load_win(
                 ))
                 .with_children(|b| {
                     b.spawn((
-                        Text::new("Descend to Planet"),
+                        Text::new(locale::tr("end.descend")),
                         TextFont { font: font.clone(), font_size: 28.0, ..default() },
                         TextColor(Color::WHITE),
                     ));
@@ -609,7 +698,7 @@ fn load_win(
             ))
             .with_children(|b| {
                 b.spawn((
-                    Text::new(format!("Continue to Station {}", station_level.0 + 2)),
+                    Text::new(locale::tr_args("end.continue_station", &[("station", &(station_level.0 + 2))])),
                     TextFont { font: font.clone(), font_size: 28.0, ..default() },
                     TextColor(Color::WHITE),
                 ));
@@ -633,7 +722,7 @@ fn load_win(
             ))
             .with_children(|b| {
                 b.spawn((
-                    Text::new("Leave"),
+                    Text::new(locale::tr("end.leave")),
                     TextFont { font: font.clone(), font_size: 28.0, ..default() },
                     TextColor(Color::WHITE),
                 ));
@@ -645,15 +734,20 @@ fn load_win(
 // Check if player health is < 0
 fn check_game_over(
     mut next_state: ResMut<NextState<GameState>>,
-    player_q: Query<&Health, With<Player>>,
+    player_q: Query<(&Health, &Transform, Option<&status::StatusEffects>), With<Player>>,
+    reaper_q: Query<&Transform, With<enemies::Reaper>>,
     test_mode: Option<Res<TestPlanetMode>>,
+    mut run_events: EventWriter<profile::RunEvent>,
 ) {
     if test_mode.is_some() { return; }
-    if let Ok(health) = player_q.single() {
-        if health.0 <= 0.0 {
-            debug!("Player health reached 0 — transitioning to GameOver!");
-            next_state.set(GameState::GameOver);
-        }
+    if let Ok((health, transform, status)) = player_q.single()
+        && health.0 <= 0.0
+    {
+        debug!("Player health reached 0 — transitioning to GameOver!");
+        let reaper_pos = reaper_q.iter().next().map(|t| t.translation.truncate());
+        let cause = profile::DeathCause::infer(status, transform.translation.truncate(), reaper_pos);
+        run_events.write(profile::RunEvent::Died(cause));
+        next_state.set(GameState::GameOver);
     }
 }
 
@@ -751,7 +845,7 @@ fn setup_ui_health(mut commands: Commands, asset_server: Res<AssetServer>, stati
             .with_children(|row| {
                 row.spawn((Node::default(),)).with_children(|c| {
                     c.spawn((
-                        Text::new("HP"),
+                        Text::new(locale::tr("hud.hp")),
                         TextFont { font: font.clone(), font_size: 20.0, ..default() },
                         TextColor(Color::srgb(1.0, 0.3, 0.3)),
                     ));
@@ -794,7 +888,7 @@ fn setup_ui_health(mut commands: Commands, asset_server: Res<AssetServer>, stati
             .with_children(|row| {
                 row.spawn((Node::default(),)).with_children(|c| {
                     c.spawn((
-                        Text::new("SH"),
+                        Text::new(locale::tr("hud.shield")),
                         TextFont { font: font.clone(), font_size: 20.0, ..default() },
                         TextColor(Color::srgb(0.3, 0.7, 1.0)),
                     ));
@@ -832,7 +926,7 @@ fn setup_ui_health(mut commands: Commands, asset_server: Res<AssetServer>, stati
             .with_children(|row| {
                 row.spawn((Node::default(),)).with_children(|c| {
                     c.spawn((
-                        Text::new("DASH"),
+                        Text::new(locale::tr("hud.dash")),
                         TextFont { font: font.clone(), font_size: 14.0, ..default() },
                         TextColor(Color::srgb(0.4, 0.9, 1.0)),
                     ));
@@ -854,7 +948,7 @@ fn setup_ui_health(mut commands: Commands, asset_server: Res<AssetServer>, stati
             // ── Station label ────────────────────────────────────────────
             col.spawn((Node::default(),)).with_children(|c| {
                 c.spawn((
-                    Text::new(format!("Station {}", station_level.0 + 1)),
+                    Text::new(locale::tr_args("hud.station", &[("station", &(station_level.0 + 1))])),
                     TextFont { font, font_size: 18.0, ..default() },
                     TextColor(Color::srgb(0.8, 0.8, 1.0)),
                     StationLevelDisplay,
@@ -869,6 +963,7 @@ fn update_ui_health_text(
     mut sh_fill_q: Query<(&mut Node, &mut BackgroundColor), (With<ShieldBarFill>, Without<HealthBarFill>)>,
     mut sh_row_q: Query<&mut Visibility, (With<ShieldBarRow>, Without<ThrusterDot>)>,
     mut dot_q: Query<(&ThrusterDot, &mut BackgroundColor, &mut Visibility), (Without<HealthBarFill>, Without<ShieldBarFill>, Without<ShieldBarRow>)>,
+    accessibility: Res<settings::AccessibilitySettings>,
 ) {
     let Ok((health, max_hp, shield, fuel)) = player_q.single() else { return };
 
@@ -876,9 +971,7 @@ fn update_ui_health_text(
     if let Ok((mut node, mut color)) = hp_fill_q.single_mut() {
         let ratio = (health.0 / max_hp.0).clamp(0.0, 1.0);
         node.width = Val::Percent(ratio * 100.0);
-        let r = (1.0 - ratio).min(1.0);
-        let g = ratio.min(1.0);
-        *color = BackgroundColor(Color::srgb(r, g, 0.0));
+        *color = BackgroundColor(accessibility.health_color(ratio));
     }
 
     // Shield bar + row visibility
@@ -915,6 +1008,7 @@ fn damage_on_collision(
     mut cooldown: ResMut<DamageCooldown>,
     mut player_q: Query<(&mut Health, &Transform), With<Player>>,
     damaging_q: Query<(&Transform, &Collider, &Damage), With<Collidable>>,
+    mut stat_events: EventWriter<run_history::StatEvent>,
 ) {
     cooldown.0.tick(time.delta());
 
@@ -932,6 +1026,7 @@ fn damage_on_collision(
 
             if overlap_x && overlap_y {
                 health.0 -= dmg.amount;
+                stat_events.write(run_history::StatEvent::DamageTaken(run_history::DamageSource::Hazard, dmg.amount));
                 debug!(" Player took {} damage! HP now = {}", dmg.amount, health.0);
                 cooldown.0.reset();
                 break;
@@ -1020,6 +1115,10 @@ fn log_state_change(state: Res<State<GameState>>) {
     info!("Just moved to {:?}!", state.get());
 }
 
+fn seed_run_rng(mut rng: ResMut<RunRng>, seed: Res<RunSeed>, level: Res<StationLevel>, planets: Res<PlanetCount>) {
+    *rng = RunRng(StdRng::seed_from_u64(seed.roll_seed(level.0, planets.0)));
+}
+
 fn handle_end_screen_buttons(
     mut commands: Commands,
     mut interactions: Query<(&Interaction, &EndScreenButtons), (Changed<Interaction>, With<Button>)>,
@@ -1027,6 +1126,10 @@ fn handle_end_screen_buttons(
     mut station_level: ResMut<StationLevel>,
     current_state: Res<State<GameState>>,
     mut saved_buffs: Option<ResMut<SavedPlayerBuffs>>,
+    mut run_events: EventWriter<profile::RunEvent>,
+    run_stats: Res<run_history::RunStats>,
+    test_mode: Option<Res<TestPlanetMode>>,
+    replaying: Option<Res<replay::ReplayPlayback>>,
 ) {
     for (interaction, which) in &mut interactions {
 
@@ -1054,17 +1157,27 @@ fn handle_end_screen_buttons(
             EndScreenButtons::PlayAgain => {
                 // Full reset
                 station_level.0 = 0;
+                commands.insert_resource(RunSeed::default());
                 commands.remove_resource::<SavedPlayerBuffs>();
+                run_save::delete_run_save();
+                run_events.write(profile::RunEvent::RunStarted);
                 next_state.set(GameState::Loading);
             }
             EndScreenButtons::MainMenu => {
-                // Full reset
+                // Full reset. A replay never touched the real save, so leave it be.
                 station_level.0 = 0;
                 commands.remove_resource::<SavedPlayerBuffs>();
+                if replaying.is_none() {
+                    run_save::delete_run_save();
+                }
                 next_state.set(GameState::Menu);
             }
             EndScreenButtons::Leave => {
                 // Player chose to leave after clearing a station — full reset, back to menu.
+                if test_mode.is_none() && replaying.is_none() {
+                    run_history::finish_run(&run_stats, run_history::RunOutcome::Left);
+                    run_save::delete_run_save();
+                }
                 station_level.0 = 0;
                 commands.remove_resource::<SavedPlayerBuffs>();
                 next_state.set(GameState::Menu);
@@ -82,6 +82,12 @@ pub struct WallGrid {
 }
 
 impl WallGrid {
+    #[cfg(test)]
+    pub(crate) fn from_tiles(tiles: &[(i32, i32)]) -> Self {
+        let cells = tiles.iter().map(|&t| (t, Vec2::splat(crate::TILE_SIZE * 0.5))).collect();
+        Self { cells, cell_size: crate::TILE_SIZE, x0: 0.0, y0: 0.0 }
+    }
+
     fn world_to_key(&self, pos: Vec2) -> (i32, i32) {
         (
             ((pos.x - self.x0) / self.cell_size).round() as i32,
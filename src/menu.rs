@@ -2,7 +2,10 @@ use bevy::prelude::*;
 use bevy::app::AppExit;
 
 use crate::{GameState, GameMusicVolume, MusicTrack, PlanetCount, PlanetLevelMarker, TestPlanetMode, TestRoomMarker};
+use crate::locale::{loc_text, tr_args};
 use crate::settings;
+use crate::run_save;
+use crate::run_history;
 
 pub struct MenuPlugin;
 
@@ -22,12 +25,14 @@ struct MenuUI;
 
 #[derive(Component)]
 enum MenuButton {
+    ContinueRun,
     Play,
     TestPlanet1,
     TestPlanet2,
     TestPlanet3,
     TestRoom,
     Credits,
+    Records,
     Settings,
     Quit,
 }
@@ -39,6 +44,15 @@ fn setup_menu(
     mut commands: Commands,
     assets: Res<AssetServer>,
 ) {
+    // Only offer Continue Run when there's a save we can actually load.
+    let saved_run = run_save::load_run_save().map(|save| {
+        if save.on_planet {
+            tr_args("menu.continue_planet", &[("planet", &(save.planet_count + 1))])
+        } else {
+            tr_args("menu.continue_station", &[("station", &(save.station_level + 1))])
+        }
+    });
+
     // Root canvas
     commands
         .spawn((
@@ -97,6 +111,31 @@ fn setup_menu(
                     },
                 ))
                 .with_children(|col| {
+                    // Continue Run
+                    if let Some(label) = saved_run {
+                        col.spawn((
+                            Button,
+                            MenuButton::ContinueRun,
+                            Node {
+                                width: Val::Px(420.0),
+                                height: Val::Px(60.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                padding: UiRect::all(Val::Px(8.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.05, 0.15, 0.1, 0.8)),
+                            BorderColor(Color::srgba(0.3, 1.0, 0.6, 0.5)),
+                            BorderRadius::all(Val::Px(6.0)),
+                        ))
+                        .with_children(|b| {
+                            b.spawn((
+                                Text::new(label),
+                                TextFont { font_size: 28.0, ..default() },
+                            ));
+                        });
+                    }
+
                     // Play
                     col.spawn((
                         Button,
@@ -122,7 +161,7 @@ fn setup_menu(
                     ))
                     .with_children(|b| {
                         b.spawn((
-                            Text::new("Test Room"),
+                            loc_text("menu.test_room"),
                             TextFont { font_size: 28.0, ..default() },
                         ));
                     });
@@ -135,9 +174,9 @@ fn setup_menu(
                     })
                     .with_children(|row| {
                         for (label, btn) in [
-                            ("Planet 1", MenuButton::TestPlanet1),
-                            ("Planet 2", MenuButton::TestPlanet2),
-                            ("Planet 3", MenuButton::TestPlanet3),
+                            ("menu.test_planet1", MenuButton::TestPlanet1),
+                            ("menu.test_planet2", MenuButton::TestPlanet2),
+                            ("menu.test_planet3", MenuButton::TestPlanet3),
                         ] {
                             row.spawn((
                                 Button,
@@ -156,7 +195,7 @@ fn setup_menu(
                             ))
                             .with_children(|b| {
                                 b.spawn((
-                                    Text::new(label),
+                                    loc_text(label),
                                     TextFont { font_size: 20.0, ..default() },
                                 ));
                             });
@@ -170,6 +209,29 @@ fn setup_menu(
                         ImageNode::new(assets.load("menu/Title_Credits.png")),
                     ));
 
+                    // Records
+                    col.spawn((
+                        Button,
+                        MenuButton::Records,
+                        Node {
+                            width: Val::Px(420.0),
+                            height: Val::Px(60.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(8.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.15, 0.1, 0.25, 0.8)),
+                        BorderColor(Color::srgba(0.8, 0.5, 1.0, 0.5)),
+                        BorderRadius::all(Val::Px(6.0)),
+                    ))
+                    .with_children(|b| {
+                        b.spawn((
+                            loc_text("menu.records"),
+                            TextFont { font_size: 28.0, ..default() },
+                        ));
+                    });
+
                     // Settings
                     col.spawn((
                         Button,
@@ -188,7 +250,7 @@ fn setup_menu(
                     ))
                     .with_children(|b| {
                         b.spawn((
-                            Text::new("Settings"),
+                            loc_text("menu.settings"),
                             TextFont { font_size: 28.0, ..default() },
                         ));
                     });
@@ -211,7 +273,7 @@ fn setup_menu(
                     ))
                     .with_children(|b| {
                         b.spawn((
-                            Text::new("Quit"),
+                            loc_text("menu.quit"),
                             TextFont { font_size: 28.0, ..default() },
                         ));
                     });
@@ -230,18 +292,21 @@ fn setup_menu(
                     ))
                     .with_children(|panel| {
                         for line in [
-                            "Controls",
-                            "WASD — Move          Shift — Dash",
-                            "Left Click — Shoot",
-                            "Right Click — Broom (sweeps toward mouse, deflects bullets, fixes windows)",
-                            "Tab — Toggle Minimap",
-                            "M — Toggle Music       Esc — Pause",
+                            "menu.help.title",
+                            "menu.help.move",
+                            "menu.help.shoot",
+                            "menu.help.broom",
+                            "menu.help.parry",
+                            "menu.help.items",
+                            "menu.help.minimap",
+                            "menu.help.music",
                         ] {
-                            let size = if line == "Controls" { 18.0 } else { 15.0 };
+                            let title = line == "menu.help.title";
+                            let size = if title { 18.0 } else { 15.0 };
                             panel.spawn((
-                                Text::new(line),
+                                loc_text(line),
                                 TextFont { font_size: size, ..default() },
-                                TextColor(if line == "Controls" {
+                                TextColor(if title {
                                     Color::srgba(1.0, 1.0, 0.5, 1.0)
                                 } else {
                                     Color::WHITE
@@ -288,9 +353,10 @@ fn handle_buttons(
     mut next_state: ResMut<NextState<GameState>>,
     mut app_exit: EventWriter<AppExit>,
     settings_open: Option<Res<settings::SettingsOrigin>>,
+    records_open: Option<Res<run_history::RecordsView>>,
 ) {
-    // Block all menu input while the settings panel is open.
-    if settings_open.is_some() { return; }
+    // Block all menu input while the settings or records panel is open.
+    if settings_open.is_some() || records_open.is_some() { return; }
 
     for (interaction, which, _button_entity) in &mut interactions {
         if *interaction != Interaction::Pressed {
@@ -298,6 +364,12 @@ fn handle_buttons(
         }
 
         match which {
+            MenuButton::ContinueRun => {
+                if let Some(save) = run_save::load_run_save() {
+                    run_save::resume_run(&mut commands, save);
+                    next_state.set(GameState::Loading);
+                }
+            }
             MenuButton::Play => {
                 next_state.set(GameState::Setup);
             }
@@ -326,6 +398,9 @@ fn handle_buttons(
             MenuButton::Credits => {
                 next_state.set(GameState::EndCredits);
             }
+            MenuButton::Records => {
+                run_history::open_records(&mut commands, &asset_server);
+            }
             MenuButton::Settings => {
                 settings::open_settings(
                     &mut commands,
@@ -1,4 +1,6 @@
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
 use std::collections::HashSet;
 use crate::map::{LevelRes, MapGridMeta};
 use crate::player::{Player, WeaponBuffStacks};
@@ -6,9 +8,11 @@ use crate::room::{LevelState, RoomVec};
 use crate::station_code::StationCodes;
 use crate::station_color::StationColors;
 use crate::station_symbol::StationSymbols;
+use crate::locale::{tr, tr_args};
 use crate::planet::PlanetSignals;
+use crate::replay::{Action, ActionSet, PlayerActions};
 use crate::weapons::WeaponInventory;
-use crate::{GameEntity, GameState, TILE_SIZE, FONT_PATH};
+use crate::{GameEntity, GameState, MainCamera, TILE_SIZE, FONT_PATH, SYMBOL_FONT_PATH, Z_UI};
 
 const MINIMAP_W: f32 = 420.0;
 const MINIMAP_H: f32 = 420.0;
@@ -18,6 +22,15 @@ const HALLWAY_CELL: usize = 3;
 /// Radius in coarse cells that the player "reveals" around themselves each frame.
 const REVEAL_RADIUS: i32 = 3;
 
+/// Side of a point-of-interest icon on the map, in pixels.
+const POI_ICON: f32 = 12.0;
+/// A click this close to the current waypoint (map pixels) removes it.
+const WAYPOINT_CLEAR_RADIUS: f32 = 10.0;
+/// The waypoint clears itself once the player is this close (world units).
+const WAYPOINT_ARRIVE_DIST: f32 = TILE_SIZE * 2.0;
+/// Gap between the waypoint arrow and the screen edge.
+const ARROW_MARGIN: f32 = 36.0;
+
 // Components
 
 #[derive(Component)]
@@ -37,6 +50,95 @@ struct MinimapHallwayNode {
 #[derive(Component)]
 struct MinimapPlayerDot;
 
+/// The map panel itself; clicks on it place waypoints.
+#[derive(Component)]
+struct MinimapPanel;
+
+#[derive(Component)]
+struct MinimapPoiIcon;
+
+#[derive(Component)]
+struct MinimapWaypointMarker;
+
+/// In-world arrow that points at the waypoint, clamped to the screen edge.
+#[derive(Component)]
+struct WaypointArrow;
+
+/// Things worth finding that the map shows once discovered.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum PoiKind {
+    Airlock,
+    Code,
+    Color,
+    Symbol,
+    Key,
+    Chest,
+    Terminal,
+    Dial,
+    Door,
+    BrokenWindow,
+}
+
+impl PoiKind {
+    const ALL: [PoiKind; 10] = [
+        PoiKind::Airlock,
+        PoiKind::Code,
+        PoiKind::Color,
+        PoiKind::Symbol,
+        PoiKind::Key,
+        PoiKind::Chest,
+        PoiKind::Terminal,
+        PoiKind::Dial,
+        PoiKind::Door,
+        PoiKind::BrokenWindow,
+    ];
+
+    fn glyph(self) -> &'static str {
+        match self {
+            PoiKind::Airlock      => "A",
+            PoiKind::Code         => "#",
+            PoiKind::Color        => "C",
+            PoiKind::Symbol       => "S",
+            PoiKind::Key          => "K",
+            PoiKind::Chest        => "$",
+            PoiKind::Terminal     => "T",
+            PoiKind::Dial         => "D",
+            PoiKind::Door         => "=",
+            PoiKind::BrokenWindow => "W",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            PoiKind::Airlock      => Color::srgb(0.3, 0.8, 1.0),
+            PoiKind::Code         => Color::srgb(0.2, 1.0, 1.0),
+            PoiKind::Color        => Color::srgb(1.0, 0.5, 0.3),
+            PoiKind::Symbol       => Color::srgb(0.8, 0.4, 1.0),
+            PoiKind::Key          => Color::srgb(1.0, 0.85, 0.2),
+            PoiKind::Chest        => Color::srgb(0.85, 0.6, 0.25),
+            PoiKind::Terminal     => Color::srgb(0.2, 1.0, 0.4),
+            PoiKind::Dial         => Color::srgb(0.4, 0.7, 1.0),
+            PoiKind::Door         => Color::srgb(0.75, 0.75, 0.75),
+            PoiKind::BrokenWindow => Color::srgb(1.0, 0.3, 0.3),
+        }
+    }
+
+    fn label(self) -> String {
+        tr(match self {
+            PoiKind::Airlock      => "map.poi.airlock",
+            PoiKind::Code         => "map.poi.code",
+            PoiKind::Color        => "map.poi.color",
+            PoiKind::Symbol       => "map.poi.symbol",
+            PoiKind::Key          => "map.poi.key",
+            PoiKind::Chest        => "map.poi.chest",
+            PoiKind::Terminal     => "map.poi.terminal",
+            PoiKind::Dial         => "map.poi.dial",
+            PoiKind::Door         => "map.poi.door",
+            PoiKind::BrokenWindow => "map.poi.breach",
+        })
+    }
+}
+
 /// Marker for each weapon text row in the inventory panel (index = slot in WeaponInventory).
 #[derive(Component)]
 struct InventoryWeaponLine(usize);
@@ -53,15 +155,33 @@ enum InventoryClueRow { Code, Color, Symbol, Signal }
 #[derive(Component)]
 struct InventoryKeyRow;
 
+/// Marker for the stashed-mods list in the inventory panel.
+#[derive(Component)]
+struct InventoryModStash;
+
 // Resources
 
 #[derive(Resource, Default)]
 pub struct MinimapVisible(pub bool);
 
+/// Row highlighted in the mod stash while the inventory is open.
+#[derive(Resource, Default)]
+struct ModCursor(usize);
+
 /// Coarse cells (col/HALLWAY_CELL, row/HALLWAY_CELL) that the player has explored.
 #[derive(Resource, Default)]
 pub struct VisitedCells(pub HashSet<(i32, i32)>);
 
+/// Discovered points of interest, in map-panel pixels. Only replaced when the
+/// list actually changes, so the icons are rebuilt rarely.
+#[derive(Resource, Default, PartialEq)]
+struct MapPois(Vec<(PoiKind, Vec2)>);
+
+/// World position the player marked on the map. Cleared on arrival and at
+/// the start of every level.
+#[derive(Resource, Default)]
+pub struct Waypoint(pub Option<Vec2>);
+
 // Plugin
 
 pub struct MinimapPlugin;
@@ -70,8 +190,25 @@ impl Plugin for MinimapPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MinimapVisible>()
             .init_resource::<VisitedCells>()
-            .add_systems(OnEnter(GameState::Playing), (clear_visited_cells, setup_minimap).chain())
+            .init_resource::<ModCursor>()
+            .init_resource::<MapPois>()
+            .init_resource::<Waypoint>()
+            .add_systems(
+                OnEnter(GameState::Playing),
+                (clear_visited_cells, clear_map_marks, setup_minimap, spawn_waypoint_arrow).chain(),
+            )
             .add_systems(OnExit(GameState::Playing), clear_visited_cells)
+            .add_systems(
+                RunFixedMainLoop,
+                hold_fire_while_map_open
+                    .in_set(ActionSet::Filter)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(|vis: Res<MinimapVisible>| vis.0),
+            )
+            .add_systems(
+                Update,
+                update_waypoint_arrow.run_if(in_state(GameState::Playing)),
+            )
             .add_systems(
                 Update,
                 toggle_minimap.run_if(in_state(GameState::Playing)),
@@ -82,7 +219,13 @@ impl Plugin for MinimapPlugin {
             )
             .add_systems(
                 Update,
-                (update_minimap, update_inventory_panel)
+                (update_minimap, swap_weapon_mods, update_inventory_panel, update_mod_stash).chain()
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(|vis: Res<MinimapVisible>| vis.0),
+            )
+            .add_systems(
+                Update,
+                (place_waypoint, collect_map_pois, draw_map_pois, update_waypoint_marker).chain()
                     .run_if(in_state(GameState::Playing))
                     .run_if(|vis: Res<MinimapVisible>| vis.0),
             );
@@ -95,6 +238,11 @@ fn clear_visited_cells(mut visited: ResMut<VisitedCells>) {
     visited.0.clear();
 }
 
+fn clear_map_marks(mut pois: ResMut<MapPois>, mut waypoint: ResMut<Waypoint>) {
+    pois.0.clear();
+    waypoint.0 = None;
+}
+
 fn setup_minimap(
     mut commands: Commands,
     rooms: Res<RoomVec>,
@@ -112,43 +260,7 @@ fn setup_minimap(
 
     let font: Handle<Font> = asset_server.load(FONT_PATH);
 
-    // Precompute which coarse cells are hallway cells (floor tile outside every room).
-    let mut hallway_cells: HashSet<(i32, i32)> = HashSet::new();
-    let level_rows = level.level.len();
-    let level_cols = level.level.first().map(|r| r.len()).unwrap_or(0);
-
-    for cell_row in 0..(rows / HALLWAY_CELL + 1) {
-        for cell_col in 0..(cols / HALLWAY_CELL + 1) {
-            let tile_col = cell_col * HALLWAY_CELL + HALLWAY_CELL / 2;
-            let tile_row = cell_row * HALLWAY_CELL + HALLWAY_CELL / 2;
-
-            if tile_row >= level_rows || tile_col >= level_cols {
-                continue;
-            }
-
-            let ch = level.level[tile_row].as_bytes().get(tile_col).copied().unwrap_or(b'.');
-            if ch != b'#' {
-                continue;
-            }
-
-            // Skip if the coarse cell's tile AABB overlaps any room (not just center tile).
-            let cell_x1 = cell_col * HALLWAY_CELL;
-            let cell_y1 = cell_row * HALLWAY_CELL;
-            let cell_x2 = cell_x1 + HALLWAY_CELL - 1;
-            let cell_y2 = cell_y1 + HALLWAY_CELL - 1;
-            let in_room = rooms.0.iter().any(|r| {
-                let rx1 = r.tile_top_left_corner.x as usize;
-                let ry1 = r.tile_top_left_corner.y as usize;
-                let rx2 = r.tile_bot_right_corner.x as usize;
-                let ry2 = r.tile_bot_right_corner.y as usize;
-                cell_x1 <= rx2 && cell_x2 >= rx1 && cell_y1 <= ry2 && cell_y2 >= ry1
-            });
-
-            if !in_room {
-                hallway_cells.insert((cell_col as i32, cell_row as i32));
-            }
-        }
-    }
+    let hallway_cells = hallway_cells(&rooms, &level, &grid);
 
     let cell_w = (HALLWAY_CELL as f32 / cols as f32 * MINIMAP_W).max(2.0);
     let cell_h = (HALLWAY_CELL as f32 / rows as f32 * MINIMAP_H).max(2.0);
@@ -184,7 +296,7 @@ fn setup_minimap(
             .with_children(|left| {
                 // Title
                 left.spawn((
-                    Text::new("MAP   [TAB] to close"),
+                    Text::new(tr("map.title")),
                     TextFont { font: font.clone(), font_size: 18.0, ..default() },
                     TextColor(Color::srgb(0.65, 0.65, 0.65)),
                 ));
@@ -199,9 +311,43 @@ fn setup_minimap(
                     },
                 ))
                 .with_children(|leg| {
-                    legend_item(leg, Color::srgba(0.2, 0.2, 0.2, 0.6), "Unexplored");
-                    legend_item(leg, Color::srgb(1.0, 0.9, 0.0),        "Current");
-                    legend_item(leg, Color::srgb(0.15, 0.65, 0.15),     "Cleared");
+                    legend_item(leg, Color::srgba(0.2, 0.2, 0.2, 0.6), "map.legend.unexplored");
+                    legend_item(leg, Color::srgb(1.0, 0.9, 0.0),        "map.legend.current");
+                    legend_item(leg, Color::srgb(0.15, 0.65, 0.15),     "map.legend.cleared");
+                    legend_item(leg, Color::srgb(0.9, 0.2, 0.2),        "map.legend.low_air");
+                });
+
+                // Point-of-interest legend
+                left.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
+                        column_gap: Val::Px(12.0),
+                        row_gap: Val::Px(4.0),
+                        max_width: Val::Px(MINIMAP_W),
+                        ..default()
+                    },
+                ))
+                .with_children(|leg| {
+                    for kind in PoiKind::ALL {
+                        leg.spawn((
+                            Node {
+                                flex_direction: FlexDirection::Row,
+                                align_items: AlignItems::Center,
+                                column_gap: Val::Px(4.0),
+                                ..default()
+                            },
+                        ))
+                        .with_children(|row| {
+                            poi_icon(row, &font, kind, None);
+                            row.spawn((
+                                Text::new(kind.label()),
+                                TextFont { font_size: 13.0, ..default() },
+                                TextColor(Color::srgb(0.75, 0.75, 0.75)),
+                            ));
+                        });
+                    }
                 });
 
                 // Map panel
@@ -212,6 +358,8 @@ fn setup_minimap(
                         ..default()
                     },
                     BackgroundColor(Color::srgba(0.04, 0.04, 0.12, 1.0)),
+                    RelativeCursorPosition::default(),
+                    MinimapPanel,
                 ))
                 .with_children(|panel| {
                     for (i, room) in rooms.0.iter().enumerate() {
@@ -229,9 +377,11 @@ fn setup_minimap(
                                 top: Val::Px(mini_y),
                                 width: Val::Px(mini_w),
                                 height: Val::Px(mini_h),
+                                border: UiRect::all(Val::Px(2.0)),
                                 ..default()
                             },
                             BackgroundColor(Color::srgba(0.2, 0.2, 0.2, 0.5)),
+                            BorderColor(Color::NONE),
                             MinimapRoomNode { room_index: i },
                         ));
                     }
@@ -271,6 +421,22 @@ fn setup_minimap(
                         ZIndex(1),
                         MinimapPlayerDot,
                     ));
+
+                    panel.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            width: Val::Px(POI_ICON),
+                            height: Val::Px(POI_ICON),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(1.0, 0.2, 0.9)),
+                        BorderRadius::MAX,
+                        Visibility::Hidden,
+                        ZIndex(3),
+                        MinimapWaypointMarker,
+                    ));
                 });
             });
 
@@ -291,13 +457,13 @@ fn setup_minimap(
             ))
             .with_children(|inv| {
                 inv.spawn((
-                    Text::new("INVENTORY"),
+                    Text::new(tr("inventory.title")),
                     TextFont { font: font.clone(), font_size: 22.0, ..default() },
                     TextColor(Color::WHITE),
                     Node { align_self: AlignSelf::Center, margin: UiRect::bottom(Val::Px(8.0)), ..default() },
                 ));
 
-                inv_section_header(inv, &font, "WEAPONS");
+                inv_section_header(inv, &font, "inventory.section.weapons");
                 // Placeholder rows — updated each frame by update_inventory_panel.
                 // Spawn up to 4 weapon slots (expand as needed).
                 for i in 0..4usize {
@@ -309,7 +475,20 @@ fn setup_minimap(
                     ));
                 }
 
-                inv_section_header(inv, &font, "BUFFS");
+                inv_section_header(inv, &font, "inventory.section.mods");
+                inv.spawn((
+                    Text::new(""),
+                    TextFont { font: font.clone(), font_size: 15.0, ..default() },
+                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                    InventoryModStash,
+                ));
+                inv.spawn((
+                    Text::new(format!("  {}", tr("inventory.mod_help"))),
+                    TextFont { font: font.clone(), font_size: 12.0, ..default() },
+                    TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                ));
+
+                inv_section_header(inv, &font, "inventory.section.buffs");
                 inv.spawn((
                     Text::new(""),
                     TextFont { font: font.clone(), font_size: 15.0, ..default() },
@@ -329,7 +508,7 @@ fn setup_minimap(
                     InventoryBuffLine::Piercing,
                 ));
 
-                inv_section_header(inv, &font, "STATION CLUES");
+                inv_section_header(inv, &font, "inventory.section.clues");
                 inv.spawn((
                     Text::new(""),
                     TextFont { font: font.clone(), font_size: 15.0, ..default() },
@@ -338,7 +517,11 @@ fn setup_minimap(
                 ));
                 inv.spawn((
                     Text::new(""),
-                    TextFont { font: font.clone(), font_size: 15.0, ..default() },
+                    TextFont {
+                        font: asset_server.load(crate::SYMBOL_FONT_PATH),
+                        font_size: 15.0,
+                        ..default()
+                    },
                     TextColor(Color::srgb(1.0, 0.5, 0.3)),
                     InventoryClueRow::Color,
                 ));
@@ -359,7 +542,7 @@ fn setup_minimap(
                     InventoryClueRow::Signal,
                 ));
 
-                inv_section_header(inv, &font, "KEY");
+                inv_section_header(inv, &font, "inventory.section.key");
                 inv.spawn((
                     Text::new(""),
                     TextFont { font: font.clone(), font_size: 15.0, ..default() },
@@ -370,15 +553,90 @@ fn setup_minimap(
         });
 }
 
+/// Coarse cells that are hallway: a floor tile outside every room.
+pub(crate) fn hallway_cells(rooms: &RoomVec, level: &LevelRes, grid: &MapGridMeta) -> HashSet<(i32, i32)> {
+    let cols = grid.cols;
+    let rows = grid.rows;
+    let mut hallway_cells: HashSet<(i32, i32)> = HashSet::new();
+    let level_rows = level.level.len();
+    let level_cols = level.level.first().map(|r| r.len()).unwrap_or(0);
+
+    for cell_row in 0..(rows / HALLWAY_CELL + 1) {
+        for cell_col in 0..(cols / HALLWAY_CELL + 1) {
+            let tile_col = cell_col * HALLWAY_CELL + HALLWAY_CELL / 2;
+            let tile_row = cell_row * HALLWAY_CELL + HALLWAY_CELL / 2;
+
+            if tile_row >= level_rows || tile_col >= level_cols {
+                continue;
+            }
+
+            let ch = level.level[tile_row].as_bytes().get(tile_col).copied().unwrap_or(b'.');
+            if ch != b'#' {
+                continue;
+            }
+
+            // Skip if the coarse cell's tile AABB overlaps any room (not just center tile).
+            let cell_x1 = cell_col * HALLWAY_CELL;
+            let cell_y1 = cell_row * HALLWAY_CELL;
+            let cell_x2 = cell_x1 + HALLWAY_CELL - 1;
+            let cell_y2 = cell_y1 + HALLWAY_CELL - 1;
+            let in_room = rooms.0.iter().any(|r| {
+                let rx1 = r.tile_top_left_corner.x as usize;
+                let ry1 = r.tile_top_left_corner.y as usize;
+                let rx2 = r.tile_bot_right_corner.x as usize;
+                let ry2 = r.tile_bot_right_corner.y as usize;
+                cell_x1 <= rx2 && cell_x2 >= rx1 && cell_y1 <= ry2 && cell_y2 >= ry1
+            });
+
+            if !in_room {
+                hallway_cells.insert((cell_col as i32, cell_row as i32));
+            }
+        }
+    }
+
+    hallway_cells
+}
+
 fn inv_section_header(parent: &mut ChildSpawnerCommands, font: &Handle<Font>, title: &str) {
     parent.spawn((
-        Text::new(format!("── {} ──", title)),
+        Text::new(format!("── {} ──", tr(title))),
         TextFont { font: font.clone(), font_size: 13.0, ..default() },
         TextColor(Color::srgb(0.5, 0.7, 1.0)),
         Node { margin: UiRect::top(Val::Px(6.0)), ..default() },
     ));
 }
 
+/// A small coloured tile with the kind's glyph, shared by the map and legend.
+/// `at` centres it on a map-panel position; otherwise it sits in the flow.
+fn poi_icon<'a>(
+    parent: &'a mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    kind: PoiKind,
+    at: Option<Vec2>,
+) -> EntityCommands<'a> {
+    let mut node = Node {
+        width: Val::Px(POI_ICON),
+        height: Val::Px(POI_ICON),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    if let Some(at) = at {
+        node.position_type = PositionType::Absolute;
+        node.left = Val::Px(at.x - POI_ICON * 0.5);
+        node.top = Val::Px(at.y - POI_ICON * 0.5);
+    }
+    let mut icon = parent.spawn((node, BackgroundColor(kind.color()), BorderRadius::all(Val::Px(2.0))));
+    icon.with_children(|icon| {
+        icon.spawn((
+            Text::new(kind.glyph()),
+            TextFont { font: font.clone(), font_size: 9.0, ..default() },
+            TextColor(Color::BLACK),
+        ));
+    });
+    icon
+}
+
 fn legend_item(parent: &mut ChildSpawnerCommands, color: Color, label: &str) {
     parent
         .spawn((Node {
@@ -393,7 +651,7 @@ fn legend_item(parent: &mut ChildSpawnerCommands, color: Color, label: &str) {
                 BackgroundColor(color),
             ));
             row.spawn((
-                Text::new(label),
+                Text::new(tr(label)),
                 TextFont { font_size: 13.0, ..default() },
                 TextColor(Color::srgb(0.75, 0.75, 0.75)),
             ));
@@ -403,12 +661,11 @@ fn legend_item(parent: &mut ChildSpawnerCommands, color: Color, label: &str) {
 // Systems
 
 fn toggle_minimap(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<PlayerActions>,
     mut visible: ResMut<MinimapVisible>,
     mut root_q: Query<&mut Visibility, With<MinimapRoot>>,
-    bindings: Res<crate::settings::KeyBindings>,
 ) {
-    if !keys.just_pressed(bindings.inventory) {
+    if !actions.just_pressed(Action::Inventory) {
         return;
     }
     visible.0 = !visible.0;
@@ -417,6 +674,60 @@ fn toggle_minimap(
     }
 }
 
+/// Moves mods between the stash and weapons while the inventory is open.
+fn swap_weapon_mods(
+    actions: Res<PlayerActions>,
+    mut cursor: ResMut<ModCursor>,
+    mut player_q: Query<&mut WeaponInventory, With<Player>>,
+) {
+    let Ok(mut inv) = player_q.single_mut() else { return };
+
+    if actions.just_pressed(Action::ModCursorUp) {
+        cursor.0 = cursor.0.saturating_sub(1);
+    }
+    if actions.just_pressed(Action::ModCursorDown) {
+        cursor.0 += 1;
+    }
+
+    let slot_actions = [Action::ModSlot1, Action::ModSlot2, Action::ModSlot3, Action::ModSlot4];
+    if let Some(slot) = slot_actions.iter().position(|a| actions.just_pressed(*a))
+        && cursor.0 < inv.stash.len()
+    {
+        let m = inv.stash.remove(cursor.0);
+        if !inv.install_mod(slot, m) {
+            inv.stash.insert(cursor.0, m);
+        }
+    }
+
+    if actions.just_pressed(Action::UninstallMod) {
+        let equipped = inv.equipped;
+        if let Some(m) = inv.weapons[equipped].mods.pop() {
+            inv.stash.push(m);
+        }
+    }
+
+    cursor.0 = cursor.0.min(inv.stash.len().saturating_sub(1));
+}
+
+fn update_mod_stash(
+    cursor: Res<ModCursor>,
+    player_q: Query<&WeaponInventory, With<Player>>,
+    mut stash_q: Query<&mut Text, With<InventoryModStash>>,
+) {
+    let Ok(inv) = player_q.single() else { return };
+    let Ok(mut text) = stash_q.single_mut() else { return };
+
+    if inv.stash.is_empty() {
+        *text = Text::new(format!("  {}", tr("inventory.none")));
+        return;
+    }
+    let rows: Vec<String> = inv.stash.iter().enumerate().map(|(i, m)| {
+        let prefix = if i == cursor.0 { "► " } else { "  " };
+        format!("{}{}  {}", prefix, m.letter(), m.name())
+    }).collect();
+    *text = Text::new(rows.join("\n"));
+}
+
 fn update_inventory_panel(
     player_q: Query<(&WeaponInventory, &WeaponBuffStacks), With<Player>>,
     codes: Res<StationCodes>,
@@ -424,6 +735,7 @@ fn update_inventory_panel(
     symbols: Res<StationSymbols>,
     signals: Option<Res<PlanetSignals>>,
     key_state: Option<Res<crate::key_chest::LevelKeyState>>,
+    accessibility: Res<crate::settings::AccessibilitySettings>,
     mut weapon_lines: Query<(&InventoryWeaponLine, &mut Text, &mut TextColor), (Without<InventoryBuffLine>, Without<InventoryClueRow>, Without<InventoryKeyRow>)>,
     mut buff_lines: Query<(&InventoryBuffLine, &mut Text, &mut TextColor), (Without<InventoryWeaponLine>, Without<InventoryClueRow>, Without<InventoryKeyRow>)>,
     mut clue_rows: Query<(&InventoryClueRow, &mut Text, &mut TextColor), (Without<InventoryBuffLine>, Without<InventoryWeaponLine>, Without<InventoryKeyRow>)>,
@@ -436,10 +748,16 @@ fn update_inventory_panel(
             let equipped = slot.0 == inv.equipped;
             let prefix = if equipped { "► " } else { "  " };
             let pierce = weapon.effective_pierce_count();
-            *text = Text::new(format!(
-                "{}{:<12}  dmg:{:.0}  pierce:{}",
-                prefix, weapon.weapon_type.name(), weapon.damage, pierce,
-            ));
+            let slots: Vec<&str> = weapon.mods.iter().map(|m| m.letter())
+                .chain(std::iter::repeat_n("-", weapon.free_mod_slots()))
+                .collect();
+            *text = Text::new(format!("{prefix}{}", tr_args("inventory.weapon", &[
+                ("slot", &(slot.0 + 1)),
+                ("name", &format!("{:<12}", weapon.name())),
+                ("damage", &format!("{:.0}", weapon.damage)),
+                ("pierce", &pierce),
+                ("mods", &slots.join(" ")),
+            ])));
             *color = TextColor(if equipped { Color::WHITE } else { Color::srgb(0.6, 0.6, 0.6) });
         } else {
             *text = Text::new("");
@@ -448,22 +766,22 @@ fn update_inventory_panel(
 
     for (buff, mut text, mut color) in &mut buff_lines {
         let (label, count) = match buff {
-            InventoryBuffLine::AtkSpeed => ("Atk Speed", buffs.atk_speed),
-            InventoryBuffLine::Damage   => ("Damage",    buffs.damage),
-            InventoryBuffLine::Piercing => ("Piercing",  buffs.piercing),
+            InventoryBuffLine::AtkSpeed => ("inventory.buff.atk_speed", buffs.atk_speed),
+            InventoryBuffLine::Damage   => ("inventory.buff.damage",    buffs.damage),
+            InventoryBuffLine::Piercing => ("inventory.buff.piercing",  buffs.piercing),
         };
-        *text = Text::new(format!("  {:<12}  +{}", label, count));
+        *text = Text::new(format!("  {:<12}  +{}", tr(label), count));
         *color = TextColor(if count > 0 { Color::srgb(0.4, 1.0, 0.5) } else { Color::srgb(0.5, 0.5, 0.5) });
     }
 
     // Clue and key rows only change when pickups are collected — skip the
     // format!/collect work every frame when nothing has changed.
     let clues_changed = codes.is_changed() || colors.is_changed() || symbols.is_changed()
+        || accessibility.is_changed()
         || signals.as_ref().map_or(false, |s| s.is_changed())
         || key_state.as_ref().map_or(false, |k| k.is_changed());
     if !clues_changed { return; }
 
-    let color_names = ["RED", "GRN", "BLU", "YLW"];
     let symbol_chars = crate::station_symbol::SYMBOL_CHARS;
 
     for (row, mut text, _) in &mut clue_rows {
@@ -472,28 +790,28 @@ fn update_inventory_panel(
                 let slots: Vec<String> = codes.codes.iter().map(|c| {
                     c.map_or("[?]".to_string(), |d| format!("[{}]", d))
                 }).collect();
-                *text = Text::new(format!("  CODE  {}", slots.join(" ")));
+                *text = Text::new(format!("  {:<4}  {}", tr("inventory.clue.code"), slots.join(" ")));
             }
             InventoryClueRow::Color => {
                 let slots: Vec<String> = colors.colors.iter().map(|c| {
-                    c.map_or("[?  ]".to_string(), |d| format!("[{}]", color_names[d as usize]))
+                    c.map_or("[?  ]".to_string(), |d| format!("[{}]", crate::station_color::color_label(&accessibility, d)))
                 }).collect();
-                *text = Text::new(format!("  CLR   {}", slots.join(" ")));
+                *text = Text::new(format!("  {:<4}  {}", tr("inventory.clue.color"), slots.join(" ")));
             }
             InventoryClueRow::Symbol => {
                 let slots: Vec<String> = symbols.symbols.iter().map(|s| {
                     s.map_or("[?]".to_string(), |d| format!("[{}]", symbol_chars[d as usize]))
                 }).collect();
-                *text = Text::new(format!("  SYM   {}", slots.join(" ")));
+                *text = Text::new(format!("  {:<4}  {}", tr("inventory.clue.symbol"), slots.join(" ")));
             }
             InventoryClueRow::Signal => {
                 if let Some(ref sigs) = signals {
                     let slots: Vec<String> = sigs.signals.iter().map(|s| {
                         s.map_or("[?]".to_string(), |v| format!("[{}]", v))
                     }).collect();
-                    *text = Text::new(format!("  SIG   {}", slots.join(" ")));
+                    *text = Text::new(format!("  {:<4}  {}", tr("inventory.clue.signal"), slots.join(" ")));
                 } else {
-                    *text = Text::new("  SIG   — — —");
+                    *text = Text::new(format!("  {:<4}  — — —", tr("inventory.clue.signal")));
                 }
             }
         }
@@ -501,7 +819,7 @@ fn update_inventory_panel(
 
     if let Ok((mut text, mut color)) = key_rows.single_mut() {
         let has_key = key_state.map_or(false, |k| k.has_key);
-        *text = Text::new(if has_key { "  KEY   [found]" } else { "  KEY   —" });
+        *text = Text::new(format!("  {:<4}  {}", tr("inventory.clue.key"), if has_key { tr("inventory.key_found") } else { "—".to_string() }));
         *color = TextColor(if has_key {
             Color::srgb(1.0, 0.85, 0.2)
         } else {
@@ -510,6 +828,15 @@ fn update_inventory_panel(
     }
 }
 
+/// The coarse fog-of-war cell containing a world position.
+pub(crate) fn coarse_cell(pos: Vec2, grid: &MapGridMeta) -> (i32, i32) {
+    // Convert world pos to tile coordinates.
+    let tile_col = ((pos.x - grid.x0) / TILE_SIZE).round() as i32;
+    // y0 is the world Y of the bottom tile; row 0 is the top tile.
+    let tile_row = (grid.rows as i32 - 1) - ((pos.y - grid.y0) / TILE_SIZE).round() as i32;
+    (tile_col / HALLWAY_CELL as i32, tile_row / HALLWAY_CELL as i32)
+}
+
 /// Marks coarse cells near the player as visited so hallways reveal on the minimap.
 fn update_visited_cells(
     player_q: Query<&Transform, With<Player>>,
@@ -517,21 +844,7 @@ fn update_visited_cells(
     mut visited: ResMut<VisitedCells>,
 ) {
     let Ok(player_tf) = player_q.single() else { return };
-    let px = player_tf.translation.x;
-    let py = player_tf.translation.y;
-
-    let cols = grid.cols as f32;
-    let rows = grid.rows as f32;
-    let _map_px_w = cols * TILE_SIZE;
-    let _map_px_h = rows * TILE_SIZE;
-
-    // Convert player world pos to tile coordinates.
-    let tile_col = ((px - grid.x0) / TILE_SIZE).round() as i32;
-    // y0 is the world Y of the bottom tile; row 0 is the top tile.
-    let tile_row = (rows as i32 - 1) - ((py - grid.y0) / TILE_SIZE).round() as i32;
-
-    let coarse_col = tile_col / HALLWAY_CELL as i32;
-    let coarse_row = tile_row / HALLWAY_CELL as i32;
+    let (coarse_col, coarse_row) = coarse_cell(player_tf.translation.truncate(), &grid);
 
     for dr in -REVEAL_RADIUS..=REVEAL_RADIUS {
         for dc in -REVEAL_RADIUS..=REVEAL_RADIUS {
@@ -546,7 +859,7 @@ fn update_minimap(
     grid: Res<MapGridMeta>,
     lvlstate: Res<LevelState>,
     visited: Res<VisitedCells>,
-    mut room_nodes: Query<(&MinimapRoomNode, &mut BackgroundColor), Without<MinimapHallwayNode>>,
+    mut room_nodes: Query<(&MinimapRoomNode, &mut BackgroundColor, &mut BorderColor), Without<MinimapHallwayNode>>,
     mut hallway_nodes: Query<(&MinimapHallwayNode, &mut BackgroundColor, &mut Visibility)>,
     mut player_dot: Query<&mut Node, With<MinimapPlayerDot>>,
 ) {
@@ -561,8 +874,10 @@ fn update_minimap(
     };
 
     // Update room nodes
-    for (node, mut bg) in &mut room_nodes {
+    for (node, mut bg, mut border) in &mut room_nodes {
         let Some(room) = rooms.0.get(node.room_index) else { continue };
+        let low_air = room.visited && room.air_pressure < crate::room::LOW_AIR_PRESSURE;
+        border.0 = if low_air { Color::srgb(0.9, 0.2, 0.2) } else { Color::NONE };
         bg.0 = if current_room == Some(node.room_index) {
             Color::srgba(1.0, 0.9, 0.0, 1.0)
         } else if !room.visited {
@@ -595,3 +910,195 @@ fn update_minimap(
         node.top = Val::Px(dot_y);
     }
 }
+
+/// World position → map-panel pixels, matching how room nodes are placed.
+fn world_to_map(pos: Vec2, grid: &MapGridMeta) -> Vec2 {
+    let map_px_w = grid.cols as f32 * TILE_SIZE;
+    let map_px_h = grid.rows as f32 * TILE_SIZE;
+    Vec2::new(
+        (pos.x + map_px_w * 0.5) / map_px_w * MINIMAP_W,
+        (map_px_h * 0.5 - pos.y) / map_px_h * MINIMAP_H,
+    )
+}
+
+/// Map-panel pixels → world position; the inverse of `world_to_map`.
+fn map_to_world(at: Vec2, grid: &MapGridMeta) -> Vec2 {
+    let map_px_w = grid.cols as f32 * TILE_SIZE;
+    let map_px_h = grid.rows as f32 * TILE_SIZE;
+    Vec2::new(
+        at.x / MINIMAP_W * map_px_w - map_px_w * 0.5,
+        map_px_h * 0.5 - at.y / MINIMAP_H * map_px_h,
+    )
+}
+
+/// Clicks on the open map shouldn't also fire or swing the broom.
+fn hold_fire_while_map_open(mut actions: ResMut<PlayerActions>) {
+    actions.release(Action::Fire);
+    actions.release(Action::Broom);
+}
+
+/// Left-click on the map sets the waypoint; clicking it again removes it.
+fn place_waypoint(
+    mouse: Res<ButtonInput<MouseButton>>,
+    panel_q: Query<&RelativeCursorPosition, With<MinimapPanel>>,
+    grid: Res<MapGridMeta>,
+    mut waypoint: ResMut<Waypoint>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) { return; }
+    let Ok(cursor) = panel_q.single() else { return };
+    if !cursor.mouse_over() { return; }
+    let Some(normalized) = cursor.normalized else { return };
+
+    let at = normalized * Vec2::new(MINIMAP_W, MINIMAP_H);
+    let on_existing = waypoint.0
+        .is_some_and(|wp| world_to_map(wp, &grid).distance(at) <= WAYPOINT_CLEAR_RADIUS);
+    waypoint.0 = if on_existing { None } else { Some(map_to_world(at, &grid)) };
+}
+
+/// Gathers every point of interest the player has seen: anything inside a
+/// visited room or a revealed hallway cell.
+fn collect_map_pois(
+    mut pois: ResMut<MapPois>,
+    rooms: Res<RoomVec>,
+    grid: Res<MapGridMeta>,
+    visited: Res<VisitedCells>,
+    codes: Query<&Transform, With<crate::station_code::CodeFragment>>,
+    colors: Query<&Transform, With<crate::station_color::ColorChip>>,
+    symbols: Query<&Transform, With<crate::station_symbol::SymbolChip>>,
+    keys: Query<&Transform, With<crate::key_chest::KeyPickup>>,
+    chests: Query<&Transform, With<crate::key_chest::Chest>>,
+    terminals: Query<&Transform, Or<(
+        With<crate::planet::ColorTerminal>,
+        With<crate::planet::SymbolTerminal>,
+        With<crate::planet::FreqMaster>,
+    )>>,
+    dials: Query<&Transform, With<crate::planet::DialButton>>,
+    doors: Query<&Transform, Or<(With<crate::planet::CodeDoor>, With<crate::planet::PlanetBossDoor>)>>,
+    windows: Query<(&Transform, &crate::window::GlassState), With<crate::window::Window>>,
+) {
+    let discovered = |pos: Vec2| {
+        visited.0.contains(&coarse_cell(pos, &grid))
+            || rooms.0.iter().any(|r| r.visited && r.bounds_check(pos))
+    };
+
+    let mut found: Vec<(PoiKind, Vec2)> = Vec::new();
+    for room in rooms.0.iter().filter(|r| r.is_airlock) {
+        let center = (room.top_left_corner + room.bot_right_corner) * 0.5;
+        if discovered(center) {
+            found.push((PoiKind::Airlock, center));
+        }
+    }
+    let sources: [(PoiKind, Vec<Vec2>); 8] = [
+        (PoiKind::Code,     codes.iter().map(|t| t.translation.truncate()).collect()),
+        (PoiKind::Color,    colors.iter().map(|t| t.translation.truncate()).collect()),
+        (PoiKind::Symbol,   symbols.iter().map(|t| t.translation.truncate()).collect()),
+        (PoiKind::Key,      keys.iter().map(|t| t.translation.truncate()).collect()),
+        (PoiKind::Chest,    chests.iter().map(|t| t.translation.truncate()).collect()),
+        (PoiKind::Terminal, terminals.iter().map(|t| t.translation.truncate()).collect()),
+        (PoiKind::Dial,     dials.iter().map(|t| t.translation.truncate()).collect()),
+        (PoiKind::Door,     doors.iter().map(|t| t.translation.truncate()).collect()),
+    ];
+    for (kind, positions) in sources {
+        found.extend(positions.into_iter().filter(|p| discovered(*p)).map(|p| (kind, p)));
+    }
+    found.extend(
+        windows.iter()
+            .filter(|(_, glass)| **glass == crate::window::GlassState::Broken)
+            .map(|(t, _)| t.translation.truncate())
+            .filter(|p| discovered(*p))
+            .map(|p| (PoiKind::BrokenWindow, p)),
+    );
+
+    let found: Vec<(PoiKind, Vec2)> = found.into_iter().map(|(k, p)| (k, world_to_map(p, &grid))).collect();
+    pois.set_if_neq(MapPois(found));
+}
+
+fn draw_map_pois(
+    mut commands: Commands,
+    pois: Res<MapPois>,
+    asset_server: Res<AssetServer>,
+    panel_q: Query<Entity, With<MinimapPanel>>,
+    icons: Query<Entity, With<MinimapPoiIcon>>,
+) {
+    if !pois.is_changed() { return; }
+    let Ok(panel) = panel_q.single() else { return };
+    for e in &icons {
+        commands.entity(e).despawn();
+    }
+
+    let font: Handle<Font> = asset_server.load(FONT_PATH);
+    commands.entity(panel).with_children(|panel| {
+        for &(kind, at) in &pois.0 {
+            let at = at.clamp(Vec2::splat(POI_ICON * 0.5), Vec2::new(MINIMAP_W, MINIMAP_H) - POI_ICON * 0.5);
+            poi_icon(panel, &font, kind, Some(at)).insert((ZIndex(2), MinimapPoiIcon));
+        }
+    });
+}
+
+fn update_waypoint_marker(
+    waypoint: Res<Waypoint>,
+    grid: Res<MapGridMeta>,
+    mut marker_q: Query<(&mut Node, &mut Visibility), With<MinimapWaypointMarker>>,
+) {
+    let Ok((mut node, mut vis)) = marker_q.single_mut() else { return };
+    match waypoint.0 {
+        Some(wp) => {
+            let at = world_to_map(wp, &grid);
+            node.left = Val::Px(at.x - POI_ICON * 0.5);
+            node.top = Val::Px(at.y - POI_ICON * 0.5);
+            *vis = Visibility::Inherited;
+        }
+        None => *vis = Visibility::Hidden,
+    }
+}
+
+fn spawn_waypoint_arrow(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        Text2d::new("▲"),
+        TextFont { font: asset_server.load(SYMBOL_FONT_PATH), font_size: 28.0, ..default() },
+        TextColor(Color::srgb(1.0, 0.2, 0.9)),
+        Transform::from_xyz(0.0, 0.0, Z_UI),
+        Visibility::Hidden,
+        WaypointArrow,
+        GameEntity,
+    ));
+}
+
+/// Points at the waypoint: hovering over it when it's on screen, otherwise
+/// pinned to the screen edge in its direction. Clears it on arrival.
+fn update_waypoint_arrow(
+    mut waypoint: ResMut<Waypoint>,
+    player_q: Query<&Transform, (With<Player>, Without<WaypointArrow>)>,
+    camera_q: Query<&Transform, (With<MainCamera>, Without<WaypointArrow>)>,
+    window_q: Query<&bevy::window::Window, With<bevy::window::PrimaryWindow>>,
+    mut arrow_q: Query<(&mut Transform, &mut Visibility), With<WaypointArrow>>,
+) {
+    let Ok((mut arrow_tf, mut vis)) = arrow_q.single_mut() else { return };
+    let Some(target) = waypoint.0 else {
+        *vis = Visibility::Hidden;
+        return;
+    };
+    if let Ok(player_tf) = player_q.single()
+        && player_tf.translation.truncate().distance(target) <= WAYPOINT_ARRIVE_DIST
+    {
+        waypoint.0 = None;
+        *vis = Visibility::Hidden;
+        return;
+    }
+    let (Ok(camera_tf), Ok(window)) = (camera_q.single(), window_q.single()) else { return };
+
+    let center = camera_tf.translation.truncate();
+    let half = (Vec2::new(window.width(), window.height()) * 0.5 - ARROW_MARGIN).max(Vec2::ONE);
+    let offset = target - center;
+
+    let (pos, angle) = if offset.x.abs() <= half.x && offset.y.abs() <= half.y {
+        // On screen: hang above the spot, pointing down at it.
+        (target + Vec2::new(0.0, TILE_SIZE * 0.75), std::f32::consts::PI)
+    } else {
+        let scale = (half.x / offset.x.abs()).min(half.y / offset.y.abs());
+        (center + offset * scale, offset.to_angle() - std::f32::consts::FRAC_PI_2)
+    };
+    arrow_tf.translation = pos.extend(Z_UI);
+    arrow_tf.rotation = Quat::from_rotation_z(angle);
+    *vis = Visibility::Visible;
+}
@@ -134,20 +134,20 @@ fn do_pause(commands: &mut Commands, font: Handle<Font>, time: &mut Time<Virtual
             .with_children(|panel| {
                 panel.spawn((Node::default(),)).with_children(|c| {
                     c.spawn((
-                        Text::new("PAUSED"),
+                        crate::locale::loc_text("pause.title"),
                         TextFont { font: font.clone(), font_size: 36.0, ..default() },
                         TextColor(Color::WHITE),
                     ));
                 });
 
-                spawn_pause_button(panel, font.clone(), "Resume",    PauseButton::Resume,   Color::srgba(0.08, 0.42, 0.08, 0.9));
-                spawn_pause_button(panel, font.clone(), "Settings",  PauseButton::Settings, Color::srgba(0.1,  0.1,  0.42, 0.9));
-                spawn_pause_button(panel, font.clone(), "Main Menu", PauseButton::MainMenu, Color::srgba(0.38, 0.08, 0.08, 0.9));
+                spawn_pause_button(panel, font.clone(), "pause.resume",    PauseButton::Resume,   Color::srgba(0.08, 0.42, 0.08, 0.9));
+                spawn_pause_button(panel, font.clone(), "pause.settings",  PauseButton::Settings, Color::srgba(0.1,  0.1,  0.42, 0.9));
+                spawn_pause_button(panel, font.clone(), "pause.main_menu", PauseButton::MainMenu, Color::srgba(0.38, 0.08, 0.08, 0.9));
             });
         });
 }
 
-fn spawn_pause_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, label: &str, button: PauseButton, bg: Color) {
+fn spawn_pause_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, label: &'static str, button: PauseButton, bg: Color) {
     parent
         .spawn((
             Button,
@@ -165,7 +165,7 @@ fn spawn_pause_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, lab
         ))
         .with_children(|b| {
             b.spawn((
-                Text::new(label),
+                crate::locale::loc_text(label),
                 TextFont { font, font_size: 24.0, ..default() },
                 TextColor(Color::WHITE),
             ));
@@ -120,6 +120,8 @@ pub struct TerminalSession {
     pub(super) wrong_timer: Option<Timer>,
     pub(super) planet_idx: u32,
     pub(super) font: Handle<Font>,
+    /// For popups that may carry colour glyphs.
+    pub(super) symbol_font: Handle<Font>,
 }
 
 /// Marker for the floating prompt near a terminal.
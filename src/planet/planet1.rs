@@ -16,13 +16,15 @@ use crate::enemies::{
     ActiveEnemy, AnimationTimer, Enemy, EnemyFrames, EnemyMoveSpeed, EnemyRes,
     HitAnimation, MeleeEnemy, Velocity, ENEMY_SPEED,
 };
+use crate::locale::{tr, tr_args};
 use crate::map::{Door, GeneratedLevel, TileRes};
 use crate::player::{Player, aabb_overlap};
 use crate::room::{Room, RoomVec};
 use crate::station_code::StationCodes;
-use crate::station_color::StationColors;
+use crate::settings::AccessibilitySettings;
+use crate::station_color::{StationColors, COLOR_NAMES};
 use crate::station_symbol::{StationSymbols, SYMBOL_CHARS};
-use crate::settings::KeyBindings;
+use crate::replay::{Action, PlayerActions};
 use rand::random_range;
 
 // ── Planet 1 constants ────────────────────────────────────────────────────────
@@ -316,8 +318,7 @@ pub(super) fn code_door_proximity(
     door_q: Query<(Entity, &Transform, &CodeDoor)>,
     prompt_q: Query<Entity, With<CodeDoorPrompt>>,
     entry_state: Option<Res<CodeEntryState>>,
-    input: Res<ButtonInput<KeyCode>>,
-    bindings: Res<KeyBindings>,
+    actions: Res<PlayerActions>,
     asset_server: Res<AssetServer>,
 ) {
     if entry_state.is_some() { return; }
@@ -344,7 +345,7 @@ pub(super) fn code_door_proximity(
     if let Some((door_entity, door_pos)) = near_door {
         let font: Handle<Font> = asset_server.load(FONT_PATH);
         commands.spawn((
-            Text2d::new("[E] Enter Code"),
+            Text2d::new(tr("planet.prompt.enter_code")),
             TextFont { font, font_size: 18.0, ..default() },
             TextColor(Color::srgb(0.2, 1.0, 1.0)),
             Transform::from_translation(door_pos + Vec3::new(0.0, TILE_SIZE * 1.5, 10.0)),
@@ -352,7 +353,7 @@ pub(super) fn code_door_proximity(
             GameEntity,
         ));
 
-        if input.just_pressed(bindings.interact) {
+        if actions.just_pressed(Action::Interact) {
             commands.insert_resource(CodeEntryState {
                 door_entity,
                 entered: [0; 3],
@@ -393,7 +394,7 @@ fn spawn_code_entry_ui(commands: &mut Commands, asset_server: &AssetServer) {
         ))
         .with_children(|panel| {
             panel.spawn((
-                Text::new("ENTER CODE"),
+                Text::new(tr("planet.keypad.title")),
                 TextFont { font: font.clone(), font_size: 22.0, ..default() },
                 TextColor(Color::srgb(0.2, 1.0, 1.0)),
             ));
@@ -417,7 +418,7 @@ fn spawn_code_entry_ui(commands: &mut Commands, asset_server: &AssetServer) {
                 });
 
             panel.spawn((
-                Text::new("W/S change  A/D move  Enter=submit  E=close"),
+                Text::new(tr("planet.keypad.help")),
                 TextFont { font: font.clone(), font_size: 14.0, ..default() },
                 TextColor(Color::srgb(0.6, 0.6, 0.6)),
                 CodeStatusText,
@@ -432,8 +433,7 @@ pub(super) fn update_code_entry_ui(
     mut signals: ResMut<PlanetSignals>,
     mut dial_targets: ResMut<DialTargets>,
     planet_count: Res<PlanetCount>,
-    input: Res<ButtonInput<KeyCode>>,
-    bindings: Res<KeyBindings>,
+    actions: Res<PlayerActions>,
     time: Res<Time>,
     player_q: Query<&Transform, With<Player>>,
     mut digit_q: Query<(&CodeDigitSlot, &mut Text, &mut TextColor)>,
@@ -449,31 +449,31 @@ pub(super) fn update_code_entry_ui(
         if timer.just_finished() {
             state.wrong_timer = None;
             if let Ok((mut txt, mut col)) = status_q.single_mut() {
-                *txt = Text::new("W/S change  A/D move  Enter=submit  E=close");
+                *txt = Text::new(tr("planet.keypad.help"));
                 *col = TextColor(Color::srgb(0.6, 0.6, 0.6));
             }
         }
         return;
     }
 
-    if input.just_pressed(bindings.interact) {
+    if actions.just_pressed(Action::Interact) {
         close_keypad(&mut commands, &ui_q);
         return;
     }
 
     let cursor_before = state.cursor;
 
-    if input.just_pressed(bindings.move_left) && state.cursor > 0 {
+    if actions.just_pressed(Action::MoveLeft) && state.cursor > 0 {
         state.cursor -= 1;
     }
-    if input.just_pressed(bindings.move_right) && state.cursor < 2 {
+    if actions.just_pressed(Action::MoveRight) && state.cursor < 2 {
         state.cursor += 1;
     }
-    if input.just_pressed(bindings.move_up) {
+    if actions.just_pressed(Action::MoveUp) {
         let idx = state.cursor;
         state.entered[idx] = (state.entered[idx] + 1) % 10;
     }
-    if input.just_pressed(bindings.move_down) {
+    if actions.just_pressed(Action::MoveDown) {
         let idx = state.cursor;
         state.entered[idx] = (state.entered[idx] + 9) % 10;
     }
@@ -492,7 +492,7 @@ pub(super) fn update_code_entry_ui(
         let _ = cursor_changed;
     }
 
-    if input.just_pressed(KeyCode::Enter) {
+    if actions.just_pressed(Action::Submit) {
         let correct = codes.codes.iter().zip(state.entered.iter()).all(|(stored, entered)| {
             stored.map_or(false, |d| d == *entered)
         });
@@ -518,7 +518,7 @@ pub(super) fn update_code_entry_ui(
                 let sig_a: u8 = random_range(1u8..=5u8);
                 signals.signals[0] = Some(sig_a);
                 commands.spawn((
-                    Text2d::new(format!("Signal Strength A: {}", sig_a)),
+                    Text2d::new(tr_args("planet.signal_strength", &[("dial", &"A"), ("value", &sig_a)])),
                     TextFont { font, font_size: 20.0, ..default() },
                     TextColor(Color::srgb(0.2, 1.0, 0.5)),
                     Transform::from_translation(popup_pos),
@@ -529,7 +529,7 @@ pub(super) fn update_code_entry_ui(
                 let target: u8 = random_range(0u8..=9u8);
                 dial_targets.targets[0] = Some(target);
                 commands.spawn((
-                    Text2d::new(format!("Dial A Target: {}", target)),
+                    Text2d::new(tr_args("planet.dial_target", &[("dial", &"A"), ("value", &target)])),
                     TextFont { font, font_size: 20.0, ..default() },
                     TextColor(Color::srgb(0.9, 0.9, 0.2)),
                     Transform::from_translation(popup_pos),
@@ -541,7 +541,7 @@ pub(super) fn update_code_entry_ui(
             close_keypad(&mut commands, &ui_q);
         } else {
             if let Ok((mut txt, mut col)) = status_q.single_mut() {
-                *txt = Text::new("✗  INCORRECT CODE  ✗");
+                *txt = Text::new(tr("planet.keypad.incorrect"));
                 *col = TextColor(Color::srgb(1.0, 0.2, 0.2));
             }
             state.wrong_timer = Some(Timer::from_seconds(1.5, TimerMode::Once));
@@ -558,11 +558,27 @@ fn close_keypad(commands: &mut Commands, ui_q: &Query<Entity, With<CodeEntryUi>>
 
 // ── Terminal helpers ──────────────────────────────────────────────────────────
 
-fn terminal_display(kind: TerminalKind, val: u8) -> &'static str {
+fn terminal_display(kind: TerminalKind, val: u8) -> String {
     match kind {
-        TerminalKind::Color  => ["RED", "GRN", "BLU", "YLW"][val as usize],
-        TerminalKind::Symbol => SYMBOL_CHARS[val as usize],
-        TerminalKind::Freq   => ["▰▱▱▱▱", "▰▰▱▱▱", "▰▰▰▱▱", "▰▰▰▰▱", "▰▰▰▰▰"][val as usize],
+        TerminalKind::Color  => tr(COLOR_NAMES[val as usize]),
+        TerminalKind::Symbol => SYMBOL_CHARS[val as usize].to_string(),
+        TerminalKind::Freq   => ["▰▱▱▱▱", "▰▰▱▱▱", "▰▰▰▱▱", "▰▰▰▰▱", "▰▰▰▰▰"][val as usize].to_string(),
+    }
+}
+
+/// Slot text and colour. Colour slots use the accessibility palette and glyphs;
+/// the others are white under the cursor and grey elsewhere.
+fn terminal_slot(kind: TerminalKind, val: u8, selected: bool, accessibility: &AccessibilitySettings) -> (String, Color) {
+    match kind {
+        TerminalKind::Color => {
+            let color = accessibility.puzzle_color(val);
+            let label = crate::station_color::color_label(accessibility, val);
+            (label, if selected { color } else { color.with_alpha(0.6) })
+        }
+        _ => {
+            let color = if selected { Color::WHITE } else { Color::srgb(0.5, 0.5, 0.5) };
+            (terminal_display(kind, val), color)
+        }
     }
 }
 
@@ -574,12 +590,12 @@ fn terminal_max(kind: TerminalKind) -> u8 {
     }
 }
 
-fn terminal_title(kind: TerminalKind) -> &'static str {
-    match kind {
-        TerminalKind::Color  => "COLOR TERMINAL",
-        TerminalKind::Symbol => "SYMBOL TERMINAL",
-        TerminalKind::Freq   => "FREQUENCY MASTER",
-    }
+fn terminal_title(kind: TerminalKind) -> String {
+    tr(match kind {
+        TerminalKind::Color  => "planet.terminal.color",
+        TerminalKind::Symbol => "planet.terminal.symbol",
+        TerminalKind::Freq   => "planet.terminal.freq",
+    })
 }
 
 fn terminal_accent(kind: TerminalKind) -> Color {
@@ -603,8 +619,7 @@ pub(super) fn terminal_proximity(
     code_session: Option<Res<CodeEntryState>>,
     signals: Res<PlanetSignals>,
     planet_count: Res<PlanetCount>,
-    input: Res<ButtonInput<KeyCode>>,
-    bindings: Res<KeyBindings>,
+    actions: Res<PlayerActions>,
     asset_server: Res<AssetServer>,
 ) {
     if session.is_some() || code_session.is_some() { return; }
@@ -650,9 +665,9 @@ pub(super) fn terminal_proximity(
     let freq_locked = kind == TerminalKind::Freq && signals.signals.iter().any(|s| s.is_none());
 
     let prompt_text = if freq_locked {
-        "[LOCKED] Need all 3 signals".to_string()
+        tr("planet.prompt.freq_locked")
     } else {
-        "[E] Interact".to_string()
+        tr("planet.prompt.interact")
     };
     let prompt_color = if freq_locked {
         Color::srgb(0.8, 0.2, 0.2)
@@ -669,7 +684,7 @@ pub(super) fn terminal_proximity(
         GameEntity,
     ));
 
-    if !freq_locked && input.just_pressed(bindings.interact) {
+    if !freq_locked && actions.just_pressed(Action::Interact) {
         commands.insert_resource(TerminalSession {
             terminal_entity,
             kind,
@@ -678,6 +693,7 @@ pub(super) fn terminal_proximity(
             wrong_timer: None,
             planet_idx: planet_count.0,
             font: asset_server.load(FONT_PATH),
+            symbol_font: asset_server.load(SYMBOL_FONT_PATH),
         });
         spawn_terminal_ui(&mut commands, &asset_server, kind);
     }
@@ -685,10 +701,8 @@ pub(super) fn terminal_proximity(
 
 fn spawn_terminal_ui(commands: &mut Commands, asset_server: &AssetServer, kind: TerminalKind) {
     let font: Handle<Font> = asset_server.load(FONT_PATH);
-    let slot_font: Handle<Font> = match kind {
-        TerminalKind::Symbol | TerminalKind::Freq => asset_server.load(SYMBOL_FONT_PATH),
-        _ => font.clone(),
-    };
+    // Colour slots may carry glyphs, which only the symbol font has.
+    let slot_font: Handle<Font> = asset_server.load(SYMBOL_FONT_PATH);
     let accent = terminal_accent(kind);
 
     let (ui_w, ui_margin) = match kind {
@@ -743,7 +757,7 @@ fn spawn_terminal_ui(commands: &mut Commands, asset_server: &AssetServer, kind:
                 });
 
             panel.spawn((
-                Text::new("W/S change  A/D move  Enter=submit  E=close"),
+                Text::new(tr("planet.keypad.help")),
                 TextFont { font: font.clone(), font_size: 14.0, ..default() },
                 TextColor(Color::srgb(0.6, 0.6, 0.6)),
                 TerminalStatusText,
@@ -760,8 +774,8 @@ pub(super) fn update_terminal_ui(
     symbols: Res<StationSymbols>,
     mut signals: ResMut<PlanetSignals>,
     mut dial_targets: ResMut<DialTargets>,
-    input: Res<ButtonInput<KeyCode>>,
-    bindings: Res<KeyBindings>,
+    actions: Res<PlayerActions>,
+    accessibility: Res<AccessibilitySettings>,
     time: Res<Time>,
     player_q: Query<&Transform, With<Player>>,
     mut slot_q: Query<(&TerminalSlot, &mut Text, &mut TextColor)>,
@@ -778,14 +792,14 @@ pub(super) fn update_terminal_ui(
         if timer.just_finished() {
             state.wrong_timer = None;
             if let Ok((mut txt, mut col)) = status_q.single_mut() {
-                *txt = Text::new("W/S change  A/D move  Enter=submit  E=close");
+                *txt = Text::new(tr("planet.keypad.help"));
                 *col = TextColor(Color::srgb(0.6, 0.6, 0.6));
             }
         }
         return;
     }
 
-    if input.just_pressed(bindings.interact) {
+    if actions.just_pressed(Action::Interact) {
         close_terminal(&mut commands, &ui_q);
         return;
     }
@@ -793,17 +807,17 @@ pub(super) fn update_terminal_ui(
     let kind = state.kind;
     let max_val = terminal_max(kind);
 
-    if input.just_pressed(bindings.move_left) && state.cursor > 0 {
+    if actions.just_pressed(Action::MoveLeft) && state.cursor > 0 {
         state.cursor -= 1;
     }
-    if input.just_pressed(bindings.move_right) && state.cursor < 2 {
+    if actions.just_pressed(Action::MoveRight) && state.cursor < 2 {
         state.cursor += 1;
     }
-    if input.just_pressed(bindings.move_up) {
+    if actions.just_pressed(Action::MoveUp) {
         let idx = state.cursor;
         state.entered[idx] = (state.entered[idx] + 1) % (max_val + 1);
     }
-    if input.just_pressed(bindings.move_down) {
+    if actions.just_pressed(Action::MoveDown) {
         let idx = state.cursor;
         state.entered[idx] = (state.entered[idx] + max_val) % (max_val + 1);
     }
@@ -811,16 +825,17 @@ pub(super) fn update_terminal_ui(
     for (slot, mut txt, mut col) in &mut slot_q {
         let i = slot.0;
         let val = state.entered[i];
-        if i == state.cursor {
-            *txt = Text::new(format!("> {} <", terminal_display(kind, val)));
-            *col = TextColor(Color::WHITE);
+        let selected = i == state.cursor;
+        let (label, color) = terminal_slot(kind, val, selected, &accessibility);
+        if selected {
+            *txt = Text::new(format!("> {} <", label));
         } else {
-            *txt = Text::new(format!("  {}  ", terminal_display(kind, val)));
-            *col = TextColor(Color::srgb(0.5, 0.5, 0.5));
+            *txt = Text::new(format!("  {}  ", label));
         }
+        *col = TextColor(color);
     }
 
-    if input.just_pressed(KeyCode::Enter) {
+    if actions.just_pressed(Action::Submit) {
         let correct = match kind {
             TerminalKind::Color  => colors.colors.iter().zip(state.entered.iter())
                 .all(|(s, e)| s.map_or(false, |v| v == *e)),
@@ -833,6 +848,7 @@ pub(super) fn update_terminal_ui(
         if correct {
             let terminal_entity = state.terminal_entity;
             let font = state.font.clone();
+            let symbol_font = state.symbol_font.clone();
             let planet_idx = state.planet_idx;
             let popup_pos = player_q.single()
                 .map(|tf| tf.translation + Vec3::new(0.0, TILE_SIZE * 2.0, 100.0))
@@ -852,7 +868,7 @@ pub(super) fn update_terminal_ui(
                         let sig = random_range(1u8..=5u8);
                         signals.signals[1] = Some(sig);
                         commands.spawn((
-                            Text2d::new(format!("Signal Strength B: {}", sig)),
+                            Text2d::new(tr_args("planet.signal_strength", &[("dial", &"B"), ("value", &sig)])),
                             TextFont { font, font_size: 20.0, ..default() },
                             TextColor(Color::srgb(1.0, 0.5, 0.2)),
                             Transform::from_translation(popup_pos),
@@ -863,9 +879,9 @@ pub(super) fn update_terminal_ui(
                         let target = random_range(0u8..=3u8);
                         dial_targets.targets[1] = Some(target);
                         commands.spawn((
-                            Text2d::new(format!("Dial B Target: {}", target)),
-                            TextFont { font, font_size: 20.0, ..default() },
-                            TextColor(Color::srgb(1.0, 0.6, 0.1)),
+                            Text2d::new(tr_args("planet.dial_target", &[("dial", &"B"), ("value", &crate::station_color::color_label(&accessibility, target))])),
+                            TextFont { font: symbol_font, font_size: 20.0, ..default() },
+                            TextColor(accessibility.puzzle_color(target)),
                             Transform::from_translation(popup_pos),
                             crate::rewards::RewardPopup { timer: Timer::from_seconds(3.0, TimerMode::Once) },
                             GameEntity,
@@ -885,7 +901,7 @@ pub(super) fn update_terminal_ui(
                         let sig = random_range(1u8..=5u8);
                         signals.signals[2] = Some(sig);
                         commands.spawn((
-                            Text2d::new(format!("Signal Strength C: {}", sig)),
+                            Text2d::new(tr_args("planet.signal_strength", &[("dial", &"C"), ("value", &sig)])),
                             TextFont { font, font_size: 20.0, ..default() },
                             TextColor(Color::srgb(0.8, 0.3, 1.0)),
                             Transform::from_translation(popup_pos),
@@ -896,7 +912,7 @@ pub(super) fn update_terminal_ui(
                         let target = random_range(0u8..=5u8);
                         dial_targets.targets[2] = Some(target);
                         commands.spawn((
-                            Text2d::new(format!("Dial C Target: {}", target)),
+                            Text2d::new(tr_args("planet.dial_target", &[("dial", &"C"), ("value", &target)])),
                             TextFont { font, font_size: 20.0, ..default() },
                             TextColor(Color::srgb(0.8, 0.3, 1.0)),
                             Transform::from_translation(popup_pos),
@@ -913,7 +929,7 @@ pub(super) fn update_terminal_ui(
                         commands.entity(entity).insert(FreqMaster { unlocked: true });
                     }
                     commands.spawn((
-                        Text2d::new("Boss Arena Unlocked!"),
+                        Text2d::new(tr("planet.boss_unlocked")),
                         TextFont { font, font_size: 24.0, ..default() },
                         TextColor(Color::srgb(0.2, 1.0, 0.4)),
                         Transform::from_translation(popup_pos),
@@ -926,7 +942,7 @@ pub(super) fn update_terminal_ui(
             close_terminal(&mut commands, &ui_q);
         } else {
             if let Ok((mut txt, mut col)) = status_q.single_mut() {
-                *txt = Text::new("✗  INCORRECT  ✗");
+                *txt = Text::new(tr("planet.terminal.incorrect"));
                 *col = TextColor(Color::srgb(1.0, 0.2, 0.2));
             }
             state.wrong_timer = Some(Timer::from_seconds(1.5, TimerMode::Once));
@@ -15,11 +15,13 @@ use super::planet1::{
     P1_EXIT_TLC,  P1_EXIT_BRC,  P1_EXIT_TILE_TLC,  P1_EXIT_TILE_BRC,
     make_empty_layout, planet_enemy_room,
 };
-use crate::{GameEntity, FONT_PATH, TILE_SIZE};
+use crate::{GameEntity, FONT_PATH, SYMBOL_FONT_PATH, TILE_SIZE};
 use crate::collidable::{Collidable, Collider};
+use crate::locale::{tr, tr_args};
 use crate::player::{Player, aabb_overlap};
 use crate::room::{Room, RoomVec};
-use crate::settings::KeyBindings;
+use crate::replay::{Action, PlayerActions};
+use crate::settings::AccessibilitySettings;
 
 // ── Planet 2 room builder ─────────────────────────────────────────────────────
 
@@ -58,8 +60,8 @@ pub(super) fn dial_proximity(
     session: Option<Res<TerminalSession>>,
     code_session: Option<Res<CodeEntryState>>,
     dial_targets: Res<DialTargets>,
-    input: Res<ButtonInput<KeyCode>>,
-    bindings: Res<KeyBindings>,
+    actions: Res<PlayerActions>,
+    accessibility: Res<AccessibilitySettings>,
     asset_server: Res<AssetServer>,
 ) {
     if dial_state.is_some() || session.is_some() || code_session.is_some() { return; }
@@ -82,11 +84,11 @@ pub(super) fn dial_proximity(
 
     let Some((dial_entity, dial_pos, dial_idx, dial_type, locked, current)) = near else { return };
 
-    let label = ["DIAL A", "DIAL B", "DIAL C"][dial_idx];
+    let label = tr_args("planet.dial.name", &[("dial", &["A", "B", "C"][dial_idx])]);
     let (prompt_text, prompt_color) = if locked {
-        (format!("[{}] LOCKED — solve terminal first", label), Color::srgb(0.8, 0.2, 0.2))
+        (tr_args("planet.dial.locked", &[("dial", &label)]), Color::srgb(0.8, 0.2, 0.2))
     } else {
-        (format!("[E] Set {}", label), Color::srgb(0.2, 1.0, 1.0))
+        (tr_args("planet.dial.set", &[("dial", &label)]), Color::srgb(0.2, 1.0, 1.0))
     };
 
     let font: Handle<Font> = asset_server.load(FONT_PATH);
@@ -99,36 +101,55 @@ pub(super) fn dial_proximity(
         GameEntity,
     ));
 
-    if !locked && input.just_pressed(bindings.interact) {
+    if !locked && actions.just_pressed(Action::Interact) {
         commands.insert_resource(DialInteractState {
             dial_entity,
             dial_idx,
             dial_type,
             current,
         });
-        spawn_dial_ui(&mut commands, &asset_server, dial_idx, dial_type, current, dial_targets.targets[dial_idx]);
+        spawn_dial_ui(&mut commands, &asset_server, &accessibility, dial_idx, dial_type, current, dial_targets.targets[dial_idx]);
+    }
+}
+
+/// Text and colour for a dial value. Colour dials show the palette colour and
+/// glyph instead of a bare number.
+fn dial_value(dial_type: DialType, value: u8, accessibility: &AccessibilitySettings) -> (String, Color) {
+    match dial_type {
+        DialType::Color => (
+            crate::station_color::color_label(accessibility, value),
+            accessibility.puzzle_color(value),
+        ),
+        _ => (value.to_string(), Color::WHITE),
     }
 }
 
 fn spawn_dial_ui(
     commands: &mut Commands,
     asset_server: &AssetServer,
+    accessibility: &AccessibilitySettings,
     dial_idx: usize,
     dial_type: DialType,
     current: u8,
     target: Option<u8>,
 ) {
     let font: Handle<Font> = asset_server.load(FONT_PATH);
-    let title = ["DIAL A", "DIAL B", "DIAL C"][dial_idx];
+    let value_font: Handle<Font> = asset_server.load(SYMBOL_FONT_PATH);
+    let title = tr_args("planet.dial.name", &[("dial", &["A", "B", "C"][dial_idx])]);
     let accent = match dial_type {
         DialType::Code   => Color::srgb(0.9, 0.9, 0.2),
         DialType::Color  => Color::srgb(1.0, 0.6, 0.1),
         DialType::Symbol => Color::srgb(0.8, 0.3, 1.0),
     };
-    let target_str = match target {
-        Some(t) => format!("TARGET: {}", t),
-        None    => "TARGET: ???".to_string(),
+    let (target_str, target_color) = match target {
+        Some(t) => {
+            let (label, color) = dial_value(dial_type, t, accessibility);
+            let color = if dial_type == DialType::Color { color } else { Color::srgb(0.7, 0.7, 0.7) };
+            (tr_args("planet.dial.target", &[("value", &label)]), color)
+        }
+        None    => (tr_args("planet.dial.target", &[("value", &"???")]), Color::srgb(0.7, 0.7, 0.7)),
     };
+    let (current_str, current_color) = dial_value(dial_type, current, accessibility);
 
     commands
         .spawn((
@@ -159,17 +180,17 @@ fn spawn_dial_ui(
             ));
             panel.spawn((
                 Text::new(target_str),
-                TextFont { font: font.clone(), font_size: 16.0, ..default() },
-                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                TextFont { font: value_font.clone(), font_size: 16.0, ..default() },
+                TextColor(target_color),
             ));
             panel.spawn((
-                Text::new(format!("▲  {}  ▼", current)),
-                TextFont { font: font.clone(), font_size: 32.0, ..default() },
-                TextColor(Color::WHITE),
+                Text::new(format!("▲  {}  ▼", current_str)),
+                TextFont { font: value_font.clone(), font_size: 32.0, ..default() },
+                TextColor(current_color),
                 DialCurrentText,
             ));
             panel.spawn((
-                Text::new("W/S  change    Enter  confirm    E  close"),
+                Text::new(tr("planet.dial.help")),
                 TextFont { font: font.clone(), font_size: 13.0, ..default() },
                 TextColor(Color::srgb(0.5, 0.5, 0.5)),
             ));
@@ -183,9 +204,9 @@ pub(super) fn update_dial_ui(
     dial_state: Option<ResMut<DialInteractState>>,
     mut dial_q: Query<(&mut DialButton, &mut Sprite)>,
     dial_targets: Res<DialTargets>,
-    input: Res<ButtonInput<KeyCode>>,
-    bindings: Res<KeyBindings>,
-    mut current_text_q: Query<&mut Text, With<DialCurrentText>>,
+    actions: Res<PlayerActions>,
+    accessibility: Res<AccessibilitySettings>,
+    mut current_text_q: Query<(&mut Text, &mut TextColor), With<DialCurrentText>>,
     ui_q: Query<Entity, With<DialUi>>,
 ) {
     let Some(mut state) = dial_state else { return };
@@ -196,23 +217,25 @@ pub(super) fn update_dial_ui(
         DialType::Symbol => 5,
     };
 
-    if input.just_pressed(bindings.interact) {
+    if actions.just_pressed(Action::Interact) {
         close_dial_ui(&mut commands, &ui_q);
         return;
     }
 
-    if input.just_pressed(bindings.move_up) {
+    if actions.just_pressed(Action::MoveUp) {
         state.current = (state.current + 1) % (dial_max + 1);
     }
-    if input.just_pressed(bindings.move_down) {
+    if actions.just_pressed(Action::MoveDown) {
         state.current = (state.current + dial_max) % (dial_max + 1);
     }
 
-    if let Ok(mut txt) = current_text_q.single_mut() {
-        *txt = Text::new(format!("▲  {}  ▼", state.current));
+    if let Ok((mut txt, mut col)) = current_text_q.single_mut() {
+        let (label, color) = dial_value(state.dial_type, state.current, &accessibility);
+        *txt = Text::new(format!("▲  {}  ▼", label));
+        *col = TextColor(color);
     }
 
-    if input.just_pressed(KeyCode::Enter) {
+    if actions.just_pressed(Action::Submit) {
         let confirmed_val = state.current;
         let target = dial_targets.targets[state.dial_idx];
         if let Ok((mut dial, mut sprite)) = dial_q.get_mut(state.dial_entity) {
@@ -271,8 +294,7 @@ pub(super) fn boss_door_proximity(
     session: Option<Res<TerminalSession>>,
     code_session: Option<Res<CodeEntryState>>,
     dial_state: Option<Res<DialInteractState>>,
-    input: Res<ButtonInput<KeyCode>>,
-    bindings: Res<KeyBindings>,
+    actions: Res<PlayerActions>,
     asset_server: Res<AssetServer>,
 ) {
     if session.is_some() || code_session.is_some() || dial_state.is_some() { return; }
@@ -297,9 +319,9 @@ pub(super) fn boss_door_proximity(
     let Some(door_pos) = near_pos else { return };
 
     let (text, color) = if is_ready {
-        ("[E] Open".to_string(), Color::srgb(0.2, 1.0, 0.4))
+        (tr("planet.prompt.open"), Color::srgb(0.2, 1.0, 0.4))
     } else {
-        ("Calibration incomplete".to_string(), Color::srgb(0.8, 0.2, 0.2))
+        (tr("planet.prompt.calibration"), Color::srgb(0.8, 0.2, 0.2))
     };
 
     let font: Handle<Font> = asset_server.load(FONT_PATH);
@@ -312,7 +334,7 @@ pub(super) fn boss_door_proximity(
         GameEntity,
     ));
 
-    if is_ready && input.just_pressed(bindings.interact) {
+    if is_ready && actions.just_pressed(Action::Interact) {
         let popup_pos = pp + Vec3::new(0.0, TILE_SIZE * 2.0, 100.0);
         for (entity, _, mut sprite, _) in door_q.iter_mut() {
             sprite.color = Color::srgb(0.3, 0.3, 0.3);
@@ -321,7 +343,7 @@ pub(super) fn boss_door_proximity(
         }
         let font: Handle<Font> = asset_server.load(FONT_PATH);
         commands.spawn((
-            Text2d::new("Boss Arena Unlocked!"),
+            Text2d::new(tr("planet.boss_unlocked")),
             TextFont { font, font_size: 24.0, ..default() },
             TextColor(Color::srgb(0.2, 1.0, 0.4)),
             Transform::from_translation(popup_pos),
@@ -18,6 +18,7 @@ use crate::enemies::{
     ActiveEnemy, AnimationTimer, Enemy, EnemyFrames, EnemyMoveSpeed, EnemyRes,
     HitAnimation, MeleeEnemy, Velocity, ENEMY_SPEED,
 };
+use crate::locale::tr_args;
 use crate::map::{Door, TileRes};
 use crate::player::Player;
 use crate::room::{Room, RoomVec};
@@ -147,7 +148,7 @@ pub(super) fn watch_mini_boss_death(
 
     let font: Handle<Font> = asset_server.load(FONT_PATH);
     commands.spawn((
-        Text2d::new(format!("Signals acquired!  {}  {}  {}", a, b, c)),
+        Text2d::new(tr_args("planet.signals_acquired", &[("a", &a), ("b", &b), ("c", &c)])),
         TextFont { font, font_size: 22.0, ..default() },
         TextColor(Color::srgb(0.3, 0.8, 1.0)),
         Transform::from_translation(popup_pos),
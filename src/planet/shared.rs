@@ -11,9 +11,10 @@ use crate::{
     PlanetLevelMarker, StationLevel, TestPlanetMode,
     FONT_PATH, WIN_H, WIN_W, Z_FLOOR,
 };
+use crate::locale::{tr, tr_args};
 use crate::player::{Player, aabb_overlap};
 use crate::rewards::RewardRes;
-use crate::settings::KeyBindings;
+use crate::replay::{Action, PlayerActions};
 
 // ── Background tints & images ─────────────────────────────────────────────────
 
@@ -124,7 +125,7 @@ pub(super) fn do_spawn_boss_health_bar(commands: &mut Commands, asset_server: &A
         ))
         .with_children(|root| {
             root.spawn((
-                Text::new("BOSS"),
+                Text::new(tr("planet.boss")),
                 TextFont { font, font_size: 18.0, ..default() },
                 TextColor(Color::srgb(1.0, 0.3, 0.3)),
                 Node { width: Val::Px(52.0), ..default() },
@@ -197,7 +198,7 @@ pub(super) fn spawn_boss_chest(
 
     let font: Handle<Font> = asset_server.load(FONT_PATH);
     commands.spawn((
-        Text2d::new("[ E ]  Leave Planet"),
+        Text2d::new(tr("planet.prompt.leave")),
         TextFont { font, font_size: 24.0, ..default() },
         TextColor(Color::srgb(0.3, 1.0, 0.4)),
         Transform::from_translation(super::planet1::PLANET_EXIT_BEACON_POS),
@@ -211,7 +212,7 @@ pub(super) fn spawn_boss_chest(
 // ── Planet exit — player presses E near the beacon ───────────────────────────
 
 pub(super) fn interact_with_exit_beacon(
-    input: Res<ButtonInput<KeyCode>>,
+    actions: Res<PlayerActions>,
     player_q: Query<&Transform, With<Player>>,
     beacon_q: Query<&Transform, With<PlanetExitBeacon>>,
     boss_arena_state: Res<BossArenaState>,
@@ -219,11 +220,12 @@ pub(super) fn interact_with_exit_beacon(
     code_session: Option<Res<CodeEntryState>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut planet_count: ResMut<PlanetCount>,
-    bindings: Res<KeyBindings>,
+    test_mode: Option<Res<TestPlanetMode>>,
+    mut run_events: EventWriter<crate::profile::RunEvent>,
 ) {
     if *boss_arena_state != BossArenaState::Active { return; }
     if session.is_some() || code_session.is_some() { return; }
-    if !input.just_pressed(bindings.interact) { return; }
+    if !actions.just_pressed(Action::Interact) { return; }
 
     let Ok(player_tf) = player_q.single() else { return };
     let Ok(beacon_tf) = beacon_q.single() else { return };
@@ -232,6 +234,9 @@ pub(super) fn interact_with_exit_beacon(
 
     if aabb_overlap(pp.x, pp.y, Vec2::splat(crate::TILE_SIZE * 2.0), bp.x, bp.y, Vec2::splat(crate::TILE_SIZE * 1.5)) {
         planet_count.0 += 1;
+        if test_mode.is_none() {
+            run_events.write(crate::profile::RunEvent::PlanetCleared);
+        }
         next_state.set(GameState::PlanetWin);
     }
 }
@@ -241,10 +246,13 @@ pub(super) fn interact_with_exit_beacon(
 pub(super) fn spawn_vault_rewards(
     mut commands: Commands,
     reward_res: Res<RewardRes>,
+    reward_font: Res<crate::rewards::RewardFont>,
+    reward_registry: Res<crate::rewards::RewardRegistry>,
     planet_count: Res<PlanetCount>,
+    mut rng: ResMut<crate::RunRng>,
 ) {
     for &pos in super::planet_vault_rewards(planet_count.0 as usize) {
-        crate::rewards::spawn_reward(&mut commands, pos, &reward_res);
+        crate::rewards::spawn_reward(&mut commands, pos, &reward_res, &reward_registry, &reward_font, &mut rng);
     }
 }
 
@@ -275,17 +283,16 @@ pub(super) fn setup_planet_win_screen(
     ))
     .with_children(|root| {
         root.spawn((
-            Text::new("Planet Cleared!"),
+            Text::new(tr("planet.cleared")),
             TextFont { font: font.clone(), font_size: 48.0, ..default() },
             TextColor(Color::srgb(0.3, 1.0, 0.4)),
         ));
 
         root.spawn((
-            Text::new(format!(
-                "Planets cleared this run: {}   |   Station {}",
-                planet_count.0,
-                station_level.0 + 1,
-            )),
+            Text::new(tr_args("planet.cleared_summary", &[
+                ("planets", &planet_count.0),
+                ("station", &(station_level.0 + 1)),
+            ])),
             TextFont { font: font.clone(), font_size: 22.0, ..default() },
             TextColor(Color::srgb(0.7, 0.9, 0.7)),
         ));
@@ -306,7 +313,7 @@ pub(super) fn setup_planet_win_screen(
         ))
         .with_children(|b| {
             b.spawn((
-                Text::new(format!("Continue to Station {}", station_level.0 + 2)),
+                Text::new(tr_args("end.continue_station", &[("station", &(station_level.0 + 2))])),
                 TextFont { font: font.clone(), font_size: 28.0, ..default() },
                 TextColor(Color::WHITE),
             ));
@@ -328,7 +335,7 @@ pub(super) fn setup_planet_win_screen(
         ))
         .with_children(|b| {
             b.spawn((
-                Text::new("Main Menu"),
+                Text::new(tr("pause.main_menu")),
                 TextFont { font: font.clone(), font_size: 28.0, ..default() },
                 TextColor(Color::WHITE),
             ));
@@ -1,4 +1,4 @@
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::prelude::*;
 use rand::random_range;
 
 use crate::collidable::{Collidable, Collider};
@@ -10,7 +10,8 @@ use crate::enemies::HitAnimation;
 use crate::map::{LevelRes, MapGridMeta};
 use crate::fluiddynamics::PulledByFluid;
 use crate::bullet::{Bullet, Velocity};
-use crate::weapons::{Weapon, WeaponType, WeaponInventory};
+use crate::weapons::{Weapon, WeaponInventory, WeaponRegistry};
+use crate::replay::{Action, PlayerActions};
 const WALL_SLIDE_FRICTION_MULTIPLIER: f32 = 0.92; // lower is more friction
 
 #[derive(Component)]
@@ -209,6 +210,10 @@ fn spawn_player(
     grid: Res<MapGridMeta>,
     saved_buffs: Option<Res<crate::SavedPlayerBuffs>>,
     selected_weapon: Option<Res<crate::SelectedWeapon>>,
+    weapon_registry: Res<WeaponRegistry>,
+    profile: Res<crate::profile::Profile>,
+    unlocks: Res<crate::profile::UnlockRegistry>,
+    replaying: Option<Res<crate::replay::ReplayPlayback>>,
 ) {
     let (image, layout) = &player_sheet.down;
 
@@ -267,32 +272,61 @@ fn spawn_player(
         WeaponBuffStacks::default()
     };
 
+    let broom_stats = saved_buffs.as_ref().map_or_else(crate::broom::BroomStats::default, |b| b.broom);
+    let reward_stacks = saved_buffs.as_ref().map(|b| b.reward_stacks.clone()).unwrap_or_default();
+    let item_slots = saved_buffs.as_ref().map_or_else(|| {
+        // Fresh runs start with every unlocked starting item; a replay brings
+        // the ones the recorded profile had.
+        let starting: Vec<_> = match &replaying {
+            Some(replay) => replay.items.clone(),
+            None => unlocks.starting_items(&profile).collect(),
+        };
+        let mut slots = crate::items::ItemSlots::default();
+        for kind in starting {
+            if kind.is_active() {
+                slots.active.get_or_insert(kind);
+            } else {
+                slots.add_consumable(kind);
+            }
+        }
+        slots
+    }, |b| b.items.clone());
+
     let inventory = if let Some(buffs) = &saved_buffs {
         if buffs.weapons.is_empty() {
-            WeaponInventory::new(Weapon::new(WeaponType::Zapper))
+            WeaponInventory::new(weapon_registry.weapon(crate::weapons::DEFAULT_WEAPON))
         } else {
             let weapons_vec: Vec<Weapon> = buffs.weapons.iter().map(|sw| {
-                let mut w = Weapon::new(sw.weapon_type);
+                let mut w = weapon_registry.weapon(&sw.id);
                 w.fire_rate = sw.fire_rate;
                 w.shoot_timer = Timer::from_seconds(sw.fire_rate, TimerMode::Once);
                 w.damage = sw.damage;
                 w.piercing_pickups = sw.piercing_pickups;
+                w.heat = sw.heat;
+                w.overheated = sw.overheated;
+                w.ammo = sw.ammo;
+                w.reload_left = sw.reload_left;
+                w.cooling = sw.cooling;
+                w.mods = sw.mods.clone();
                 w
             }).collect();
-            WeaponInventory { weapons: weapons_vec, equipped: 0 }
+            WeaponInventory { weapons: weapons_vec, equipped: 0, stash: buffs.mod_stash.clone() }
         }
     } else {
-        let weapon_type = selected_weapon
-            .map(|r| r.0)
-            .unwrap_or(WeaponType::Zapper);
-        WeaponInventory::new(Weapon::new(weapon_type))
+        let id = selected_weapon
+            .as_ref()
+            .map_or(crate::weapons::DEFAULT_WEAPON, |r| r.0.as_str());
+        WeaponInventory::new(weapon_registry.weapon(id))
     };
 
     commands.spawn((
-        Sprite::from_atlas_image(
-            image.clone(),
-            TextureAtlas { layout: layout.clone(), index: 0 },
-        ),
+        Sprite {
+            color: unlocks.crew_tint(&profile),
+            ..Sprite::from_atlas_image(
+                image.clone(),
+                TextureAtlas { layout: layout.clone(), index: 0 },
+            )
+        },
         Transform {
             translation: Vec3::new(world_x, world_y, 0.0),
             scale: Vec3::new(0.04, 0.04, 0.04),
@@ -304,11 +338,11 @@ fn spawn_player(
         MaxHealth(max_hp),
         DamageTimer::new(1.0),
         // grouped into nested tuples to stay within Bevy's 15-element Bundle limit
-        (MoveSpeed(move_speed), Armor(armor), Collidable, Regen(regen_rate), Shield::new(shield_max)),
+        (MoveSpeed(move_speed), Armor(armor), Collidable, Regen(regen_rate), Shield::new(shield_max), item_slots),
         Collider { half_extents: Vec2::new(TILE_SIZE * 0.5, TILE_SIZE * 1.0) },
         Facing(FacingDirection::Down),
         NumOfCleared(num_cleared),
-        (PulledByFluid{mass: vacuum_mass}, AirTank::new(tank_max, tank_drain), ThrusterFuel { current: 3.0, max: 3.0 }, buff_stacks),
+        (PulledByFluid{mass: vacuum_mass}, AirTank::new(tank_max, tank_drain), ThrusterFuel { current: 3.0, max: 3.0 }, buff_stacks, broom_stats, reward_stacks),
         inventory,
         GameEntity,
     ));
@@ -330,13 +364,12 @@ fn debug_end_credits(
 
 fn move_player(
     time: Res<Time>,
-    input: Res<ButtonInput<KeyCode>>,
-    mut player: Query<(&mut Transform, &mut Velocity, &mut Facing, &MoveSpeed), With<Player>>,
+    actions: Res<PlayerActions>,
+    mut player: Query<(&mut Transform, &mut Velocity, &mut Facing, &MoveSpeed, Option<&crate::status::StatusEffects>), With<Player>>,
     // Excludes permanent wall tiles and tables — tables are handled by player_deflects_tables.
     colliders: Query<(&Transform, &Collider), (With<Collidable>, Without<Player>, Without<Bullet>, Without<Broom>, Without<crate::map::WallTile>, Without<table::Table>)>,
     wall_grid: Res<crate::map::WallGrid>,
     grid_query: Query<&crate::fluiddynamics::FluidGrid>,
-    bindings: Res<crate::settings::KeyBindings>,
     code_session: Option<Res<crate::planet::CodeEntryState>>,
     term_session: Option<Res<crate::planet::TerminalSession>>,
     dial_session: Option<Res<crate::planet::DialInteractState>>,
@@ -344,7 +377,7 @@ fn move_player(
     let Ok(grid) = grid_query.single() else {
         return;
     };
-    let Ok((mut transform, mut velocity, mut facing, spd)) = player.single_mut() else {
+    let Ok((mut transform, mut velocity, mut facing, spd, status)) = player.single_mut() else {
         return;
     };
 
@@ -353,19 +386,19 @@ fn move_player(
 
     let mut dir: Vec2 = Vec2::ZERO;
 
-    if input.pressed(bindings.move_left) {
+    if actions.pressed(Action::MoveLeft) {
         dir.x -= 1.;
         facing.0 = FacingDirection::Left;
     }
-    if input.pressed(bindings.move_right) {
+    if actions.pressed(Action::MoveRight) {
         dir.x += 1.;
         facing.0 = FacingDirection::Right;
     }
-    if input.pressed(bindings.move_up) {
+    if actions.pressed(Action::MoveUp) {
         dir.y += 1.;
         facing.0 = FacingDirection::Up;
     }
-    if input.pressed(bindings.move_down) {
+    if actions.pressed(Action::MoveDown) {
         dir.y -= 1.;
         facing.0 = FacingDirection::Down;
     }
@@ -389,8 +422,10 @@ fn move_player(
     let deltat = time.delta_secs();
     let accel = ACCEL_RATE * deltat;
 
+    let max_speed = (PLAYER_SPEED + spd.0) * crate::status::speed_multiplier(status);
+
     **velocity = if dir.length() > 0. {
-        (**velocity + (dir.normalize_or_zero() * accel)).clamp_length_max(PLAYER_SPEED + spd.0)
+        (**velocity + (dir.normalize_or_zero() * accel)).clamp_length_max(max_speed)
     // allows the player to be moved if the breaches are open
     // the drag helps stop the player so it doesn't feel like they are on ice
     } else if !grid.breaches.is_empty() {
@@ -533,13 +568,15 @@ fn tick_dash_invincibility(
 
 fn enemy_hits_player(
     time: Res<Time>,
-    mut player_query: Query<(&Transform, &mut crate::player::Health, &mut DamageTimer, &Armor, &mut Shield, Option<&DashInvincibility>), With<crate::player::Player>>,
-    enemy_query: Query<(Entity, &Transform, &crate::enemies::Health), With<Enemy>>,
+    mut player_query: Query<(&Transform, &mut crate::player::Health, &mut DamageTimer, &Armor, &mut Shield, Option<&DashInvincibility>, Option<&crate::status::StatusEffects>), With<crate::player::Player>>,
+    enemy_query: Query<(Entity, &Transform, &crate::enemies::Health), (With<Enemy>, Without<crate::enemies::Nest>)>,
     mut commands: Commands,
+    mut enemy_hits: EventWriter<crate::enemies::EnemyHitPlayerEvent>,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
 ) {
     let player_half = Vec2::splat(32.0);
     let enemy_half = Vec2::splat(ENEMY_SIZE * 0.5);
-    for (player_tf, mut health, mut damage_timer, armor, mut shield, dash_inv) in &mut player_query {
+    for (player_tf, mut health, mut damage_timer, armor, mut shield, dash_inv, status) in &mut player_query {
         if dash_inv.is_some() { continue; }
 
         damage_timer.0.tick(time.delta());
@@ -564,7 +601,10 @@ fn enemy_hits_player(
                     if shield.current >= 1.0 {
                         shield.current -= 1.0;
                     } else {
-                        health.0 -= 15.0 * armor_factor(armor.0);
+                        let dealt = 15.0 * armor_factor(armor.0) * crate::status::damage_taken_multiplier(status);
+                        health.0 -= dealt;
+                        enemy_hits.write(crate::enemies::EnemyHitPlayerEvent { enemy: enemy_entity, damage: dealt });
+                        stat_events.write(crate::run_history::StatEvent::DamageTaken(crate::run_history::DamageSource::Contact, dealt));
                     }
                     damage_timer.0.reset();
                     
@@ -592,9 +632,8 @@ fn update_player_sprite(
     time: Res<Time>,
     mut query: Query<&mut Sprite, With<Player>>,
     player_res: Res<PlayerRes>,
-    input: Res<ButtonInput<KeyCode>>,
+    actions: Res<PlayerActions>,
     mut frame_timer: Local<f32>,
-    bindings: Res<crate::settings::KeyBindings>,
 ) {
     *frame_timer += time.delta_secs();
 
@@ -603,13 +642,13 @@ fn update_player_sprite(
 
     for mut sprite in &mut query {
         // Select the current sprite sheet based on input
-        let (image, layout_handle) = if input.pressed(bindings.move_up) {
+        let (image, layout_handle) = if actions.pressed(Action::MoveUp) {
             &player_res.up
-        } else if input.pressed(bindings.move_down) {
+        } else if actions.pressed(Action::MoveDown) {
             &player_res.down
-        } else if input.pressed(bindings.move_left) {
+        } else if actions.pressed(Action::MoveLeft) {
             &player_res.left
-        } else if input.pressed(bindings.move_right) {
+        } else if actions.pressed(Action::MoveRight) {
             &player_res.right
         } else {
             continue;
@@ -639,12 +678,13 @@ fn regen_system(
 /// away from the table's center. Direction is always outward from the table, so
 /// many tables surrounding the player cancel each other out rather than stacking.
 fn table_hits_player(
-    mut player_query: Query<(&Transform, &mut Velocity, &mut Health, &mut DamageTimer, &Armor, &mut Shield), With<Player>>,
+    mut player_query: Query<(&Transform, &mut Velocity, &mut Health, &mut DamageTimer, &Armor, &mut Shield, Option<&crate::status::StatusEffects>), With<Player>>,
     table_query: Query<(&Transform, &Collider, Option<&crate::enemies::Velocity>), With<table::Table>>,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
 ) {
     let player_half = Vec2::new(TILE_SIZE * 0.5, TILE_SIZE * 1.0);
 
-    for (player_tf, mut player_vel, mut health, mut dmg_timer, armor, mut shield) in &mut player_query {
+    for (player_tf, mut player_vel, mut health, mut dmg_timer, armor, mut shield, status) in &mut player_query {
         let player_pos = player_tf.translation.truncate();
 
         let mut total_impulse = Vec2::ZERO;
@@ -676,7 +716,9 @@ fn table_hits_player(
                 if shield.current >= 1.0 {
                     shield.current -= 1.0;
                 } else {
-                    health.0 -= fastest_speed * 0.02 * armor_factor(armor.0);
+                    let taken = fastest_speed * 0.02 * armor_factor(armor.0) * crate::status::damage_taken_multiplier(status);
+                    health.0 -= taken;
+                    stat_events.write(crate::run_history::StatEvent::DamageTaken(crate::run_history::DamageSource::Table, taken));
                 }
                 dmg_timer.0.reset();
             }
@@ -821,7 +863,7 @@ fn apply_breach_force_to_player(
     time: Res<Time>,
     rooms: Res<crate::room::RoomVec>,
     grid_query: Query<&crate::fluiddynamics::FluidGrid>,
-    mut player_query: Query<(&Transform, &mut Velocity, &PulledByFluid), With<Player>>,
+    mut player_query: Query<(&Transform, &mut Velocity, &PulledByFluid, &crate::rewards::RewardStacks, Has<DashInvincibility>, Has<crate::items::AirBubble>), With<Player>>,
 ) {
     let Ok(grid) = grid_query.single() else { return; };
     if grid.breaches.is_empty() { return; }
@@ -830,7 +872,9 @@ fn apply_breach_force_to_player(
     let grid_origin_x = -(grid.width as f32 * cell_size) / 2.0;
     let grid_origin_y = -(grid.height as f32 * cell_size) / 2.0;
 
-    let Ok((transform, mut velocity, pulled)) = player_query.single_mut() else { return; };
+    let Ok((transform, mut velocity, pulled, stacks, dashing, bubbled)) = player_query.single_mut() else { return; };
+    if bubbled { return; }
+    if dashing && crate::rewards::Synergy::VacuumDash.is_active(stacks) { return; }
     let world_pos = transform.translation.truncate();
 
     // Only apply suction from breaches in the player's current room.
@@ -920,21 +964,15 @@ fn thruster_regen_system(
 
 fn thruster_dodge_system(
     mut commands: Commands,
-    input: Res<ButtonInput<KeyCode>>,
+    actions: Res<PlayerActions>,
     mut q_player: Query<(Entity, &Transform, &mut Velocity, &mut ThrusterFuel), With<Player>>,
-    q_window: Query<&Window, With<PrimaryWindow>>,
-    q_camera: Query<(&Camera, &GlobalTransform)>,
-    bindings: Res<crate::settings::KeyBindings>,
 ) {
-    if !input.just_pressed(bindings.dash) { return; }
+    if !actions.just_pressed(Action::Dash) { return; }
 
     let Ok((player_entity, player_tf, mut velocity, mut fuel)) = q_player.single_mut() else { return; };
     if fuel.max <= 0.0 || fuel.current < 1.0 { return; }
 
-    let Ok(window) = q_window.single() else { return; };
-    let Some(cursor_pos) = window.cursor_position() else { return; };
-    let Ok((camera, cam_transform)) = q_camera.single() else { return; };
-    let Some(world_pos) = camera.viewport_to_world_2d(cam_transform, cursor_pos).ok() else { return; };
+    let Some(world_pos) = actions.aim else { return; };
 
     let player_pos = player_tf.translation.truncate();
     let dir = (world_pos - player_pos).normalize_or_zero();
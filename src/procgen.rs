@@ -303,6 +303,8 @@ pub fn build_full_level(
     rooms: Res<RoomRes>,
     mut room_vec: ResMut<RoomVec>,
     window_cfg: Res<WindowConfig>,
+    run_seed: Res<crate::RunSeed>,
+    station_level: Res<crate::StationLevel>,
 ) {
     // +40 and +20 are edge padding kept clear for wall generation.
     // BSP area is MAP_W-40 × MAP_H-20.  MIN_LEAF_SIZE scaled proportionally
@@ -311,7 +313,7 @@ pub fn build_full_level(
     const MAP_H: usize = 250 + 20;   // was 200+20
     const MIN_LEAF_SIZE: usize = 44;  // was 35  (35 * 250/200 ≈ 44)
     const MIN_ROOM_SIZE: usize = 30;  // was 24  (slightly larger rooms)
-    let seed: u64 = random_range(0..u64::MAX);
+    let seed: u64 = run_seed.station_seed(station_level.0);
 
     // full map of '.'
     let mut map: Vec<Vec<char>> = vec![vec!['.'; MAP_W]; MAP_H];
@@ -0,0 +1,290 @@
+pub mod unlocks;
+
+use bevy::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use crate::{GameEntity, GameState, TILE_SIZE};
+use crate::locale::{tr, tr_args};
+use crate::player::Player;
+use crate::rewards::{RewardFont, RewardPopup, RewardRegistry};
+
+pub use unlocks::{UnlockCondition, UnlockDef, UnlockRegistry, Unlockable};
+
+// ── Profile ──────────────────────────────────────────────────────────────────
+//
+// Meta-progression that outlives any single run: lifetime stats and the set
+// of unlocks earned from them. Stored in profile.ron next to config.ron.
+
+/// Bump when the profile layout changes in a way `#[serde(default)]` can't absorb.
+pub const PROFILE_VERSION: u32 = 1;
+
+/// Unlock id the old `beam_rifle_unlocked` config flag maps to.
+const LEGACY_BEAM_RIFLE: &str = "beam_rifle";
+
+/// Enemy types tracked in lifetime kill counts.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, serde::Serialize, serde::Deserialize)]
+pub enum EnemyKind {
+    Chaser,
+    Ranger,
+    Turret,
+    Saboteur,
+    Nest,
+    Reaper,
+}
+
+impl EnemyKind {
+    pub fn name(self) -> String {
+        tr(match self {
+            EnemyKind::Chaser   => "enemy.chaser",
+            EnemyKind::Ranger   => "enemy.ranger",
+            EnemyKind::Turret   => "enemy.turret",
+            EnemyKind::Saboteur => "enemy.saboteur",
+            EnemyKind::Nest     => "enemy.nest",
+            EnemyKind::Reaper   => "enemy.reaper",
+        })
+    }
+}
+
+/// Best guess at what finished the player off, judged at the moment of death.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, serde::Serialize, serde::Deserialize)]
+pub enum DeathCause {
+    Suffocation,
+    Burn,
+    Reaper,
+    Enemies,
+}
+
+/// How close the Reaper has to be to get the blame for a death.
+const REAPER_BLAME_RADIUS: f32 = TILE_SIZE * 4.0;
+
+impl DeathCause {
+    /// Damage isn't tagged with a source, so this reads the scene at the
+    /// moment of death: lingering damage-over-time first, then a nearby Reaper.
+    pub fn infer(status: Option<&crate::status::StatusEffects>, player_pos: Vec2, reaper_pos: Option<Vec2>) -> Self {
+        use crate::status::StatusKind;
+        if status.is_some_and(|s| s.has(StatusKind::Suffocation)) {
+            DeathCause::Suffocation
+        } else if status.is_some_and(|s| s.has(StatusKind::Burn)) {
+            DeathCause::Burn
+        } else if reaper_pos.is_some_and(|r| r.distance(player_pos) <= REAPER_BLAME_RADIUS) {
+            DeathCause::Reaper
+        } else {
+            DeathCause::Enemies
+        }
+    }
+
+    pub fn name(self) -> String {
+        tr(match self {
+            DeathCause::Suffocation => "death.suffocation",
+            DeathCause::Burn        => "death.burn",
+            DeathCause::Reaper      => "death.reaper",
+            DeathCause::Enemies     => "death.enemies",
+        })
+    }
+}
+
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct LifetimeStats {
+    pub runs: u32,
+    pub kills: HashMap<EnemyKind, u32>,
+    pub stations_cleared: u32,
+    pub planets_cleared: u32,
+    pub chests_opened: u32,
+    pub deaths: HashMap<DeathCause, u32>,
+}
+
+impl LifetimeStats {
+    pub fn total_kills(&self) -> u32 {
+        self.kills.values().sum()
+    }
+
+    pub fn total_deaths(&self) -> u32 {
+        self.deaths.values().sum()
+    }
+}
+
+#[derive(Resource, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub version: u32,
+    /// Ids from `assets/profile/unlocks.ron`.
+    pub unlocked: BTreeSet<String>,
+    pub stats: LifetimeStats,
+    /// Selected crew unlock id; `None` is the default crew.
+    pub crew: Option<String>,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self { version: PROFILE_VERSION, unlocked: BTreeSet::new(), stats: LifetimeStats::default(), crew: None }
+    }
+}
+
+impl Profile {
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.contains(id)
+    }
+}
+
+/// Things that happen during a run that feed lifetime stats.
+#[derive(Event, Clone, Copy)]
+pub enum RunEvent {
+    RunStarted,
+    EnemyKilled(EnemyKind),
+    StationCleared,
+    PlanetCleared,
+    ChestOpened,
+    Died(DeathCause),
+}
+
+// ── Persistence ──────────────────────────────────────────────────────────────
+
+fn profile_path() -> PathBuf {
+    crate::settings::config_dir().join("profile.ron")
+}
+
+/// Reads profile.ron. A profile from another version is moved aside to
+/// profile.ron.bak rather than overwritten, and a fresh one is started.
+pub fn load_profile() -> Profile {
+    let path = profile_path();
+    let mut profile = match std::fs::read_to_string(&path) {
+        Err(_) => Profile::default(),
+        Ok(text) => match ron::from_str::<Profile>(&text) {
+            Ok(p) if p.version == PROFILE_VERSION => p,
+            Ok(p) => {
+                warn!("Profile is version {} (expected {}); starting a new one", p.version, PROFILE_VERSION);
+                let _ = std::fs::rename(&path, path.with_extension("ron.bak"));
+                Profile::default()
+            }
+            Err(e) => {
+                warn!("Could not read profile '{}': {e}; starting a new one", path.display());
+                let _ = std::fs::rename(&path, path.with_extension("ron.bak"));
+                Profile::default()
+            }
+        },
+    };
+
+    // Carry over the old config.ron flag; config.ron no longer writes it.
+    if crate::settings::load_legacy_beam_rifle_unlock()
+        && profile.unlocked.insert(LEGACY_BEAM_RIFLE.to_string())
+    {
+        save_profile(&profile);
+    }
+    profile
+}
+
+pub fn save_profile(profile: &Profile) {
+    if let Ok(s) = ron::to_string(profile) {
+        let path = profile_path();
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let _ = std::fs::write(path, s);
+    }
+}
+
+// ── Plugin ───────────────────────────────────────────────────────────────────
+
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RunEvent>()
+            .add_systems(Startup, (unlocks::load_unlock_registry, check_unlocks).chain())
+            // A replay re-runs someone's old inputs; it shouldn't count towards the profile.
+            .add_systems(Update, (
+                record_run_events.run_if(not(resource_exists::<crate::replay::ReplayPlayback>)),
+                // A replay brings the recorded locks instead.
+                sync_reward_locks.run_if(not(resource_exists::<crate::replay::ReplayPlayback>)),
+            ).chain())
+            .add_systems(OnExit(GameState::Playing), save_profile_on_exit);
+    }
+}
+
+/// Kills don't save on their own, so write them out when a level ends.
+fn save_profile_on_exit(profile: Res<Profile>) {
+    save_profile(&profile);
+}
+
+/// Grants every unlock whose condition is now met. Returns the new ones.
+fn evaluate_unlocks(profile: &mut Profile, registry: &UnlockRegistry) -> Vec<String> {
+    let mut earned = Vec::new();
+    for def in registry.iter() {
+        if !profile.is_unlocked(&def.id) && def.condition.is_met(&profile.stats) {
+            profile.unlocked.insert(def.id.clone());
+            earned.push(def.name());
+        }
+    }
+    earned
+}
+
+/// Catches unlocks added to the file since the profile was last saved.
+fn check_unlocks(mut profile: ResMut<Profile>, registry: Res<UnlockRegistry>) {
+    if !evaluate_unlocks(&mut profile, &registry).is_empty() {
+        save_profile(&profile);
+    }
+}
+
+fn record_run_events(
+    mut commands: Commands,
+    mut events: EventReader<RunEvent>,
+    mut profile: ResMut<Profile>,
+    registry: Res<UnlockRegistry>,
+    player_q: Query<&Transform, With<Player>>,
+    font: Option<Res<RewardFont>>,
+) {
+    if events.is_empty() { return; }
+
+    let mut save = false;
+    for ev in events.read() {
+        let stats = &mut profile.stats;
+        match *ev {
+            RunEvent::RunStarted => stats.runs += 1,
+            RunEvent::EnemyKilled(kind) => {
+                *stats.kills.entry(kind).or_default() += 1;
+                continue;
+            }
+            RunEvent::StationCleared => stats.stations_cleared += 1,
+            RunEvent::PlanetCleared => stats.planets_cleared += 1,
+            RunEvent::ChestOpened => stats.chests_opened += 1,
+            RunEvent::Died(cause) => *stats.deaths.entry(cause).or_default() += 1,
+        }
+        save = true;
+    }
+
+    let earned = evaluate_unlocks(&mut profile, &registry);
+    if save || !earned.is_empty() {
+        save_profile(&profile);
+    }
+
+    for (i, name) in earned.iter().enumerate() {
+        info!("Unlocked {name}");
+        if let (Ok(tf), Some(font)) = (player_q.single(), font.as_ref()) {
+            let at = tf.translation;
+            commands.spawn((
+                Text2d::new(tr_args("unlock.popup", &[("name", name)])),
+                TextFont { font: font.0.clone(), font_size: 20.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.85, 0.3)),
+                Transform::from_translation(Vec3::new(at.x, at.y + TILE_SIZE * (2.0 + i as f32), 10.0)),
+                RewardPopup { timer: Timer::from_seconds(3.0, TimerMode::Once) },
+                GameEntity,
+            ));
+        }
+    }
+}
+
+/// Keeps rewards with an unearned unlock out of crates. The locks are fixed
+/// when a run starts, so a reward unlocked mid-run shows up from the next one
+/// and a replay can restore them from its header.
+fn sync_reward_locks(
+    profile: Res<Profile>,
+    unlocks: Res<UnlockRegistry>,
+    rewards: Option<ResMut<RewardRegistry>>,
+    mut events: EventReader<RunEvent>,
+) {
+    let Some(mut rewards) = rewards else { return };
+    let run_started = events.read().any(|ev| matches!(ev, RunEvent::RunStarted));
+    if !run_started && !rewards.is_added() { return; }
+    rewards.set_locked(unlocks.locked_rewards(&profile).collect());
+}
@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+use bevy::asset::io::file::FileAssetReader;
+use serde::Deserialize;
+use crate::items::ItemKind;
+use crate::locale::{tr, tr_args};
+use crate::rewards::RewardKind;
+use super::{DeathCause, EnemyKind, LifetimeStats, Profile};
+
+/// File (relative to the asset root) listing every unlock and its condition.
+const UNLOCK_FILE: &str = "profile/unlocks.ron";
+
+// ── Definition file ──────────────────────────────────────────────────────────
+
+/// What an unlock adds to future runs.
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub enum Unlockable {
+    /// A weapon id from `assets/weapons` that has `requires_unlock` set.
+    Weapon(String),
+    /// An item every fresh run starts with.
+    StartingItem(ItemKind),
+    /// A reward that only appears in crates once unlocked.
+    Reward(RewardKind),
+    /// A crew colour for the player sprite, picked on the setup screen.
+    Crew((f32, f32, f32)),
+}
+
+/// Checked against lifetime stats after every run event.
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum UnlockCondition {
+    RunsStarted(u32),
+    /// Kills of any enemy type.
+    Kills(u32),
+    KillsOf(EnemyKind, u32),
+    StationsCleared(u32),
+    PlanetsCleared(u32),
+    ChestsOpened(u32),
+    Deaths(u32),
+    DeathsBy(DeathCause, u32),
+}
+
+impl UnlockCondition {
+    /// Current progress and the target.
+    pub fn progress(self, stats: &LifetimeStats) -> (u32, u32) {
+        match self {
+            UnlockCondition::RunsStarted(n)     => (stats.runs, n),
+            UnlockCondition::Kills(n)           => (stats.total_kills(), n),
+            UnlockCondition::KillsOf(kind, n)   => (stats.kills.get(&kind).copied().unwrap_or(0), n),
+            UnlockCondition::StationsCleared(n) => (stats.stations_cleared, n),
+            UnlockCondition::PlanetsCleared(n)  => (stats.planets_cleared, n),
+            UnlockCondition::ChestsOpened(n)    => (stats.chests_opened, n),
+            UnlockCondition::Deaths(n)          => (stats.total_deaths(), n),
+            UnlockCondition::DeathsBy(cause, n) => (stats.deaths.get(&cause).copied().unwrap_or(0), n),
+        }
+    }
+
+    pub fn is_met(self, stats: &LifetimeStats) -> bool {
+        let (have, need) = self.progress(stats);
+        have >= need
+    }
+
+    /// Player-facing description for the setup screen.
+    pub fn describe(self) -> String {
+        match self {
+            UnlockCondition::RunsStarted(n)     => tr_args("unlock.runs_started", &[("n", &n)]),
+            UnlockCondition::Kills(n)           => tr_args("unlock.kills", &[("n", &n)]),
+            UnlockCondition::KillsOf(kind, 1)   => tr_args("unlock.kills_of_one", &[("enemy", &kind.name())]),
+            UnlockCondition::KillsOf(kind, n)   => tr_args("unlock.kills_of", &[("n", &n), ("enemy", &kind.name())]),
+            UnlockCondition::StationsCleared(n) => tr_args("unlock.stations_cleared", &[("n", &n)]),
+            UnlockCondition::PlanetsCleared(1)  => tr("unlock.planets_cleared_one"),
+            UnlockCondition::PlanetsCleared(n)  => tr_args("unlock.planets_cleared", &[("n", &n)]),
+            UnlockCondition::ChestsOpened(1)    => tr("unlock.chests_opened_one"),
+            UnlockCondition::ChestsOpened(n)    => tr_args("unlock.chests_opened", &[("n", &n)]),
+            UnlockCondition::Deaths(n)          => tr_args("unlock.deaths", &[("n", &n)]),
+            UnlockCondition::DeathsBy(cause, n) => tr_args("unlock.deaths_by", &[("n", &n), ("cause", &cause.name())]),
+        }
+    }
+}
+
+/// One entry in `assets/profile/unlocks.ron`.
+#[derive(Deserialize, Clone)]
+pub struct UnlockDef {
+    /// Stable key stored in profile.ron.
+    pub id: String,
+    /// String-table key for the unlock's title.
+    pub name_key: String,
+    pub grants: Unlockable,
+    pub condition: UnlockCondition,
+}
+
+impl UnlockDef {
+    pub fn name(&self) -> String {
+        tr(&self.name_key)
+    }
+}
+
+#[derive(Deserialize)]
+struct UnlockFile {
+    unlocks: Vec<UnlockDef>,
+}
+
+// ── Registry ────────────────────────────────────────────────────────────────
+
+/// Every unlock in file order, read once at startup.
+#[derive(Resource, Default)]
+pub struct UnlockRegistry {
+    defs: Vec<UnlockDef>,
+}
+
+impl UnlockRegistry {
+    pub fn iter(&self) -> impl Iterator<Item = &UnlockDef> {
+        self.defs.iter()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&UnlockDef> {
+        self.defs.iter().find(|d| d.id == id)
+    }
+
+    /// The unlock that grants weapon `id`, if any.
+    pub fn for_weapon(&self, id: &str) -> Option<&UnlockDef> {
+        self.defs.iter().find(|d| matches!(&d.grants, Unlockable::Weapon(w) if w == id))
+    }
+
+    /// Weapons without `requires_unlock` are always available; the rest need
+    /// their unlock. A locked weapon with no unlock entry stays locked.
+    pub fn weapon_available(&self, profile: &Profile, id: &str, requires_unlock: bool) -> bool {
+        !requires_unlock || self.for_weapon(id).is_some_and(|d| profile.is_unlocked(&d.id))
+    }
+
+    /// Rewards with an unlock entry that the profile doesn't have yet.
+    pub fn locked_rewards<'a>(&'a self, profile: &'a Profile) -> impl Iterator<Item = RewardKind> + 'a {
+        self.defs.iter().filter_map(move |d| match d.grants {
+            Unlockable::Reward(kind) if !profile.is_unlocked(&d.id) => Some(kind),
+            _ => None,
+        })
+    }
+
+    /// Items a fresh run starts with.
+    pub fn starting_items<'a>(&'a self, profile: &'a Profile) -> impl Iterator<Item = ItemKind> + 'a {
+        self.defs.iter().filter_map(move |d| match d.grants {
+            Unlockable::StartingItem(kind) if profile.is_unlocked(&d.id) => Some(kind),
+            _ => None,
+        })
+    }
+
+    /// Crew unlocks the profile has earned, in file order.
+    pub fn crews<'a>(&'a self, profile: &'a Profile) -> impl Iterator<Item = &'a UnlockDef> + 'a {
+        self.defs
+            .iter()
+            .filter(move |d| matches!(d.grants, Unlockable::Crew(_)) && profile.is_unlocked(&d.id))
+    }
+
+    /// Sprite tint for the profile's selected crew; white for the default crew.
+    pub fn crew_tint(&self, profile: &Profile) -> Color {
+        profile
+            .crew
+            .as_deref()
+            .filter(|id| profile.is_unlocked(id))
+            .and_then(|id| self.get(id))
+            .and_then(|d| match d.grants {
+                Unlockable::Crew((r, g, b)) => Some(Color::srgb(r, g, b)),
+                _ => None,
+            })
+            .unwrap_or(Color::WHITE)
+    }
+}
+
+fn read_defs() -> Vec<UnlockDef> {
+    let path = FileAssetReader::get_base_path().join("assets").join(UNLOCK_FILE);
+    match std::fs::read_to_string(&path).map_err(|e| e.to_string())
+        .and_then(|s| ron::from_str::<UnlockFile>(&s).map_err(|e| e.to_string()))
+    {
+        Ok(file) => file.unlocks,
+        Err(e) => {
+            warn!("Could not load unlock definitions '{}': {e}", path.display());
+            Vec::new()
+        }
+    }
+}
+
+pub(super) fn load_unlock_registry(mut commands: Commands) {
+    commands.insert_resource(UnlockRegistry { defs: read_defs() });
+}
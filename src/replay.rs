@@ -0,0 +1,530 @@
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::window::PrimaryWindow;
+use std::path::PathBuf;
+use std::time::Duration;
+use crate::{GameState, PlanetCount, PlanetLevelMarker, RunSeed, SavedPlayerBuffs, SelectedWeapon, StationLevel};
+use crate::items::ItemKind;
+use crate::pause::IsPaused;
+use crate::profile::{Profile, RunEvent, UnlockRegistry};
+use crate::rewards::{RewardKind, RewardRegistry};
+use crate::settings::{InputBinding, KeyBindings};
+
+// ── Input actions and replays ────────────────────────────────────────────────
+//
+// Gameplay systems read the player's intent from `PlayerActions` rather than
+// ButtonInput and the cursor. Live play fills it from the keyboard and mouse
+// each frame; a replay fills it from a recording instead.
+//
+// Launch with `--record <file>` to record every run started from the setup
+// screen (each new run overwrites the file), or `--replay <file>` to play one
+// back straight away. Both switch time to a fixed step so every recorded frame
+// advances the game by exactly the same amount. Only frames spent unpaused in
+// GameState::Playing are recorded, mod swaps on the inventory screen included;
+// end-screen choices are made by hand during playback. The header stores the run
+// seed, which lays out each station and reseeds `RunRng` as every level loads,
+// so drops, weapon spread and enemy AI roll the same numbers on playback. It
+// also stores what the profile had unlocked when the run began, the starting
+// items and the rewards still locked out of crates, so playback doesn't depend
+// on the watcher's profile.
+
+/// Frames per second of simulated time while recording or replaying.
+pub const FIXED_STEP_HZ: u32 = 60;
+
+const MAGIC: &[u8; 4] = b"CCRP";
+
+/// Bump when the file layout below changes.
+const REPLAY_VERSION: u16 = 3;
+
+/// Everything the player can do that a replay reproduces.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Action {
+    MoveLeft,
+    MoveRight,
+    MoveUp,
+    MoveDown,
+    Fire,
+    Dash,
+    Broom,
+    Interact,
+    /// Enter, for confirming planet terminals and dials.
+    Submit,
+    SwapWeapon,
+    ActiveItem,
+    Hotbar1,
+    Hotbar2,
+    Hotbar3,
+    /// Opens and closes the inventory, which gates the hotbar.
+    Inventory,
+    /// Moves the inventory's mod cursor through the stash.
+    ModCursorUp,
+    ModCursorDown,
+    /// Installs the stash mod under the cursor into weapon slot 1–4.
+    ModSlot1,
+    ModSlot2,
+    ModSlot3,
+    ModSlot4,
+    /// Moves the equipped weapon's last mod back to the stash.
+    UninstallMod,
+}
+
+impl Action {
+    pub const ALL: [Action; 22] = [
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::Fire,
+        Action::Dash,
+        Action::Broom,
+        Action::Interact,
+        Action::Submit,
+        Action::SwapWeapon,
+        Action::ActiveItem,
+        Action::Hotbar1,
+        Action::Hotbar2,
+        Action::Hotbar3,
+        Action::Inventory,
+        Action::ModCursorUp,
+        Action::ModCursorDown,
+        Action::ModSlot1,
+        Action::ModSlot2,
+        Action::ModSlot3,
+        Action::ModSlot4,
+        Action::UninstallMod,
+    ];
+
+    fn bit(self) -> u32 {
+        1 << self as u32
+    }
+
+    fn binding(self, bindings: &KeyBindings) -> InputBinding {
+        match self {
+            Action::MoveLeft   => InputBinding::Key(bindings.move_left),
+            Action::MoveRight  => InputBinding::Key(bindings.move_right),
+            Action::MoveUp     => InputBinding::Key(bindings.move_up),
+            Action::MoveDown   => InputBinding::Key(bindings.move_down),
+            Action::Fire       => bindings.shoot,
+            Action::Dash       => InputBinding::Key(bindings.dash),
+            Action::Broom      => bindings.broom,
+            Action::Interact   => InputBinding::Key(bindings.interact),
+            Action::Submit     => InputBinding::Key(KeyCode::Enter),
+            Action::SwapWeapon => InputBinding::Key(bindings.swap_weapon),
+            Action::ActiveItem => InputBinding::Key(bindings.active_item),
+            Action::Hotbar1    => InputBinding::Key(bindings.hotbar_1),
+            Action::Hotbar2    => InputBinding::Key(bindings.hotbar_2),
+            Action::Hotbar3    => InputBinding::Key(bindings.hotbar_3),
+            Action::Inventory  => InputBinding::Key(bindings.inventory),
+            Action::ModCursorUp   => InputBinding::Key(KeyCode::ArrowUp),
+            Action::ModCursorDown => InputBinding::Key(KeyCode::ArrowDown),
+            Action::ModSlot1      => InputBinding::Key(KeyCode::Digit1),
+            Action::ModSlot2      => InputBinding::Key(KeyCode::Digit2),
+            Action::ModSlot3      => InputBinding::Key(KeyCode::Digit3),
+            Action::ModSlot4      => InputBinding::Key(KeyCode::Digit4),
+            Action::UninstallMod  => InputBinding::Key(KeyCode::KeyU),
+        }
+    }
+}
+
+/// This frame's player input, live or replayed.
+#[derive(Resource, Clone, Copy, Default, PartialEq)]
+pub struct PlayerActions {
+    pressed: u32,
+    just_pressed: u32,
+    /// Cursor position in world space; `None` while it's outside the window.
+    pub aim: Option<Vec2>,
+}
+
+impl PlayerActions {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.pressed & action.bit() != 0
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.just_pressed & action.bit() != 0
+    }
+
+    /// Drops an action for this frame, for rules that override the input.
+    pub fn release(&mut self, action: Action) {
+        self.pressed &= !action.bit();
+        self.just_pressed &= !action.bit();
+    }
+
+    fn set(&mut self, action: Action, pressed: bool, just_pressed: bool) {
+        if pressed {
+            self.pressed |= action.bit();
+        }
+        if just_pressed {
+            self.just_pressed |= action.bit();
+        }
+    }
+}
+
+/// The steps that fill `PlayerActions` each frame, in order.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ActionSet {
+    /// Live input or replay playback.
+    Read,
+    /// Rules that suppress actions, e.g. clicks meant for the open map. Runs
+    /// during playback too, so it must only depend on recorded state.
+    Filter,
+    Record,
+}
+
+// ── Replay file ──────────────────────────────────────────────────────────────
+//
+// Little-endian: "CCRP", version u16, step Hz u16, run seed u64, starting
+// weapon (u16 length + UTF-8), starting items and locked rewards (each a u8
+// count, then one u8 index into `ItemKind::ALL` / `RewardKind::ALL` per entry),
+// frame count u32, then per frame the pressed and just-pressed bit sets (u32
+// each) and the aim point (two f32, NaN for none).
+
+/// A recorded run: how it started and the input for every played frame.
+pub struct Replay {
+    /// `RunSeed` of the recorded run.
+    pub seed: u64,
+    pub weapon: String,
+    /// Unlocked starting items, in the order they fill the item slots.
+    pub items: Vec<ItemKind>,
+    /// Rewards crates couldn't roll yet.
+    pub locked_rewards: Vec<RewardKind>,
+    pub frames: Vec<PlayerActions>,
+}
+
+impl Replay {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(32 + self.frames.len() * 16);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&REPLAY_VERSION.to_le_bytes());
+        out.extend_from_slice(&(FIXED_STEP_HZ as u16).to_le_bytes());
+        out.extend_from_slice(&self.seed.to_le_bytes());
+        out.extend_from_slice(&(self.weapon.len() as u16).to_le_bytes());
+        out.extend_from_slice(self.weapon.as_bytes());
+        write_indices(&mut out, &self.items, &ItemKind::ALL);
+        write_indices(&mut out, &self.locked_rewards, &RewardKind::ALL);
+        out.extend_from_slice(&(self.frames.len() as u32).to_le_bytes());
+        for frame in &self.frames {
+            let aim = frame.aim.unwrap_or(Vec2::NAN);
+            out.extend_from_slice(&frame.pressed.to_le_bytes());
+            out.extend_from_slice(&frame.just_pressed.to_le_bytes());
+            out.extend_from_slice(&aim.x.to_le_bytes());
+            out.extend_from_slice(&aim.y.to_le_bytes());
+        }
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let mut r = Reader(bytes);
+        if r.take(4)? != MAGIC {
+            return Err("not a replay file".to_string());
+        }
+        let version = r.u16()?;
+        if version != REPLAY_VERSION {
+            return Err(format!("replay is version {version} (expected {REPLAY_VERSION})"));
+        }
+        let hz = r.u16()?;
+        if hz as u32 != FIXED_STEP_HZ {
+            return Err(format!("replay was recorded at {hz} Hz (expected {FIXED_STEP_HZ})"));
+        }
+        let seed = r.u64()?;
+        let weapon_len = r.u16()? as usize;
+        let weapon = String::from_utf8(r.take(weapon_len)?.to_vec()).map_err(|e| e.to_string())?;
+        let items = r.indices(&ItemKind::ALL)?;
+        let locked_rewards = r.indices(&RewardKind::ALL)?;
+        let count = r.u32()? as usize;
+        let mut frames = Vec::with_capacity(count.min(bytes.len() / 16));
+        for _ in 0..count {
+            let pressed = r.u32()?;
+            let just_pressed = r.u32()?;
+            let aim = Vec2::new(r.f32()?, r.f32()?);
+            frames.push(PlayerActions { pressed, just_pressed, aim: (!aim.is_nan()).then_some(aim) });
+        }
+        Ok(Self { seed, weapon, items, locked_rewards, frames })
+    }
+}
+
+/// Writes `values` as a u8 count followed by each one's position in `all`.
+fn write_indices<T: PartialEq>(out: &mut Vec<u8>, values: &[T], all: &[T]) {
+    out.push(values.len() as u8);
+    for value in values {
+        out.push(all.iter().position(|v| v == value).expect("value missing from ALL") as u8);
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        if self.0.len() < n {
+            return Err("replay file is truncated".to_string());
+        }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn f32(&mut self) -> Result<f32, String> {
+        Ok(f32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn indices<T: Copy>(&mut self, all: &[T]) -> Result<Vec<T>, String> {
+        let count = self.take(1)?[0] as usize;
+        self.take(count)?
+            .iter()
+            .map(|&i| all.get(i as usize).copied().ok_or_else(|| format!("unknown index {i} in replay header")))
+            .collect()
+    }
+}
+
+// ── Resources ────────────────────────────────────────────────────────────────
+
+/// Set by `--record`: where new runs are recorded to.
+#[derive(Resource)]
+struct RecordTo(PathBuf);
+
+/// Set by `--replay` until the menu comes up and the run is started.
+#[derive(Resource)]
+struct PendingReplay(Replay);
+
+/// Present while a run is being recorded.
+#[derive(Resource)]
+pub struct ReplayRecorder {
+    path: PathBuf,
+    replay: Replay,
+}
+
+/// Present while a replay is feeding `PlayerActions`.
+#[derive(Resource)]
+pub struct ReplayPlayback {
+    /// Starting items from the header, used in place of the profile's.
+    pub items: Vec<ItemKind>,
+    frames: Vec<PlayerActions>,
+    next: usize,
+}
+
+fn fixed_step() -> TimeUpdateStrategy {
+    TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / FIXED_STEP_HZ as f64))
+}
+
+// ── Plugin ───────────────────────────────────────────────────────────────────
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        let args: Vec<String> = std::env::args().collect();
+        let flag = |name: &str| args.iter().position(|a| a == name).and_then(|i| args.get(i + 1));
+        if let Some(path) = flag("--record") {
+            app.insert_resource(RecordTo(PathBuf::from(path)));
+        }
+        if let Some(path) = flag("--replay") {
+            match std::fs::read(path).map_err(|e| e.to_string()).and_then(|bytes| Replay::decode(&bytes)) {
+                Ok(replay) => {
+                    info!("Loaded replay '{path}' ({} frames)", replay.frames.len());
+                    app.insert_resource(PendingReplay(replay));
+                }
+                Err(e) => error!("Could not load replay '{path}': {e}"),
+            }
+        }
+
+        app.init_resource::<PlayerActions>()
+            // After StateTransition, so `in_state` agrees with the Update systems
+            // that consume the actions on the same frame.
+            .configure_sets(
+                RunFixedMainLoop,
+                (ActionSet::Read, ActionSet::Filter, ActionSet::Record)
+                    .chain()
+                    .in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
+            )
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    read_live_actions.run_if(not(resource_exists::<ReplayPlayback>)),
+                    play_back_actions.run_if(resource_exists::<ReplayPlayback>),
+                )
+                    .in_set(ActionSet::Read),
+            )
+            .add_systems(
+                RunFixedMainLoop,
+                record_actions
+                    .run_if(resource_exists::<ReplayRecorder>)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not(resource_exists::<IsPaused>))
+                    .in_set(ActionSet::Record),
+            )
+            .add_systems(PostUpdate, (
+                start_recording.run_if(resource_exists::<RecordTo>),
+                end_playback_on_new_run.run_if(resource_exists::<ReplayPlayback>),
+            ))
+            .add_systems(OnExit(GameState::Playing), flush_recording.run_if(resource_exists::<ReplayRecorder>))
+            .add_systems(OnEnter(GameState::Menu), stop_recording.run_if(resource_exists::<ReplayRecorder>))
+            .add_systems(OnEnter(GameState::Menu), start_pending_replay.run_if(resource_exists::<PendingReplay>));
+    }
+}
+
+fn read_live_actions(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    bindings: Res<KeyBindings>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+    mut actions: ResMut<PlayerActions>,
+) {
+    let mut next = PlayerActions::default();
+    for action in Action::ALL {
+        let binding = action.binding(&bindings);
+        next.set(action, binding.pressed(&keys, &mouse), binding.just_pressed(&keys, &mouse));
+    }
+    next.aim = q_window
+        .single()
+        .ok()
+        .and_then(|w| w.cursor_position())
+        .zip(q_camera.single().ok())
+        .and_then(|(cursor, camera)| crate::bullet::cursor_to_world(cursor, camera));
+    *actions = next;
+}
+
+/// Feeds one recorded frame per unpaused Playing frame. Outside of that the
+/// actions are cleared so nothing acts on a stale press.
+fn play_back_actions(
+    mut commands: Commands,
+    mut playback: ResMut<ReplayPlayback>,
+    mut actions: ResMut<PlayerActions>,
+    state: Res<State<GameState>>,
+    paused: Option<Res<IsPaused>>,
+) {
+    if *state.get() != GameState::Playing || paused.is_some() {
+        *actions = PlayerActions::default();
+        return;
+    }
+    match playback.frames.get(playback.next) {
+        Some(&frame) => {
+            *actions = frame;
+            playback.next += 1;
+        }
+        None => {
+            info!("Replay finished after {} frames", playback.next);
+            *actions = PlayerActions::default();
+            commands.remove_resource::<ReplayPlayback>();
+            commands.insert_resource(TimeUpdateStrategy::Automatic);
+        }
+    }
+}
+
+/// Play Again during a replay starts a real run; leftover frames mustn't drive it.
+fn end_playback_on_new_run(mut commands: Commands, mut events: EventReader<RunEvent>) {
+    if !events.read().any(|ev| matches!(ev, RunEvent::RunStarted)) { return; }
+    commands.remove_resource::<ReplayPlayback>();
+    commands.insert_resource(TimeUpdateStrategy::Automatic);
+}
+
+fn record_actions(actions: Res<PlayerActions>, mut recorder: ResMut<ReplayRecorder>) {
+    recorder.replay.frames.push(*actions);
+}
+
+/// Starts a fresh recording whenever a new run begins.
+fn start_recording(
+    mut commands: Commands,
+    mut events: EventReader<RunEvent>,
+    record_to: Res<RecordTo>,
+    seed: Res<RunSeed>,
+    weapon: Res<SelectedWeapon>,
+    profile: Res<Profile>,
+    unlocks: Res<UnlockRegistry>,
+) {
+    if !events.read().any(|ev| matches!(ev, RunEvent::RunStarted)) { return; }
+    info!("Recording run to '{}'", record_to.0.display());
+    commands.insert_resource(ReplayRecorder {
+        path: record_to.0.clone(),
+        replay: Replay {
+            seed: seed.0,
+            weapon: weapon.0.clone(),
+            items: unlocks.starting_items(&profile).collect(),
+            locked_rewards: unlocks.locked_rewards(&profile).collect(),
+            frames: Vec::new(),
+        },
+    });
+    commands.insert_resource(fixed_step());
+}
+
+/// Rewrites the file at the end of every level so a crash loses at most one.
+fn flush_recording(recorder: Res<ReplayRecorder>) {
+    if let Some(parent) = recorder.path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    match std::fs::write(&recorder.path, recorder.replay.encode()) {
+        Ok(()) => info!("Saved {} replay frames to '{}'", recorder.replay.frames.len(), recorder.path.display()),
+        Err(e) => warn!("Failed to write replay '{}': {e}", recorder.path.display()),
+    }
+}
+
+fn stop_recording(mut commands: Commands) {
+    commands.remove_resource::<ReplayRecorder>();
+    commands.insert_resource(TimeUpdateStrategy::Automatic);
+}
+
+/// Starts the recorded run the same way the setup screen would.
+fn start_pending_replay(
+    mut commands: Commands,
+    mut pending: ResMut<PendingReplay>,
+    mut next_state: ResMut<NextState<GameState>>,
+    rewards: Option<ResMut<RewardRegistry>>,
+) {
+    let replay = std::mem::replace(&mut pending.0, Replay {
+        seed: 0,
+        weapon: String::new(),
+        items: Vec::new(),
+        locked_rewards: Vec::new(),
+        frames: Vec::new(),
+    });
+    commands.remove_resource::<PendingReplay>();
+
+    if let Some(mut rewards) = rewards {
+        rewards.set_locked(replay.locked_rewards.iter().copied().collect());
+    }
+
+    commands.insert_resource(StationLevel(0));
+    commands.insert_resource(PlanetCount(0));
+    commands.insert_resource(RunSeed(replay.seed));
+    commands.insert_resource(SelectedWeapon(replay.weapon));
+    commands.insert_resource(crate::run_history::RunStats::default());
+    commands.remove_resource::<SavedPlayerBuffs>();
+    commands.remove_resource::<PlanetLevelMarker>();
+    commands.insert_resource(ReplayPlayback { items: replay.items, frames: replay.frames, next: 0 });
+    commands.insert_resource(fixed_step());
+    next_state.set(GameState::Loading);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_round_trips_unlocks() {
+        let replay = Replay {
+            seed: 42,
+            weapon: "pistol".to_string(),
+            items: vec![ItemKind::Emp, ItemKind::Stim, ItemKind::Stim],
+            locked_rewards: vec![RewardKind::Shield, RewardKind::BroomStun],
+            frames: vec![PlayerActions { pressed: 0b101, just_pressed: 0b1, aim: None }],
+        };
+        let decoded = Replay::decode(&replay.encode()).unwrap();
+        assert_eq!(decoded.seed, 42);
+        assert_eq!(decoded.weapon, "pistol");
+        assert_eq!(decoded.items, replay.items);
+        assert_eq!(decoded.locked_rewards, replay.locked_rewards);
+        assert!(decoded.frames == replay.frames);
+    }
+}
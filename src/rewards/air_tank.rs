@@ -1,9 +1,17 @@
 use crate::player::AirTank;
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Larger Air Tank";
+pub const NAME: &str = "reward.air_tank.name";
 pub const ASSET: &str = "rewards/LargerTank.png";
 
-pub fn apply(tank: &mut AirTank) {
-    tank.max_capacity += 2.5;
-    tank.current = (tank.current + 2.5).min(tank.max_capacity);
+const CAPACITY_PER_PICKUP: f32 = 2.5;
+
+pub fn apply(tank: &mut AirTank, scale: f32) {
+    let added = CAPACITY_PER_PICKUP * scale;
+    tank.max_capacity += added;
+    tank.current = (tank.current + added).min(tank.max_capacity);
+}
+
+pub fn preview(tank: &AirTank, scale: f32) -> String {
+    tr_args("reward.air_tank.preview", &[("from", &format!("{:.1}", tank.max_capacity)), ("to", &format!("{:.1}", tank.max_capacity + CAPACITY_PER_PICKUP * scale))])
 }
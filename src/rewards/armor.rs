@@ -1,8 +1,15 @@
 use crate::player::Armor;
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Armor Up";
+pub const NAME: &str = "reward.armor.name";
 pub const ASSET: &str = "rewards/ArmorBox.png";
 
-pub fn apply(armor: &mut Armor) {
-    armor.0 += 20.0;
+const ARMOR_PER_PICKUP: f32 = 20.0;
+
+pub fn apply(armor: &mut Armor, scale: f32) {
+    armor.0 += ARMOR_PER_PICKUP * scale;
+}
+
+pub fn preview(armor: &Armor, scale: f32) -> String {
+    tr_args("reward.armor.preview", &[("from", &format!("{:.0}", armor.0)), ("to", &format!("{:.0}", armor.0 + ARMOR_PER_PICKUP * scale))])
 }
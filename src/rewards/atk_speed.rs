@@ -1,12 +1,23 @@
 use std::time::Duration;
 use crate::weapons::Weapon;
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Attack Speed Up";
+pub const NAME: &str = "reward.atk_speed.name";
 pub const ASSET: &str = "rewards/AtkSpdBox.png";
 
-pub fn apply(weapon: &mut Weapon) {
+const COOLING_PER_STACK: f32 = 1.15;
+
+/// Shortens the fire timer and speeds up venting and reloads.
+pub fn apply(weapon: &mut Weapon, scale: f32) {
+    weapon.cooling *= 1.0 + (COOLING_PER_STACK - 1.0) * scale;
     let current = weapon.shoot_timer.duration().as_secs_f32();
-    let new_duration = (current - 0.03).max(0.05);
+    let new_duration = (current - 0.03 * scale).max(0.05);
     weapon.fire_rate = new_duration;
     weapon.shoot_timer.set_duration(Duration::from_secs_f32(new_duration));
 }
+
+pub fn preview(weapon: &Weapon, scale: f32) -> String {
+    let mut next = weapon.clone();
+    apply(&mut next, scale);
+    tr_args("reward.atk_speed.preview", &[("from", &format!("{:.2}", weapon.fire_rate)), ("to", &format!("{:.2}", next.fire_rate))])
+}
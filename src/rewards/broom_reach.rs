@@ -0,0 +1,15 @@
+use crate::broom::BroomStats;
+use crate::locale::tr_args;
+
+pub const NAME: &str = "reward.broom_reach.name";
+pub const ASSET: &str = "rewards/BroomArc.png";
+
+const REACH_PER_PICKUP: f32 = 0.15;
+
+pub fn apply(stats: &mut BroomStats, scale: f32) {
+    stats.reach += REACH_PER_PICKUP * scale;
+}
+
+pub fn preview(stats: &BroomStats, scale: f32) -> String {
+    tr_args("reward.broom_reach.preview", &[("from", &format!("{:.0}", stats.reach * 100.0)), ("to", &format!("{:.0}", (stats.reach + REACH_PER_PICKUP * scale) * 100.0))])
+}
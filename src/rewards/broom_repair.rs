@@ -0,0 +1,15 @@
+use crate::broom::BroomStats;
+use crate::locale::tr_args;
+
+pub const NAME: &str = "reward.broom_repair.name";
+pub const ASSET: &str = "rewards/BroomRepair.png";
+
+const REPAIR_PER_PICKUP: f32 = 10.0;
+
+pub fn apply(stats: &mut BroomStats, scale: f32) {
+    stats.repair += REPAIR_PER_PICKUP * scale;
+}
+
+pub fn preview(stats: &BroomStats, scale: f32) -> String {
+    tr_args("reward.broom_repair.preview", &[("from", &format!("{:.0}", stats.repair)), ("to", &format!("{:.0}", stats.repair + REPAIR_PER_PICKUP * scale))])
+}
@@ -0,0 +1,18 @@
+use crate::broom::BroomStats;
+use crate::locale::tr_args;
+
+pub const NAME: &str = "reward.broom_stun.name";
+pub const ASSET: &str = "rewards/BroomStun.png";
+
+/// First pickup stuns for 0.5s; each one after adds a bit more.
+fn next(stun: f32, scale: f32) -> f32 {
+    stun + if stun == 0.0 { 0.5 } else { 0.25 * scale }
+}
+
+pub fn apply(stats: &mut BroomStats, scale: f32) {
+    stats.stun = next(stats.stun, scale);
+}
+
+pub fn preview(stats: &BroomStats, scale: f32) -> String {
+    tr_args("reward.broom_stun.preview", &[("from", &format!("{:.2}", stats.stun)), ("to", &format!("{:.2}", next(stats.stun, scale)))])
+}
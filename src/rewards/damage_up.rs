@@ -1,8 +1,15 @@
 use crate::weapons::Weapon;
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Damage Up";
+pub const NAME: &str = "reward.damage_up.name";
 pub const ASSET: &str = "rewards/DamageUp.png";
 
-pub fn apply(weapon: &mut Weapon) {
-    weapon.damage += 10.0;
+const DAMAGE_PER_PICKUP: f32 = 10.0;
+
+pub fn apply(weapon: &mut Weapon, scale: f32) {
+    weapon.damage += DAMAGE_PER_PICKUP * scale;
+}
+
+pub fn preview(weapon: &Weapon, scale: f32) -> String {
+    tr_args("reward.damage_up.preview", &[("from", &format!("{:.0}", weapon.damage)), ("to", &format!("{:.0}", weapon.damage + DAMAGE_PER_PICKUP * scale))])
 }
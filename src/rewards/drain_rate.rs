@@ -1,9 +1,25 @@
 use crate::player::AirTank;
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Slower Air Drain";
+pub const NAME: &str = "reward.drain_rate.name";
 pub const ASSET: &str = "rewards/DrainRate.png";
 
-pub fn apply(tank: &mut AirTank) {
+const DRAIN_FACTOR: f32 = 0.8;
+const MIN_DRAIN: f32 = 0.2;
+
+fn next(drain_rate: f32, scale: f32) -> f32 {
+    (drain_rate * (1.0 - (1.0 - DRAIN_FACTOR) * scale)).max(MIN_DRAIN)
+}
+
+pub fn apply(tank: &mut AirTank, scale: f32) {
     // 20% reduction per pickup, minimum 0.2 units/sec
-    tank.drain_rate = (tank.drain_rate * 0.8).max(0.2);
+    tank.drain_rate = next(tank.drain_rate, scale);
+}
+
+pub fn preview(tank: &AirTank, scale: f32) -> String {
+    tr_args("reward.drain_rate.preview", &[("from", &format!("{:.2}", tank.drain_rate)), ("to", &format!("{:.2}", next(tank.drain_rate, scale)))])
+}
+
+pub fn capped(tank: &AirTank) -> bool {
+    tank.drain_rate <= MIN_DRAIN
 }
@@ -1,11 +1,16 @@
-use rand::random_range;
 use crate::player::{Health, MaxHealth};
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Max HP Up";
+pub const NAME: &str = "reward.max_hp.name";
 pub const ASSET: &str = "rewards/HeartBox.png";
 
-pub fn apply(hp: &mut Health, maxhp: &mut MaxHealth) {
-    let increase = random_range(5..=20) as f32;
-    maxhp.0 += increase;
-    hp.0 += increase;
+const HP_PER_PICKUP: f32 = 15.0;
+
+pub fn apply(hp: &mut Health, maxhp: &mut MaxHealth, scale: f32) {
+    maxhp.0 += HP_PER_PICKUP * scale;
+    hp.0 += HP_PER_PICKUP * scale;
+}
+
+pub fn preview(maxhp: &MaxHealth, scale: f32) -> String {
+    tr_args("reward.max_hp.preview", &[("from", &format!("{:.0}", maxhp.0)), ("to", &format!("{:.0}", maxhp.0 + HP_PER_PICKUP * scale))])
 }
@@ -1,22 +1,32 @@
 pub mod air_tank;
 pub mod armor;
 pub mod atk_speed;
+pub mod broom_reach;
+pub mod broom_repair;
+pub mod broom_stun;
 pub mod damage_up;
 pub mod drain_rate;
 pub mod max_hp;
 pub mod move_speed;
 pub mod piercing;
 pub mod regen;
+pub mod registry;
 pub mod shield;
+pub mod synergy;
 pub mod vacuum_res;
 
 use bevy::prelude::*;
-use rand::random_range;
-use crate::{TILE_SIZE, GameEntity};
+use rand::Rng;
+use crate::{TILE_SIZE, GameEntity, RunRng};
 use crate::Player;
 use crate::player::{Health, MaxHealth, MoveSpeed, Armor, AirTank, Regen, Shield, ThrusterFuel, WeaponBuffStacks, aabb_overlap};
 use crate::fluiddynamics::PulledByFluid;
 use crate::weapons::WeaponInventory;
+use crate::broom::BroomStats;
+use crate::locale::{tr, tr_args};
+use std::collections::HashMap;
+pub use registry::{Rarity, RewardRegistry};
+pub use synergy::Synergy;
 
 // Popup 
 
@@ -45,10 +55,112 @@ pub fn tick_reward_popups(
     }
 }
 
+// Reward kinds
+
+/// Every reward a crate can hold.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, serde::Serialize, serde::Deserialize)]
+pub enum RewardKind {
+    MaxHp,
+    AtkSpeed,
+    MoveSpeed,
+    Armor,
+    AirTank,
+    DrainRate,
+    VacuumRes,
+    Regen,
+    Piercing,
+    DamageUp,
+    Shield,
+    BroomRepair,
+    BroomReach,
+    BroomStun,
+}
+
+impl RewardKind {
+    pub const ALL: [RewardKind; 14] = [
+        RewardKind::MaxHp,
+        RewardKind::AtkSpeed,
+        RewardKind::MoveSpeed,
+        RewardKind::Armor,
+        RewardKind::AirTank,
+        RewardKind::DrainRate,
+        RewardKind::VacuumRes,
+        RewardKind::Regen,
+        RewardKind::Piercing,
+        RewardKind::DamageUp,
+        RewardKind::Shield,
+        RewardKind::BroomRepair,
+        RewardKind::BroomReach,
+        RewardKind::BroomStun,
+    ];
+
+    pub fn name(self) -> String {
+        tr(match self {
+            RewardKind::MaxHp       => max_hp::NAME,
+            RewardKind::AtkSpeed    => atk_speed::NAME,
+            RewardKind::MoveSpeed   => move_speed::NAME,
+            RewardKind::Armor       => armor::NAME,
+            RewardKind::AirTank     => air_tank::NAME,
+            RewardKind::DrainRate   => drain_rate::NAME,
+            RewardKind::VacuumRes   => vacuum_res::NAME,
+            RewardKind::Regen       => regen::NAME,
+            RewardKind::Piercing    => piercing::NAME,
+            RewardKind::DamageUp    => damage_up::NAME,
+            RewardKind::Shield      => shield::NAME,
+            RewardKind::BroomRepair => broom_repair::NAME,
+            RewardKind::BroomReach  => broom_reach::NAME,
+            RewardKind::BroomStun   => broom_stun::NAME,
+        })
+    }
+
+    /// Weighted pick by rarity, skipping anything in `exclude`.
+    fn random(registry: &RewardRegistry, exclude: &[RewardKind], rng: &mut RunRng) -> Option<RewardKind> {
+        let pool: Vec<RewardKind> = Self::ALL.into_iter().filter(|k| !exclude.contains(k)).collect();
+        let total: u32 = pool.iter().map(|&k| registry.weight(k)).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.random_range(0..total);
+        pool.into_iter().find(|&k| {
+            if roll < registry.weight(k) { return true; }
+            roll -= registry.weight(k);
+            false
+        })
+    }
+}
+
+/// How many of each reward the player has picked up this run.
+#[derive(Component, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RewardStacks(pub HashMap<RewardKind, u32>);
+
+impl RewardStacks {
+    pub fn count(&self, kind: RewardKind) -> u32 {
+        self.0.get(&kind).copied().unwrap_or(0)
+    }
+}
+
 // Reward component & asset resource 
 
 #[derive(Component)]
-pub struct Reward(pub usize);
+pub struct Reward(pub RewardKind);
+
+/// One of the crates on a room-clear pedestal. Picking any crate removes the
+/// pedestal entity it points at along with the other crates.
+#[derive(Component)]
+pub struct RewardChoice(pub Entity);
+
+/// Stat-change text above a reward crate, shown while the player is close.
+#[derive(Component)]
+pub struct RewardTooltip;
+
+/// Rarity-coloured backing drawn behind a reward crate.
+#[derive(Component)]
+pub struct RarityGlow;
+
+const CHOICE_COUNT: usize = 3;
+const CHOICE_SPACING: f32 = TILE_SIZE * 1.5;
+const CRATE_SCALE: f32 = 0.75;
+const TOOLTIP_RANGE: f32 = TILE_SIZE * 2.0;
 
 #[allow(dead_code)]
 #[derive(Resource)]
@@ -64,6 +176,30 @@ pub struct RewardRes {
     piercing:   Handle<Image>,
     damage_up:  Handle<Image>,
     shield_burst: Handle<Image>,
+    broom_repair: Handle<Image>,
+    broom_reach:  Handle<Image>,
+    broom_stun:   Handle<Image>,
+}
+
+impl RewardRes {
+    fn image(&self, kind: RewardKind) -> Handle<Image> {
+        match kind {
+            RewardKind::MaxHp       => self.max_hp.clone(),
+            RewardKind::AtkSpeed    => self.atk_spd.clone(),
+            RewardKind::MoveSpeed   => self.mov_spd.clone(),
+            RewardKind::Armor       => self.armor.clone(),
+            RewardKind::AirTank     => self.air_tank.clone(),
+            RewardKind::DrainRate   => self.drain_rate.clone(),
+            RewardKind::VacuumRes   => self.vacuum_res.clone(),
+            RewardKind::Regen       => self.regen.clone(),
+            RewardKind::Piercing    => self.piercing.clone(),
+            RewardKind::DamageUp    => self.damage_up.clone(),
+            RewardKind::Shield      => self.shield_burst.clone(),
+            RewardKind::BroomRepair => self.broom_repair.clone(),
+            RewardKind::BroomReach  => self.broom_reach.clone(),
+            RewardKind::BroomStun   => self.broom_stun.clone(),
+        }
+    }
 }
 
 
@@ -71,7 +207,8 @@ pub struct RewardPlugin;
 
 impl Plugin for RewardPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, load_crates);
+        app.add_systems(Startup, (load_crates, registry::load_reward_registry))
+            .add_plugins(synergy::SynergyPlugin);
     }
 }
 
@@ -99,38 +236,190 @@ fn load_crates(mut commands: Commands, asset_server: Res<AssetServer>) {
         piercing:     asset_server.load(piercing::ASSET),
         damage_up:    asset_server.load(damage_up::ASSET),
         shield_burst: asset_server.load(shield::ASSET),
+        broom_repair: asset_server.load(broom_repair::ASSET),
+        broom_reach:  asset_server.load(broom_reach::ASSET),
+        broom_stun:   asset_server.load(broom_stun::ASSET),
     });
 }
 
 // Spawn
 
-pub fn spawn_reward(commands: &mut Commands, pos: Vec3, box_sprite: &RewardRes) {
-    let reward_type: usize = random_range(1..=11);
-    let reward_img = match reward_type {
-        1  => box_sprite.max_hp.clone(),
-        2  => box_sprite.atk_spd.clone(),
-        3  => box_sprite.mov_spd.clone(),
-        4  => box_sprite.armor.clone(),
-        5  => box_sprite.air_tank.clone(),
-        6  => box_sprite.drain_rate.clone(),
-        7  => box_sprite.vacuum_res.clone(),
-        8  => box_sprite.regen.clone(),
-        9  => box_sprite.piercing.clone(),
-        10 => box_sprite.damage_up.clone(),
-        11 => box_sprite.shield_burst.clone(),
-        _  => { warn!("spawn_reward: type {} out of range, defaulting to max_hp", reward_type); box_sprite.max_hp.clone() }
-    };
+/// Rewards at their stack cap or that would do nothing for the player right now.
+pub fn capped_rewards(
+    registry: &RewardRegistry,
+    stacks: &RewardStacks,
+    movspd: &MoveSpeed,
+    fuel: &ThrusterFuel,
+    tank: &AirTank,
+) -> Vec<RewardKind> {
+    let mut capped: Vec<RewardKind> = RewardKind::ALL
+        .into_iter()
+        .filter(|&k| registry.capped(k, stacks.count(k)))
+        .collect();
+    if move_speed::capped(movspd, fuel) { capped.push(RewardKind::MoveSpeed); }
+    if drain_rate::capped(tank) { capped.push(RewardKind::DrainRate); }
+    capped
+}
+
+fn spawn_crate(
+    commands: &mut Commands,
+    pos: Vec3,
+    kind: RewardKind,
+    box_sprite: &RewardRes,
+    registry: &RewardRegistry,
+    font: &Handle<Font>,
+) -> Entity {
+    commands
+        .spawn((
+            Sprite::from_image(box_sprite.image(kind)),
+            Transform {
+                translation: pos,
+                scale: Vec3::new(CRATE_SCALE, CRATE_SCALE, 1.0),
+                ..Default::default()
+            },
+            Reward(kind),
+            GameEntity,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Sprite {
+                    color: registry.get(kind).rarity.color().with_alpha(0.6),
+                    custom_size: Some(Vec2::splat(TILE_SIZE * 1.2 / CRATE_SCALE)),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, -0.05),
+                RarityGlow,
+            ));
+            // Counter the crate's scale so the text renders at its font size.
+            p.spawn((
+                Text2d::new(""),
+                TextFont { font: font.clone(), font_size: 16.0, ..default() },
+                TextColor(Color::WHITE),
+                Transform {
+                    translation: Vec3::new(0.0, TILE_SIZE * 1.2 / CRATE_SCALE, 10.0),
+                    scale: Vec3::splat(1.0 / CRATE_SCALE),
+                    ..default()
+                },
+                Visibility::Hidden,
+                RewardTooltip,
+            ));
+        })
+        .id()
+}
 
-    commands.spawn((
-        Sprite::from_image(reward_img),
-        Transform {
-            translation: pos,
-            scale: Vec3::new(0.75, 0.75, 1.0),
-            ..Default::default()
+/// A single random reward crate, e.g. an elite's bonus drop.
+pub fn spawn_reward(commands: &mut Commands, pos: Vec3, box_sprite: &RewardRes, registry: &RewardRegistry, font: &RewardFont, rng: &mut RunRng) {
+    let kind = RewardKind::random(registry, &[], rng).unwrap_or(RewardKind::MaxHp);
+    spawn_crate(commands, pos, kind, box_sprite, registry, &font.0);
+}
+
+/// A pedestal of three different rewards centred on `pos`; the player keeps one.
+pub fn spawn_reward_choice(
+    commands: &mut Commands,
+    pos: Vec3,
+    box_sprite: &RewardRes,
+    registry: &RewardRegistry,
+    font: &RewardFont,
+    capped: &[RewardKind],
+    rng: &mut RunRng,
+) {
+    let mut exclude = capped.to_vec();
+    let mut kinds = Vec::with_capacity(CHOICE_COUNT);
+    while kinds.len() < CHOICE_COUNT {
+        let Some(kind) = RewardKind::random(registry, &exclude, rng) else { break };
+        exclude.push(kind);
+        kinds.push(kind);
+    }
+
+    let width = CHOICE_SPACING * kinds.len() as f32;
+    let pedestal = commands.spawn((
+        Sprite {
+            color: Color::srgba(0.15, 0.18, 0.25, 0.85),
+            custom_size: Some(Vec2::new(width, TILE_SIZE * 1.3)),
+            ..default()
         },
-        Reward(reward_type),
+        Transform::from_translation(pos - Vec3::Z * 0.1),
         GameEntity,
-    ));
+    )).id();
+
+    let first = -(kinds.len() as f32 - 1.0) * 0.5;
+    for (i, kind) in kinds.into_iter().enumerate() {
+        let at = pos + Vec3::X * (first + i as f32) * CHOICE_SPACING;
+        let id = spawn_crate(commands, at, kind, box_sprite, registry, &font.0);
+        commands.entity(id).insert(RewardChoice(pedestal));
+    }
+}
+
+// Tooltip
+
+fn reward_preview(
+    kind: RewardKind,
+    scale: f32,
+    (maxhp, movspd, armor, tank, regen, shield, pull, inv, broom): (
+        &MaxHealth, &MoveSpeed, &Armor, &AirTank, &Regen, &Shield, &PulledByFluid, &WeaponInventory, &BroomStats,
+    ),
+) -> String {
+    let weapon = inv.current();
+    match kind {
+        RewardKind::MaxHp       => max_hp::preview(maxhp, scale),
+        RewardKind::AtkSpeed    => atk_speed::preview(weapon, scale),
+        RewardKind::MoveSpeed   => move_speed::preview(movspd, scale),
+        RewardKind::Armor       => armor::preview(armor, scale),
+        RewardKind::AirTank     => air_tank::preview(tank, scale),
+        RewardKind::DrainRate   => drain_rate::preview(tank, scale),
+        RewardKind::VacuumRes   => vacuum_res::preview(pull, scale),
+        RewardKind::Regen       => regen::preview(regen, scale),
+        RewardKind::Piercing    => piercing::preview(weapon),
+        RewardKind::DamageUp    => damage_up::preview(weapon, scale),
+        RewardKind::Shield      => shield::preview(shield),
+        RewardKind::BroomRepair => broom_repair::preview(broom, scale),
+        RewardKind::BroomReach  => broom_reach::preview(broom, scale),
+        RewardKind::BroomStun   => broom_stun::preview(broom, scale),
+    }
+}
+
+/// Shows the stat change for the crate nearest the player, if within range.
+pub fn update_reward_tooltips(
+    registry: Res<RewardRegistry>,
+    player_q: Query<(
+        &Transform, &MaxHealth, &MoveSpeed, &Armor, &AirTank, &Regen, &Shield,
+        &PulledByFluid, &WeaponInventory, &BroomStats, &RewardStacks,
+    ), With<Player>>,
+    rewards: Query<(&Transform, &Reward), Without<Player>>,
+    mut tooltips: Query<(&ChildOf, &mut Text2d, &mut Visibility), With<RewardTooltip>>,
+) {
+    let Ok((player_tf, maxhp, movspd, armor, tank, regen, shield, pull, inv, broom, stacks)) = player_q.single() else { return };
+    let player_pos = player_tf.translation.truncate();
+
+    let nearest = tooltips
+        .iter()
+        .filter_map(|(parent, _, _)| {
+            let (tf, _) = rewards.get(parent.parent()).ok()?;
+            let d = tf.translation.truncate().distance(player_pos);
+            (d <= TOOLTIP_RANGE).then_some((parent.parent(), d))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(e, _)| e);
+
+    for (parent, mut text, mut vis) in &mut tooltips {
+        if Some(parent.parent()) != nearest {
+            *vis = Visibility::Hidden;
+            continue;
+        }
+        let Ok((_, reward)) = rewards.get(parent.parent()) else { continue };
+        let def = registry.get(reward.0);
+        let held = stacks.count(reward.0);
+        let preview = if registry.capped(reward.0, held) {
+            format!("{}\n{}", def.rarity.name(), tr_args("reward.capped", &[("name", &reward.0.name())]))
+        } else {
+            let scale = registry.scale(reward.0, held);
+            format!("{}\n{}", def.rarity.name(), reward_preview(reward.0, scale, (maxhp, movspd, armor, tank, regen, shield, pull, inv, broom)))
+        };
+        if text.0 != preview {
+            text.0 = preview;
+        }
+        *vis = Visibility::Inherited;
+    }
 }
 
 // Pickup
@@ -141,73 +430,92 @@ pub fn player_pickup_reward(
         Entity, &Transform,
         &mut Health, &mut MaxHealth, &mut MoveSpeed, &mut Armor, &mut AirTank,
         &mut Regen, &mut Shield, &mut PulledByFluid, &mut ThrusterFuel,
-        &mut WeaponBuffStacks,
+        &mut WeaponBuffStacks, &mut BroomStats, &mut RewardStacks,
     ), With<Player>>,
-    reward_query: Query<(Entity, &Transform, &Reward)>,
+    registry: Res<RewardRegistry>,
+    reward_query: Query<(Entity, &Transform, &Reward, Option<&RewardChoice>)>,
     mut player_weapon_q: Query<&mut WeaponInventory, With<Player>>,
     font: Res<RewardFont>,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
 ) {
     let Ok((
         _player_entity, player_tf,
         mut hp, mut maxhp, mut movspd, mut arm, mut tank,
-        mut reg, mut shld, mut pull, mut fuel, mut stacks,
+        mut reg, mut shld, mut pull, mut fuel, mut stacks, mut broom, mut reward_stacks,
     )) = player_query.single_mut() else {
         return;
     };
     let player_pos = player_tf.translation;
     let player_half = Vec2::splat(TILE_SIZE * 0.5);
+    let mut taken: Vec<Entity> = Vec::new();
 
-    for (reward_entity, reward_tf, reward_type) in &reward_query {
+    for (reward_entity, reward_tf, reward, choice) in &reward_query {
+        // The other crates of a pedestal we just picked from are already gone.
+        if choice.is_some_and(|c| taken.contains(&c.0)) {
+            continue;
+        }
         let reward_pos = reward_tf.translation;
         let reward_half = Vec2::splat(TILE_SIZE * 0.5);
         if !aabb_overlap(player_pos.x, player_pos.y, player_half, reward_pos.x, reward_pos.y, reward_half) {
             continue;
         }
 
-        if let Ok(mut inv) = player_weapon_q.single_mut() {
-            match reward_type.0 {
-                1  => max_hp::apply(&mut hp, &mut maxhp),
-                2  => { stacks.atk_speed += 1; for w in &mut inv.weapons { atk_speed::apply(w); } }
-                3  => move_speed::apply(&mut movspd, &mut fuel),
-                4  => armor::apply(&mut arm),
-                5  => air_tank::apply(&mut tank),
-                6  => drain_rate::apply(&mut tank),
-                7  => vacuum_res::apply(&mut pull),
-                8  => regen::apply(&mut reg),
-                9  => { stacks.piercing += 1; for w in &mut inv.weapons { piercing::apply(w); } }
-                10 => { stacks.damage += 1; for w in &mut inv.weapons { damage_up::apply(w); } }
-                11 => shield::apply(&mut shld),
-                _  => { warn!("player_pickup_reward: unknown type {}, defaulting to max_hp", reward_type.0); max_hp::apply(&mut hp, &mut maxhp) }
+        let held = reward_stacks.count(reward.0);
+        let capped = registry.capped(reward.0, held);
+        let scale = registry.scale(reward.0, held);
+        let synergies_before: Vec<Synergy> = Synergy::active(&reward_stacks).collect();
+        if !capped && let Ok(mut inv) = player_weapon_q.single_mut() {
+            match reward.0 {
+                RewardKind::MaxHp       => max_hp::apply(&mut hp, &mut maxhp, scale),
+                RewardKind::AtkSpeed    => { stacks.atk_speed += 1; for w in &mut inv.weapons { atk_speed::apply(w, scale); } }
+                RewardKind::MoveSpeed   => move_speed::apply(&mut movspd, &mut fuel, scale),
+                RewardKind::Armor       => armor::apply(&mut arm, scale),
+                RewardKind::AirTank     => air_tank::apply(&mut tank, scale),
+                RewardKind::DrainRate   => drain_rate::apply(&mut tank, scale),
+                RewardKind::VacuumRes   => vacuum_res::apply(&mut pull, scale),
+                RewardKind::Regen       => regen::apply(&mut reg, scale),
+                RewardKind::Piercing    => { stacks.piercing += 1; for w in &mut inv.weapons { piercing::apply(w); } }
+                RewardKind::DamageUp    => { stacks.damage += 1; for w in &mut inv.weapons { damage_up::apply(w, scale); } }
+                RewardKind::Shield      => shield::apply(&mut shld),
+                RewardKind::BroomRepair => broom_repair::apply(&mut broom, scale),
+                RewardKind::BroomReach  => broom_reach::apply(&mut broom, scale),
+                RewardKind::BroomStun   => broom_stun::apply(&mut broom, scale),
             }
+            *reward_stacks.0.entry(reward.0).or_insert(0) += 1;
         }
 
+        stat_events.write(crate::run_history::StatEvent::RewardPicked(reward.0));
+
         if let Ok(mut ec) = commands.get_entity(reward_entity) { ec.despawn(); }
+        if let Some(choice) = choice {
+            taken.push(choice.0);
+            if let Ok(mut ec) = commands.get_entity(choice.0) { ec.despawn(); }
+            for (other, _, _, other_choice) in &reward_query {
+                if other != reward_entity && other_choice.is_some_and(|c| c.0 == choice.0) {
+                    commands.entity(other).despawn();
+                }
+            }
+        }
 
-        let name = reward_name(reward_type.0);
+        let label = if capped { tr_args("reward.capped", &[("name", &reward.0.name())]) } else { reward.0.name() };
         commands.spawn((
-            Text2d::new(name),
+            Text2d::new(label),
             TextFont { font: font.0.clone(), font_size: 20.0, ..default() },
-            TextColor(Color::srgba(1.0, 1.0, 0.3, 1.0)),
+            TextColor(registry.get(reward.0).rarity.color()),
             Transform::from_translation(Vec3::new(reward_pos.x, reward_pos.y + TILE_SIZE, 10.0)),
             RewardPopup { timer: Timer::from_seconds(1.5, TimerMode::Once) },
             GameEntity,
         ));
-    }
-}
 
-fn reward_name(id: usize) -> &'static str {
-    match id {
-        1  => max_hp::NAME,
-        2  => atk_speed::NAME,
-        3  => move_speed::NAME,
-        4  => armor::NAME,
-        5  => air_tank::NAME,
-        6  => drain_rate::NAME,
-        7  => vacuum_res::NAME,
-        8  => regen::NAME,
-        9  => piercing::NAME,
-        10 => damage_up::NAME,
-        11 => shield::NAME,
-        _  => "???",
+        for synergy in Synergy::active(&reward_stacks).filter(|s| !synergies_before.contains(s)) {
+            commands.spawn((
+                Text2d::new(tr_args("reward.synergy", &[("name", &synergy.name())])),
+                TextFont { font: font.0.clone(), font_size: 20.0, ..default() },
+                TextColor(synergy::SYNERGY_COLOR),
+                Transform::from_translation(Vec3::new(reward_pos.x, reward_pos.y + TILE_SIZE * 1.8, 10.0)),
+                RewardPopup { timer: Timer::from_seconds(2.5, TimerMode::Once) },
+                GameEntity,
+            ));
+        }
     }
 }
@@ -1,13 +1,27 @@
 use crate::player::{MoveSpeed, ThrusterFuel};
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Move Speed Up";
+pub const NAME: &str = "reward.move_speed.name";
 pub const ASSET: &str = "rewards/MoveSpdBox.png";
 
-pub fn apply(movspd: &mut MoveSpeed, fuel: &mut ThrusterFuel) {
-    movspd.0 = (movspd.0 + 20.0).min(600.0);
+const SPEED_PER_PICKUP: f32 = 20.0;
+const MAX_SPEED: f32 = 600.0;
+const FUEL_PER_PICKUP: f32 = 3.0;
+const MAX_FUEL: f32 = 10.0;
+
+pub fn apply(movspd: &mut MoveSpeed, fuel: &mut ThrusterFuel, scale: f32) {
+    movspd.0 = (movspd.0 + SPEED_PER_PICKUP * scale).min(MAX_SPEED);
     // Each Speed Up also extends the thruster fuel tank (max 10 charges)
-    let new_max = (fuel.max + 3.0).min(10.0);
+    let new_max = (fuel.max + FUEL_PER_PICKUP * scale).min(MAX_FUEL);
     let added = new_max - fuel.max;
     fuel.max = new_max;
     fuel.current = (fuel.current + added).min(fuel.max);
 }
+
+pub fn preview(movspd: &MoveSpeed, scale: f32) -> String {
+    tr_args("reward.move_speed.preview", &[("from", &format!("{:.0}", movspd.0)), ("to", &format!("{:.0}", (movspd.0 + SPEED_PER_PICKUP * scale).min(MAX_SPEED)))])
+}
+
+pub fn capped(movspd: &MoveSpeed, fuel: &ThrusterFuel) -> bool {
+    movspd.0 >= MAX_SPEED && fuel.max >= MAX_FUEL
+}
@@ -1,11 +1,19 @@
 use crate::weapons::Weapon;
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Piercing Rounds";
+pub const NAME: &str = "reward.piercing.name";
 pub const ASSET: &str = "rewards/Piercing.png";
 
 /// Each pickup adds one raw piercing stack.
 /// Bullets use `Weapon::effective_pierce_count()` to determine the actual pierce level,
-/// which applies diminishing returns after 4 pickups (2 pickups per +1 pierce level).
+/// which applies diminishing returns after 4 pickups (2 pickups per +1 pierce level),
+/// so the registry curve isn't applied on top.
 pub fn apply(weapon: &mut Weapon) {
     weapon.piercing_pickups += 1;
 }
+
+pub fn preview(weapon: &Weapon) -> String {
+    let mut next = weapon.clone();
+    apply(&mut next);
+    tr_args("reward.piercing.preview", &[("from", &weapon.effective_pierce_count()), ("to", &next.effective_pierce_count())])
+}
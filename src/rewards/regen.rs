@@ -1,8 +1,15 @@
 use crate::player::Regen;
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Regen";
+pub const NAME: &str = "reward.regen.name";
 pub const ASSET: &str = "rewards/HealthRegen.png";
 
-pub fn apply(regen: &mut Regen) {
-    regen.0 += 2.0;
+const REGEN_PER_PICKUP: f32 = 2.0;
+
+pub fn apply(regen: &mut Regen, scale: f32) {
+    regen.0 += REGEN_PER_PICKUP * scale;
+}
+
+pub fn preview(regen: &Regen, scale: f32) -> String {
+    tr_args("reward.regen.preview", &[("from", &format!("{:.1}", regen.0)), ("to", &format!("{:.1}", regen.0 + REGEN_PER_PICKUP * scale))])
 }
@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+use bevy::asset::io::file::FileAssetReader;
+use serde::Deserialize;
+use crate::locale::tr;
+use std::collections::{HashMap, HashSet};
+use super::RewardKind;
+
+/// File (relative to the asset root) holding every reward's rarity, cap and curve.
+const REWARD_FILE: &str = "rewards/rewards.ron";
+
+// ── Definition file ──────────────────────────────────────────────────────────
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Rare,
+    Legendary,
+}
+
+impl Rarity {
+    /// Relative drop weight shared by every reward of this rarity.
+    pub fn weight(self) -> u32 {
+        match self {
+            Rarity::Common    => 12,
+            Rarity::Rare      => 4,
+            Rarity::Legendary => 1,
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Rarity::Common    => Color::srgb(0.75, 0.75, 0.75),
+            Rarity::Rare      => Color::srgb(0.3, 0.6, 1.0),
+            Rarity::Legendary => Color::srgb(1.0, 0.75, 0.2),
+        }
+    }
+
+    pub fn name(self) -> String {
+        tr(match self {
+            Rarity::Common    => "rarity.common",
+            Rarity::Rare      => "rarity.rare",
+            Rarity::Legendary => "rarity.legendary",
+        })
+    }
+}
+
+/// How much each further pickup of a reward is worth, as a multiplier on the
+/// module's base amount. `held` is the number picked up before this one.
+#[derive(Deserialize, Clone, Copy, Default)]
+pub enum Curve {
+    #[default]
+    Flat,
+    /// Each pickup is worth `factor` times the one before it.
+    Diminishing(f32),
+    /// Each pickup adds `step` to the multiplier.
+    Growing(f32),
+}
+
+impl Curve {
+    pub fn scale(self, held: u32) -> f32 {
+        match self {
+            Curve::Flat => 1.0,
+            Curve::Diminishing(factor) => factor.powi(held as i32),
+            Curve::Growing(step) => 1.0 + step * held as f32,
+        }
+    }
+}
+
+/// One entry in `assets/rewards/rewards.ron`.
+#[derive(Deserialize, Clone, Copy)]
+pub struct RewardDef {
+    pub kind: RewardKind,
+    #[serde(default)]
+    pub rarity: Rarity,
+    /// Pickups past this many do nothing, and the reward stops being offered.
+    #[serde(default)]
+    pub cap: Option<u32>,
+    #[serde(default)]
+    pub curve: Curve,
+}
+
+impl RewardDef {
+    fn fallback(kind: RewardKind) -> Self {
+        Self { kind, rarity: Rarity::Common, cap: None, curve: Curve::Flat }
+    }
+}
+
+#[derive(Deserialize)]
+struct RewardFile {
+    rewards: Vec<RewardDef>,
+}
+
+// ── Registry ────────────────────────────────────────────────────────────────
+
+/// Rarity, cap and curve for every `RewardKind`, read once at startup.
+#[derive(Resource)]
+pub struct RewardRegistry {
+    defs: HashMap<RewardKind, RewardDef>,
+    /// Rewards the profile hasn't unlocked yet; never rolled.
+    locked: HashSet<RewardKind>,
+}
+
+impl RewardRegistry {
+    /// The definition for `kind`; kinds missing from the file are common,
+    /// uncapped and flat.
+    pub fn get(&self, kind: RewardKind) -> RewardDef {
+        self.defs.get(&kind).copied().unwrap_or_else(|| RewardDef::fallback(kind))
+    }
+
+    pub fn weight(&self, kind: RewardKind) -> u32 {
+        if self.locked.contains(&kind) {
+            return 0;
+        }
+        self.get(kind).rarity.weight()
+    }
+
+    pub fn set_locked(&mut self, locked: HashSet<RewardKind>) {
+        self.locked = locked;
+    }
+
+    /// Multiplier for the next pickup when `held` are already owned.
+    pub fn scale(&self, kind: RewardKind, held: u32) -> f32 {
+        self.get(kind).curve.scale(held)
+    }
+
+    pub fn capped(&self, kind: RewardKind, held: u32) -> bool {
+        self.get(kind).cap.is_some_and(|cap| held >= cap)
+    }
+}
+
+fn read_defs() -> Vec<RewardDef> {
+    let path = FileAssetReader::get_base_path().join("assets").join(REWARD_FILE);
+    match std::fs::read_to_string(&path).map_err(|e| e.to_string())
+        .and_then(|s| ron::from_str::<RewardFile>(&s).map_err(|e| e.to_string()))
+    {
+        Ok(file) => file.rewards,
+        Err(e) => {
+            warn!("Could not load reward definitions '{}': {e}", path.display());
+            Vec::new()
+        }
+    }
+}
+
+pub(super) fn load_reward_registry(mut commands: Commands) {
+    let defs: HashMap<RewardKind, RewardDef> = read_defs().into_iter().map(|d| (d.kind, d)).collect();
+    for kind in RewardKind::ALL {
+        if !defs.contains_key(&kind) {
+            warn!("No reward definition for {kind:?}; using defaults");
+        }
+    }
+    commands.insert_resource(RewardRegistry { defs, locked: HashSet::new() });
+}
@@ -1,9 +1,15 @@
 use crate::player::Shield;
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Shield Charge";
+pub const NAME: &str = "reward.shield.name";
 pub const ASSET: &str = "rewards/Shield.png";
 
+/// Whole charges only, so the registry curve isn't applied; use a cap instead.
 pub fn apply(shield: &mut Shield) {
     shield.max += 1.0;
     shield.current = (shield.current + 1.0).min(shield.max);
 }
+
+pub fn preview(shield: &Shield) -> String {
+    tr_args("reward.shield.preview", &[("from", &format!("{:.0}", shield.max)), ("to", &format!("{:.0}", shield.max + 1.0))])
+}
@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use crate::GameState;
+use crate::locale::tr;
+use crate::player::{Player, Shield};
+use super::{RewardKind, RewardStacks};
+
+// ── Synergies ────────────────────────────────────────────────────────────────
+
+/// A bonus that switches on while the player holds every reward in
+/// `requires` at least once.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Synergy {
+    /// Shield + Regen: spent shield charges come back over time.
+    RechargingShield,
+    /// Vacuum Resistance + Move Speed: breaches can't pull you mid-dash.
+    VacuumDash,
+}
+
+impl Synergy {
+    pub const ALL: [Synergy; 2] = [Synergy::RechargingShield, Synergy::VacuumDash];
+
+    pub fn name(self) -> String {
+        tr(match self {
+            Synergy::RechargingShield => "synergy.recharging_shield",
+            Synergy::VacuumDash       => "synergy.vacuum_dash",
+        })
+    }
+
+    pub fn requires(self) -> [RewardKind; 2] {
+        match self {
+            Synergy::RechargingShield => [RewardKind::Shield, RewardKind::Regen],
+            Synergy::VacuumDash       => [RewardKind::VacuumRes, RewardKind::MoveSpeed],
+        }
+    }
+
+    pub fn is_active(self, stacks: &RewardStacks) -> bool {
+        self.requires().iter().all(|&k| stacks.count(k) > 0)
+    }
+
+    pub fn active(stacks: &RewardStacks) -> impl Iterator<Item = Synergy> + '_ {
+        Self::ALL.into_iter().filter(|s| s.is_active(stacks))
+    }
+}
+
+// ── Tuning ───────────────────────────────────────────────────────────────────
+
+pub const SYNERGY_COLOR: Color = Color::srgb(0.9, 0.4, 1.0);
+/// Seconds to regain one shield charge with Recharging Shield.
+const SHIELD_RECHARGE_TIME: f32 = 8.0;
+
+// ── Systems ──────────────────────────────────────────────────────────────────
+
+pub struct SynergyPlugin;
+
+impl Plugin for SynergyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            recharge_shield
+                .run_if(in_state(GameState::Playing))
+                .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+        );
+    }
+}
+
+fn recharge_shield(
+    time: Res<Time>,
+    mut progress: Local<f32>,
+    mut player_q: Query<(&RewardStacks, &mut Shield), With<Player>>,
+) {
+    let Ok((stacks, mut shield)) = player_q.single_mut() else { return };
+    if !Synergy::RechargingShield.is_active(stacks) || shield.current >= shield.max {
+        *progress = 0.0;
+        return;
+    }
+    *progress += time.delta_secs();
+    if *progress >= SHIELD_RECHARGE_TIME {
+        *progress = 0.0;
+        shield.current = (shield.current + 1.0).min(shield.max);
+    }
+}
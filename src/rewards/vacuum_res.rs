@@ -1,9 +1,16 @@
 use crate::fluiddynamics::PulledByFluid;
+use crate::locale::tr_args;
 
-pub const NAME: &str = "Vacuum Resistance";
+pub const NAME: &str = "reward.vacuum_res.name";
 pub const ASSET: &str = "rewards/VaccuumResistance.png";
 
-pub fn apply(pull: &mut PulledByFluid) {
+const MASS_PER_PICKUP: f32 = 25.0;
+
+pub fn apply(pull: &mut PulledByFluid, scale: f32) {
     // Heavier = harder to suck into breaches
-    pull.mass += 25.0;
+    pull.mass += MASS_PER_PICKUP * scale;
+}
+
+pub fn preview(pull: &PulledByFluid, scale: f32) -> String {
+    tr_args("reward.vacuum_res.preview", &[("from", &format!("{:.0}", pull.mass)), ("to", &format!("{:.0}", pull.mass + MASS_PER_PICKUP * scale))])
 }
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use rand::Rng;
 use rand::seq::SliceRandom;
 use rand::{SeedableRng};
 use rand::rngs::StdRng;
@@ -9,8 +10,9 @@ use crate::{GameEntity, GameState, TILE_SIZE, Z_ENTITIES};
 use crate::map::{Door, TablePositions};
 use crate::map::TileRes;
 use crate::player::{NumOfCleared, Player};
-use crate::enemies::{EnemyRes, LastKillPos, RangedEnemyRes, TurretRes, spawn_enemy_at, spawn_ranged_enemy_at, spawn_turret_enemy_at};
+use crate::enemies::{EnemyRes, LastKillPos, RangedEnemyRes, TurretRes, spawn_enemy_at, spawn_nest_at, spawn_ranged_enemy_at, spawn_saboteur_enemy_at, spawn_turret_enemy_at};
 use crate::table;
+use crate::locale::tr_args;
 
 #[derive(Resource)]
 pub struct EnemyPosition(pub HashSet<(usize, usize)>);
@@ -25,6 +27,15 @@ pub enum LevelState{
 #[derive(Resource)]
 pub struct RoomVec(pub Vec<Room>);
 
+/// What the player has to do before a room's doors reopen.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoomObjective {
+    /// Kill every enemy spawned on entry.
+    KillAll,
+    /// Destroy every nest; their remaining spawns die with the last one.
+    DestroyNests,
+}
+
 pub struct Room{
     pub cleared: bool,
     pub visited: bool,
@@ -40,6 +51,8 @@ pub struct Room{
     layout: Vec<String>,
     pub air_pressure: f32,
     pub breaches: Vec<Vec2>,
+    pub objective: RoomObjective,
+    pub nests_remaining: usize,
 }
 
 impl Room{
@@ -59,6 +72,8 @@ impl Room{
             layout: room_layout.clone(),
             air_pressure: 100.0,
             breaches: Vec::new(),
+            objective: RoomObjective::KillAll,
+            nests_remaining: 0,
         }
     }
 
@@ -254,6 +269,7 @@ pub fn entered_room(
     play_query: Single<&NumOfCleared, With<Player>>,
     station_level: Res<crate::StationLevel>,
     mut shield_query: Query<&mut crate::player::Shield, With<Player>>,
+    mut rng: ResMut<crate::RunRng>,
 ){
     match *lvlstate
     {
@@ -271,7 +287,7 @@ pub fn entered_room(
             }
 
             // info!("[room] entered_room processing index={}, doors={}", index, rooms.0[index].doors.len());
-            if let Some((pos, chest_pos)) = generate_enemies_in_room(1, None, &mut rooms, index, &mut commands, &enemy_res, &ranged_res, &turret_res, &play_query, station_level.0){
+            if let Some((pos, chest_pos)) = generate_enemies_in_room(1, &mut rng, &mut rooms, index, &mut commands, &enemy_res, &ranged_res, &turret_res, &play_query, station_level.0){
                 // info!("[room] enemies spawned in room {}, numofenemies={}", index, rooms.0[index].numofenemies);
                 *lvlstate = LevelState::InRoom(index, pos, chest_pos);
             } else {
@@ -325,18 +341,24 @@ pub fn playing_room(
     mut lvlstate: ResMut<LevelState>,
     mut commands: Commands,
     tiles: Res<TileRes>,
-    mut player: Single<&mut NumOfCleared, With<Player>>,
+    mut player: Single<(&mut NumOfCleared, &crate::player::MoveSpeed, &crate::player::ThrusterFuel, &crate::player::AirTank, &crate::rewards::RewardStacks), With<Player>>,
     heart_res: Res<crate::heart::HeartRes>,
     reward_res: Res<crate::rewards::RewardRes>,
+    reward_font: Res<crate::rewards::RewardFont>,
+    reward_registry: Res<crate::rewards::RewardRegistry>,
     last_kill_pos: Res<LastKillPos>,
     wall_grid: Res<crate::map::WallGrid>,
     grid: Res<crate::map::MapGridMeta>,
     planet: Option<Res<crate::PlanetLevelMarker>>,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
+    mut rng: ResMut<crate::RunRng>,
 ){
     match *lvlstate
     {
         LevelState::InRoom(index, reward_pos, _) =>
         {
+            // numofenemies also counts nests and every wave they emit mid-fight,
+            // so this covers both kill-all and destroy-the-nests rooms.
             if rooms.0[index].numofenemies == 0{
                 // debug!("All enemies defeated");
 
@@ -347,8 +369,11 @@ pub fn playing_room(
                     }
                 }
                 crate::heart::spawn_heart(&mut commands, &heart_res, heart_pos);
+                crate::items::roll_room_drop(&mut commands, &reward_font.0, heart_pos + Vec2::new(TILE_SIZE, 0.0), &mut rng);
                 if planet.is_none() {
-                    crate::rewards::spawn_reward(&mut commands, reward_pos, &reward_res);
+                    let (_, movspd, fuel, tank, stacks) = *player;
+                    let capped = crate::rewards::capped_rewards(&reward_registry, stacks, movspd, fuel, tank);
+                    crate::rewards::spawn_reward_choice(&mut commands, reward_pos, &reward_res, &reward_registry, &reward_font, &capped, &mut rng);
                 }
 
                 for door in rooms.0[index].doors.iter(){
@@ -358,8 +383,9 @@ pub fn playing_room(
                 }
 
                 rooms.0[index].cleared = true;
+                stat_events.write(crate::run_history::StatEvent::RoomCleared);
                 //rooms.0.remove(index);
-                player.0 += 1;
+                player.0.0 += 1;
                 *lvlstate = LevelState::NotRoom;
             }
         }
@@ -369,7 +395,7 @@ pub fn playing_room(
 
 pub fn generate_enemies_in_room(
     num_of_enemies: usize,
-    seed: Option<u64>,
+    rng: &mut crate::RunRng,
     rooms: &mut RoomVec,
     index: usize,
     mut commands: &mut Commands,
@@ -386,7 +412,19 @@ pub fn generate_enemies_in_room(
     let room = &mut rooms.0[index];
     let station_bonus = (station_level as usize) * 2;
     let base = room.base_enemies.max(num_of_enemies);
-    let scaled_num_enemies = rooms_cleared + base + station_bonus;
+    let mut scaled_num_enemies = rooms_cleared + base + station_bonus;
+
+    // Some rooms swap the kill-all fight for nests that keep spawning until
+    // destroyed; they start with a smaller garrison to compensate.
+    let nest_chance = (0.1 + station_level as f32 * 0.05).min(0.35);
+    let nest_count = if !room.is_airlock && rng.random::<f32>() < nest_chance {
+        (1 + station_level as usize / 2).min(3)
+    } else {
+        0
+    };
+    if nest_count > 0 {
+        scaled_num_enemies = scaled_num_enemies / 2 + nest_count;
+    }
     room.numofenemies = scaled_num_enemies;
 
     let health_multiplier = (1.0 + (station_level as f32) * 0.5) * room.health_mult;
@@ -420,13 +458,7 @@ pub fn generate_enemies_in_room(
         return None;
     }
 
-    if let Some(s) = seed {
-        let mut seeded = StdRng::seed_from_u64(s);
-        floors.shuffle(&mut seeded);
-    } else {
-        let mut trng = rand::rng();
-        floors.shuffle(&mut trng);
-    }
+    floors.shuffle(&mut **rng);
 
     // Iterate over ALL floor candidates (not just scaled_num_enemies of them) so that
     // the adjacent_to_wall filter doesn't silently drop spawns and leave numofenemies
@@ -434,6 +466,9 @@ pub fn generate_enemies_in_room(
     let mut actually_spawned: usize = 0;
     let mut spawn_idx: usize = 0; // separate counter so ranged ratio stays consistent
     let mut valid_floors: Vec<(f32, f32)> = Vec::new();
+    let mut nests_placed: usize = 0;
+    // Saboteurs only make sense where there's glass for them to go after.
+    let room_has_glass = room.layout.iter().any(|row| row.contains('G'));
     for (x, y) in floors.iter() {
         if actually_spawned >= scaled_num_enemies {
             break;
@@ -481,13 +516,23 @@ pub fn generate_enemies_in_room(
         valid_floors.push((*x, *y));
         let pos = Vec3::new(*x, *y, Z_ENTITIES);
 
-        if spawn_idx % 8 == 6 {
-            spawn_turret_enemy_at(&mut commands, turret_res, pos, true, health_multiplier, speed_bonus);
+        if nests_placed < nest_count {
+            spawn_nest_at(&mut commands, pos, index, health_multiplier, speed_bonus);
+            nests_placed += 1;
+            actually_spawned += 1;
+            continue;
+        }
+
+        let enemy = if spawn_idx % 8 == 6 {
+            spawn_turret_enemy_at(&mut commands, turret_res, pos, true, health_multiplier, speed_bonus)
         } else if spawn_idx % 8 == 2 {
-            spawn_ranged_enemy_at(&mut commands, ranged_res, pos, true, health_multiplier, speed_bonus);
+            spawn_ranged_enemy_at(&mut commands, ranged_res, pos, true, health_multiplier, speed_bonus)
+        } else if spawn_idx % 8 == 4 && room_has_glass {
+            spawn_saboteur_enemy_at(&mut commands, enemy_res, pos, true, health_multiplier, speed_bonus)
         } else {
-            spawn_enemy_at(&mut commands, enemy_res, pos, true, health_multiplier, speed_bonus);
-        }
+            spawn_enemy_at(&mut commands, enemy_res, pos, true, health_multiplier, speed_bonus)
+        };
+        crate::enemies::affix::maybe_make_elite(&mut commands, enemy, station_level, rooms_cleared, rng);
         actually_spawned += 1;
         spawn_idx += 1;
     }
@@ -495,19 +540,15 @@ pub fn generate_enemies_in_room(
     // numofenemies must equal what was actually spawned — a mismatch would permanently
     // lock the room if some tiles were rejected by the wall-adjacency filter.
     room.numofenemies = actually_spawned;
+    room.nests_remaining = nests_placed;
+    room.objective = if nests_placed > 0 { RoomObjective::DestroyNests } else { RoomObjective::KillAll };
 
     if actually_spawned == 0 {
         // info!("Room {}: all candidate tiles were adjacent to walls, cannot spawn.", index);
         return None;
     }
 
-    if let Some(s) = seed {
-        let mut seeded = StdRng::seed_from_u64(s);
-        valid_floors.shuffle(&mut seeded);
-    } else {
-        let mut trng = rand::rng();
-        valid_floors.shuffle(&mut trng);
-    }
+    valid_floors.shuffle(&mut **rng);
 
     let mut it = valid_floors.into_iter();
     let reward_tile = it.next()?;
@@ -698,14 +739,22 @@ pub fn apply_breach_forces_to_entities(
     }
 }
 
+/// Room air (percent) below which the player starts breathing from their tank.
+pub const LOW_AIR_PRESSURE: f32 = 20.0;
+
 pub fn damage_player_from_low_pressure(
     time: Res<Time>,
     rooms: Res<RoomVec>,
-    mut player: Query<(&Transform, &mut crate::player::Health, &mut crate::player::DamageTimer, &mut crate::player::AirTank), With<crate::player::Player>>,
+    mut player: Query<(Entity, &Transform, &mut crate::player::AirTank, Has<crate::items::AirBubble>), With<crate::player::Player>>,
+    mut statuses: EventWriter<crate::status::ApplyStatus>,
 ) {
-    let Ok((transform, mut health, mut damage_timer, mut tank)) = player.single_mut() else {
+    let Ok((player_entity, transform, mut tank, bubbled)) = player.single_mut() else {
         return;
     };
+    // An air bubble brings its own air, so the tank holds steady.
+    if bubbled {
+        return;
+    }
 
     let player_pos = transform.translation.truncate();
     let mut current_room: Option<&Room> = None;
@@ -721,26 +770,20 @@ pub fn damage_player_from_low_pressure(
         return;
     };
 
-    let pressure_threshold = 20.0;
-
-    if room.air_pressure < pressure_threshold {
+    if room.air_pressure < LOW_AIR_PRESSURE {
         // Low air: drain the tank
         tank.current = (tank.current - tank.drain_rate * time.delta_secs()).max(0.0);
 
-        // Only damage the player once the tank is fully depleted
+        // Only suffocate the player once the tank is fully depleted. The effect
+        // is refreshed every frame and lingers briefly after air returns. Zero
+        // stacks still starts it at one but keeps refreshes from stacking it up.
         if tank.current <= 0.0 {
-            damage_timer.tick(time.delta());
-
-            if damage_timer.finished() {
-                let damage = 5.0;
-                health.0 -= damage;
-                damage_timer.reset();
-
-                // debug!(
-                //     "Player taking pressure damage! Room pressure: {:.1}% - HP: {:.1}",
-                //     room.air_pressure, health.0
-                // );
-            }
+            statuses.write(crate::status::ApplyStatus {
+                target: player_entity,
+                kind: crate::status::StatusKind::Suffocation,
+                duration: 1.0,
+                stacks: 0,
+            });
         }
     }
 }
@@ -754,7 +797,7 @@ fn setup_air_pressure_ui(
     );
 
     commands.spawn((
-        Text::new("Air: 100%"),
+        Text::new(tr_args("hud.air", &[("pct", &100)])),
         TextFont {
             font: font.clone(),
             font_size: 24.0,
@@ -772,7 +815,7 @@ fn setup_air_pressure_ui(
     ));
 
     commands.spawn((
-        Text::new("Tank: 100%"),
+        Text::new(tr_args("hud.tank", &[("pct", &100)])),
         TextFont {
             font,
             font_size: 24.0,
@@ -813,7 +856,7 @@ fn update_air_pressure_ui(
         }
     }
 
-    **text = format!("Air: {:.0}%", current_pressure);
+    **text = tr_args("hud.air", &[("pct", &format!("{:.0}", current_pressure))]);
 
     color.0 = if current_pressure < 20.0 {
         Color::srgb(1.0, 0.0, 0.0)
@@ -843,7 +886,7 @@ fn update_air_tank_ui(
         .unwrap_or(false);
 
     let pct = (tank.current / tank.max_capacity * 100.0).clamp(0.0, 100.0);
-    **text = format!("Tank: {:.0}%", pct);
+    **text = tr_args("hud.tank", &[("pct", &format!("{:.0}", pct))]);
 
     color.0 = if tank.current <= 0.0 {
         Color::srgb(1.0, 0.1, 0.1)
@@ -855,3 +898,124 @@ fn update_air_tank_ui(
         Color::srgb(0.2, 1.0, 0.5)
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::{AirTank, Health};
+    use crate::status::{ApplyStatus, apply_status_events, tick_status_effects};
+    use std::time::Duration;
+
+    #[test]
+    fn empty_tank_suffocates_at_one_stack() {
+        let mut room = Room::new(Vec2::new(-100.0, 100.0), Vec2::new(100.0, -100.0), Vec2::ZERO, Vec2::ZERO, Vec::new());
+        room.air_pressure = 0.0;
+
+        let mut app = App::new();
+        app.add_event::<ApplyStatus>()
+            .add_event::<crate::run_history::StatEvent>()
+            .init_resource::<Time>()
+            .insert_resource(RoomVec(vec![room]))
+            .add_systems(Update, (damage_player_from_low_pressure, apply_status_events, tick_status_effects).chain());
+        let mut tank = AirTank::new(100.0, 10.0);
+        tank.current = 0.0;
+        let player = app.world_mut().spawn((Player, Transform::default(), tank, Health(100.0))).id();
+
+        for _ in 0..60 {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        }
+
+        let taken = 100.0 - app.world().get::<Health>(player).unwrap().0;
+        assert!((taken - 5.0).abs() < 0.01, "expected 5 damage over one second, took {taken}");
+    }
+
+    fn nest_room(nests: usize, enemies: usize) -> Room {
+        let mut room = Room::new(Vec2::new(-100.0, 100.0), Vec2::new(100.0, -100.0), Vec2::ZERO, Vec2::ZERO, Vec::new());
+        room.objective = RoomObjective::DestroyNests;
+        room.nests_remaining = nests;
+        room.numofenemies = enemies;
+        room
+    }
+
+    fn nest_app(room: Room) -> App {
+        let mut app = App::new();
+        app.add_event::<crate::enemies::EliteDeathEvent>()
+            .add_event::<crate::profile::RunEvent>()
+            .init_resource::<LastKillPos>()
+            .insert_resource(RoomVec(vec![room]))
+            .insert_resource(LevelState::InRoom(0, Vec3::ZERO, Vec3::ZERO))
+            .add_systems(Update, (
+                crate::enemies::nest::collapse_orphaned_spawns,
+                crate::enemies::check_enemy_health,
+            ).chain());
+        app
+    }
+
+    fn spawn_nest(app: &mut App, hp: f32) -> Entity {
+        app.world_mut().spawn((
+            crate::enemies::Enemy,
+            crate::enemies::Health(hp),
+            crate::enemies::MaxHealth(150.0),
+            crate::enemies::Nest {
+                room: 0,
+                wave_timer: Timer::from_seconds(1.0, TimerMode::Once),
+                waves_emitted: 1,
+                health_multiplier: 1.0,
+                speed_bonus: 0.0,
+            },
+            Transform::default(),
+        )).id()
+    }
+
+    fn spawn_enemy(app: &mut App, at: Vec2, nest: Option<Entity>) -> Entity {
+        let mut e = app.world_mut().spawn((
+            crate::enemies::Enemy,
+            crate::enemies::Health(50.0),
+            crate::enemies::MaxHealth(50.0),
+            Transform::from_translation(at.extend(0.0)),
+        ));
+        if let Some(nest) = nest {
+            e.insert(crate::enemies::nest::NestSpawn(nest));
+        }
+        e.id()
+    }
+
+    #[test]
+    fn destroyed_nest_takes_its_live_spawns_with_it() {
+        // The nest plus two of its spawns.
+        let mut app = nest_app(nest_room(1, 3));
+        let nest = spawn_nest(&mut app, 0.0);
+        let spawns = [
+            spawn_enemy(&mut app, Vec2::new(20.0, 0.0), Some(nest)),
+            spawn_enemy(&mut app, Vec2::new(-20.0, 0.0), Some(nest)),
+        ];
+
+        app.update();
+        let room = &app.world().resource::<RoomVec>().0[0];
+        assert_eq!(room.nests_remaining, 0);
+        assert_eq!(room.numofenemies, 2);
+
+        app.update();
+        assert_eq!(app.world().resource::<RoomVec>().0[0].numofenemies, 0);
+        assert!(spawns.iter().all(|&e| app.world().get_entity(e).is_err()));
+    }
+
+    #[test]
+    fn orphans_collapse_only_inside_the_room_after_the_last_nest() {
+        let mut app = nest_app(nest_room(1, 2));
+        let nest = spawn_nest(&mut app, 150.0);
+        let inside = spawn_enemy(&mut app, Vec2::new(20.0, 0.0), Some(nest));
+        let outside = spawn_enemy(&mut app, Vec2::new(500.0, 0.0), None);
+
+        app.update();
+        assert_eq!(app.world().get::<crate::enemies::Health>(inside).unwrap().0, 50.0);
+
+        app.world_mut().get_mut::<crate::enemies::Health>(nest).unwrap().0 = 0.0;
+        app.update();
+        app.update();
+        assert!(app.world().get_entity(inside).is_err());
+        assert_eq!(app.world().get::<crate::enemies::Health>(outside).unwrap().0, 50.0);
+        assert_eq!(app.world().resource::<RoomVec>().0[0].numofenemies, 0);
+    }
+}
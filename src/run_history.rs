@@ -0,0 +1,584 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::io::Write as _;
+use std::path::PathBuf;
+use crate::{
+    GameState, PlanetCount, PlanetLevelMarker, RunSeed, StationLevel, TestPlanetMode,
+    TestRoomMarker, FONT_PATH,
+};
+use crate::locale::{tr, tr_args};
+use crate::profile::{DeathCause, RunEvent};
+use crate::rewards::RewardKind;
+
+// ── Run history ──────────────────────────────────────────────────────────────
+//
+// Per-run stats collected while playing, shown on the end screens and appended
+// to runs.jsonl (one JSON record per line, next to config.ron) when the run
+// ends. The main menu's Records page browses that file.
+
+/// What a point of damage came from. Mechanisms rather than enemy types —
+/// a ranger's shot and a turret's shot both count as `EnemyShot`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, serde::Serialize, serde::Deserialize)]
+pub enum DamageSource {
+    Gun,
+    Beam,
+    Grenade,
+    Broom,
+    Table,
+    Status,
+    Contact,
+    EnemyShot,
+    Explosion,
+    Hazard,
+}
+
+impl DamageSource {
+    pub fn name(self) -> String {
+        tr(match self {
+            DamageSource::Gun       => "damage.gun",
+            DamageSource::Beam      => "damage.beam",
+            DamageSource::Grenade   => "damage.grenade",
+            DamageSource::Broom     => "damage.broom",
+            DamageSource::Table     => "damage.table",
+            DamageSource::Status    => "damage.status",
+            DamageSource::Contact   => "damage.contact",
+            DamageSource::EnemyShot => "damage.enemy_shot",
+            DamageSource::Explosion => "damage.explosion",
+            DamageSource::Hazard    => "damage.hazard",
+        })
+    }
+}
+
+/// Things that happen during a run that only matter to this run's summary.
+/// Lifetime counters go through `RunEvent` instead.
+#[derive(Event, Clone, Copy)]
+pub enum StatEvent {
+    DamageDealt(DamageSource, f32),
+    DamageTaken(DamageSource, f32),
+    RoomCleared,
+    RewardPicked(RewardKind),
+    WindowBroken,
+    WindowRepaired,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum LevelKind {
+    Station,
+    Planet,
+}
+
+/// Time spent on one station or planet.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+pub struct LevelTime {
+    pub kind: LevelKind,
+    /// 1-based, as shown to the player.
+    pub number: u32,
+    pub secs: f32,
+}
+
+impl LevelTime {
+    /// "Station 2", in the current language.
+    pub fn label(&self) -> String {
+        let key = match self.kind {
+            LevelKind::Station => "summary.level_station",
+            LevelKind::Planet  => "summary.level_planet",
+        };
+        tr_args(key, &[("n", &self.number)])
+    }
+}
+
+/// Stats for the run in progress. Carried in the run save so a resumed run
+/// keeps its history.
+#[derive(Resource, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct RunStats {
+    pub seed: u64,
+    /// Furthest station reached (0-based), as of the latest level start.
+    pub station_level: u32,
+    pub planets_cleared: u32,
+    pub levels: Vec<LevelTime>,
+    pub rooms_cleared: u32,
+    pub kills: u32,
+    pub damage_dealt: HashMap<DamageSource, f32>,
+    pub damage_taken: HashMap<DamageSource, f32>,
+    pub rewards: Vec<RewardKind>,
+    pub windows_broken: u32,
+    pub windows_repaired: u32,
+    pub death: Option<DeathCause>,
+}
+
+impl RunStats {
+    pub fn total_secs(&self) -> f32 {
+        self.levels.iter().map(|l| l.secs).sum()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub enum RunOutcome {
+    #[default]
+    Died,
+    /// Flew home from a win screen.
+    Left,
+}
+
+/// One line of runs.jsonl.
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct RunRecord {
+    /// Unix seconds when the run ended.
+    pub ended_at: u64,
+    pub outcome: RunOutcome,
+    pub stats: RunStats,
+}
+
+// ── Persistence ──────────────────────────────────────────────────────────────
+
+fn history_path() -> PathBuf {
+    crate::settings::config_dir().join("runs.jsonl")
+}
+
+/// Appends the finished run to runs.jsonl.
+pub fn finish_run(stats: &RunStats, outcome: RunOutcome) {
+    let record = RunRecord {
+        ended_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        outcome,
+        stats: stats.clone(),
+    };
+    let line = match serde_json::to_string(&record) {
+        Ok(line) => line,
+        Err(e) => {
+            warn!("Failed to serialize run record: {e}");
+            return;
+        }
+    };
+    let path = history_path();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut f| writeln!(f, "{line}"));
+    if let Err(e) = result {
+        warn!("Failed to append to run history '{}': {e}", path.display());
+    }
+}
+
+/// Every readable run in runs.jsonl, oldest first. Bad lines are skipped.
+pub fn load_history() -> Vec<RunRecord> {
+    let Ok(text) = std::fs::read_to_string(history_path()) else { return Vec::new() };
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                warn!("Skipping unreadable run record: {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+// ── Plugin ───────────────────────────────────────────────────────────────────
+
+pub struct RunHistoryPlugin;
+
+impl Plugin for RunHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<StatEvent>()
+            .init_resource::<RunStats>()
+            // PostUpdate so a death recorded this frame is in the stats before
+            // the GameOver screen is built at the start of the next.
+            .add_systems(PostUpdate, record_stats)
+            .add_systems(OnEnter(GameState::Playing), start_level_timer)
+            .add_systems(
+                Update,
+                tick_level_timer
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (record_death, spawn_run_summary).chain(),
+            )
+            .add_systems(OnEnter(GameState::Win), spawn_run_summary)
+            .add_systems(OnEnter(GameState::PlanetWin), spawn_run_summary)
+            .add_systems(OnExit(GameState::GameOver), cleanup_run_summary)
+            .add_systems(OnExit(GameState::Win), cleanup_run_summary)
+            .add_systems(OnExit(GameState::PlanetWin), cleanup_run_summary)
+            .add_systems(Update, handle_records_buttons.run_if(resource_exists::<RecordsView>))
+            .add_systems(Update, sync_records_page.run_if(resource_exists::<RecordsView>));
+    }
+}
+
+fn record_stats(
+    mut stats: ResMut<RunStats>,
+    mut events: EventReader<StatEvent>,
+    mut run_events: EventReader<RunEvent>,
+) {
+    for ev in run_events.read() {
+        match *ev {
+            RunEvent::RunStarted => *stats = RunStats::default(),
+            RunEvent::EnemyKilled(_) => stats.kills += 1,
+            RunEvent::Died(cause) => stats.death = Some(cause),
+            _ => {}
+        }
+    }
+    for ev in events.read() {
+        match *ev {
+            StatEvent::DamageDealt(source, amount) => *stats.damage_dealt.entry(source).or_default() += amount,
+            StatEvent::DamageTaken(source, amount) => *stats.damage_taken.entry(source).or_default() += amount,
+            StatEvent::RoomCleared => stats.rooms_cleared += 1,
+            StatEvent::RewardPicked(kind) => stats.rewards.push(kind),
+            StatEvent::WindowBroken => stats.windows_broken += 1,
+            StatEvent::WindowRepaired => stats.windows_repaired += 1,
+        }
+    }
+}
+
+fn start_level_timer(
+    mut stats: ResMut<RunStats>,
+    seed: Res<RunSeed>,
+    station_level: Res<StationLevel>,
+    planet_count: Res<PlanetCount>,
+    planet: Option<Res<PlanetLevelMarker>>,
+) {
+    stats.seed = seed.0;
+    stats.station_level = station_level.0;
+    stats.planets_cleared = planet_count.0;
+    let (kind, number) = if planet.is_some() {
+        (LevelKind::Planet, planet_count.0 + 1)
+    } else {
+        (LevelKind::Station, station_level.0 + 1)
+    };
+    stats.levels.push(LevelTime { kind, number, secs: 0.0 });
+}
+
+fn tick_level_timer(time: Res<Time>, mut stats: ResMut<RunStats>) {
+    if let Some(level) = stats.levels.last_mut() {
+        level.secs += time.delta_secs();
+    }
+}
+
+fn record_death(
+    stats: Res<RunStats>,
+    test_room: Option<Res<TestRoomMarker>>,
+    test_planet: Option<Res<TestPlanetMode>>,
+    replaying: Option<Res<crate::replay::ReplayPlayback>>,
+) {
+    if test_room.is_some() || test_planet.is_some() || replaying.is_some() { return; }
+    finish_run(&stats, RunOutcome::Died);
+}
+
+// ── Summary text ─────────────────────────────────────────────────────────────
+
+fn format_time(secs: f32) -> String {
+    let secs = secs.max(0.0) as u32;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// "Gun 120 · Broom 40", biggest first.
+fn format_damage(by_source: &HashMap<DamageSource, f32>) -> String {
+    let mut parts: Vec<(DamageSource, f32)> = by_source.iter().map(|(&s, &d)| (s, d)).filter(|&(_, d)| d >= 0.5).collect();
+    if parts.is_empty() {
+        return tr("summary.none");
+    }
+    parts.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let total: f32 = parts.iter().map(|p| p.1).sum();
+    let detail: Vec<String> = parts.iter().map(|(s, d)| format!("{} {}", s.name(), d.round() as u32)).collect();
+    format!("{}  ({})", total.round() as u32, detail.join(" · "))
+}
+
+/// "Max HP x2, Armor", in the order first picked.
+fn format_rewards(rewards: &[RewardKind]) -> String {
+    if rewards.is_empty() {
+        return tr("summary.none");
+    }
+    let mut order: Vec<RewardKind> = Vec::new();
+    let mut counts: HashMap<RewardKind, u32> = HashMap::new();
+    for &kind in rewards {
+        if !counts.contains_key(&kind) {
+            order.push(kind);
+        }
+        *counts.entry(kind).or_default() += 1;
+    }
+    order.iter()
+        .map(|k| match counts[k] {
+            1 => k.name(),
+            n => tr_args("summary.reward_count", &[("name", &k.name()), ("n", &n)]),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Lines shared by the end-screen panel and the Records page.
+pub fn summary_lines(stats: &RunStats) -> Vec<String> {
+    let mut lines = vec![
+        tr_args("summary.seed", &[("seed", &format!("{:016X}", stats.seed))]),
+        tr_args("summary.total_time", &[("time", &format_time(stats.total_secs()))]),
+    ];
+    for level in &stats.levels {
+        lines.push(format!("   {}: {}", level.label(), format_time(level.secs)));
+    }
+    lines.push(tr_args("summary.rooms", &[("n", &stats.rooms_cleared)]));
+    lines.push(tr_args("summary.kills", &[("n", &stats.kills)]));
+    lines.push(tr_args("summary.damage_dealt", &[("damage", &format_damage(&stats.damage_dealt))]));
+    lines.push(tr_args("summary.damage_taken", &[("damage", &format_damage(&stats.damage_taken))]));
+    lines.push(tr_args("summary.rewards", &[("rewards", &format_rewards(&stats.rewards))]));
+    lines.push(tr_args("summary.windows", &[("broken", &stats.windows_broken), ("repaired", &stats.windows_repaired)]));
+    if let Some(cause) = stats.death {
+        lines.push(tr_args("summary.cause", &[("cause", &cause.name())]));
+    }
+    lines
+}
+
+fn format_age(ended_at: u64) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let ago = now.saturating_sub(ended_at);
+    match ago {
+        0..60 => tr("records.age.now"),
+        60..3600 => tr_args("records.age.minutes", &[("n", &(ago / 60))]),
+        3600..86400 => tr_args("records.age.hours", &[("n", &(ago / 3600))]),
+        _ => tr_args("records.age.days", &[("n", &(ago / 86400))]),
+    }
+}
+
+fn record_heading(record: &RunRecord) -> String {
+    let station = record.stats.station_level + 1;
+    let age = format_age(record.ended_at);
+    match record.outcome {
+        RunOutcome::Died => tr_args("records.died", &[("station", &station), ("age", &age)]),
+        RunOutcome::Left => tr_args("records.left", &[("station", &station), ("age", &age)]),
+    }
+}
+
+// ── End-screen summary panel ─────────────────────────────────────────────────
+
+#[derive(Component)]
+struct RunSummaryUI;
+
+fn spawn_run_summary(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    stats: Res<RunStats>,
+    state: Res<State<GameState>>,
+) {
+    let font: Handle<Font> = asset_server.load(FONT_PATH);
+    let title = tr(if *state.get() == GameState::GameOver { "summary.title" } else { "summary.title_so_far" });
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(24.0),
+            top: Val::Px(80.0),
+            width: Val::Px(420.0),
+            padding: UiRect::all(Val::Px(14.0)),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        BorderRadius::all(Val::Px(6.0)),
+        ZIndex(25),
+        RunSummaryUI,
+    ))
+    .with_children(|panel| {
+        panel.spawn((
+            Text::new(title),
+            TextFont { font: font.clone(), font_size: 22.0, ..default() },
+            TextColor(Color::srgb(1.0, 1.0, 0.5)),
+        ));
+        for line in summary_lines(&stats) {
+            panel.spawn((
+                Text::new(line),
+                TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+        }
+    });
+}
+
+fn cleanup_run_summary(mut commands: Commands, q: Query<Entity, With<RunSummaryUI>>) {
+    for e in &q {
+        commands.entity(e).despawn();
+    }
+}
+
+// ── Records page ─────────────────────────────────────────────────────────────
+
+/// Present while the Records overlay is open. `index` counts back from the
+/// most recent run.
+#[derive(Resource)]
+pub struct RecordsView {
+    runs: Vec<RunRecord>,
+    index: usize,
+}
+
+#[derive(Component)]
+struct RecordsUI;
+
+#[derive(Component)]
+struct RecordsHeading;
+
+#[derive(Component)]
+struct RecordsBody;
+
+#[derive(Component)]
+enum RecordsButton {
+    Prev,
+    Next,
+    Back,
+}
+
+pub fn open_records(commands: &mut Commands, assets: &AssetServer) {
+    let mut runs = load_history();
+    runs.reverse();
+    commands.insert_resource(RecordsView { runs, index: 0 });
+
+    let font: Handle<Font> = assets.load(FONT_PATH);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.55)),
+            ZIndex(300),
+            RecordsUI,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    width: Val::Px(640.0),
+                    padding: UiRect::all(Val::Px(28.0)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(14.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.05, 0.05, 0.14, 0.97)),
+                BorderColor(Color::srgba(0.3, 0.3, 0.7, 0.8)),
+                BorderRadius::all(Val::Px(10.0)),
+            ))
+            .with_children(|panel| {
+                panel.spawn((
+                    Text::new(tr("records.title")),
+                    TextFont { font: font.clone(), font_size: 34.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+                panel.spawn((
+                    Text::new(""),
+                    TextFont { font: font.clone(), font_size: 20.0, ..default() },
+                    TextColor(Color::srgb(1.0, 1.0, 0.5)),
+                    RecordsHeading,
+                ));
+                panel.spawn((
+                    Node { width: Val::Percent(100.0), ..default() },
+                    Text::new(""),
+                    TextFont { font: font.clone(), font_size: 15.0, ..default() },
+                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                    RecordsBody,
+                ));
+
+                panel
+                    .spawn((Node {
+                        column_gap: Val::Px(12.0),
+                        margin: UiRect { top: Val::Px(8.0), ..default() },
+                        ..default()
+                    },))
+                    .with_children(|row| {
+                        spawn_records_button(row, font.clone(), "records.newer", RecordsButton::Prev);
+                        spawn_records_button(row, font.clone(), "common.back", RecordsButton::Back);
+                        spawn_records_button(row, font.clone(), "records.older", RecordsButton::Next);
+                    });
+            });
+        });
+}
+
+fn spawn_records_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, label: &str, button: RecordsButton) {
+    let bg = match button {
+        RecordsButton::Back => Color::srgba(0.25, 0.1, 0.1, 0.9),
+        _ => Color::srgba(0.1, 0.2, 0.35, 0.9),
+    };
+    parent
+        .spawn((
+            Button,
+            button,
+            Node {
+                width: Val::Px(160.0),
+                height: Val::Px(46.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(bg),
+            BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.3)),
+            BorderRadius::all(Val::Px(6.0)),
+        ))
+        .with_children(|b| {
+            b.spawn((
+                Text::new(tr(label)),
+                TextFont { font, font_size: 22.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+fn handle_records_buttons(
+    mut commands: Commands,
+    mut view: ResMut<RecordsView>,
+    interactions: Query<(&Interaction, &RecordsButton), (Changed<Interaction>, With<Button>)>,
+    ui_q: Query<Entity, With<RecordsUI>>,
+) {
+    for (interaction, button) in &interactions {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            RecordsButton::Prev => view.index = view.index.saturating_sub(1),
+            RecordsButton::Next => view.index = (view.index + 1).min(view.runs.len().saturating_sub(1)),
+            RecordsButton::Back => {
+                commands.remove_resource::<RecordsView>();
+                for e in &ui_q {
+                    commands.entity(e).despawn();
+                }
+            }
+        }
+    }
+}
+
+fn sync_records_page(
+    view: Res<RecordsView>,
+    mut heading_q: Query<&mut Text, (With<RecordsHeading>, Without<RecordsBody>)>,
+    mut body_q: Query<&mut Text, (With<RecordsBody>, Without<RecordsHeading>)>,
+) {
+    if !view.is_changed() { return; }
+
+    let (heading, body) = match view.runs.get(view.index) {
+        Some(record) => (
+            tr_args("records.heading", &[
+                ("n", &(view.runs.len() - view.index)),
+                ("total", &view.runs.len()),
+                ("run", &record_heading(record)),
+            ]),
+            summary_lines(&record.stats).join("\n"),
+        ),
+        None => (tr("records.empty"), String::new()),
+    };
+    if let Ok(mut text) = heading_q.single_mut() {
+        text.0 = heading;
+    }
+    if let Ok(mut text) = body_q.single_mut() {
+        text.0 = body;
+    }
+}
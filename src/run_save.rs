@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use std::path::PathBuf;
+use crate::{
+    GameState, PlanetCount, PlanetLevelMarker, RunSeed, SavedPlayerBuffs, SelectedWeapon,
+    StationLevel, TestPlanetMode, TestRoomMarker,
+};
+use crate::replay::ReplayPlayback;
+use crate::run_history::RunStats;
+
+// ── Run save ─────────────────────────────────────────────────────────────────
+//
+// Written to run.ron next to config.ron each time a station or planet starts
+// loading, so quitting mid-run resumes from the start of that level. Dying or
+// starting a new run deletes it.
+
+/// Bump whenever `RunSave` or anything it contains changes shape.
+/// Saves with any other version are ignored rather than half-loaded.
+pub const SAVE_VERSION: u32 = 1;
+
+/// Everything needed to rebuild a run at the start of a level.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct RunSave {
+    pub version: u32,
+    pub station_level: u32,
+    pub planet_count: u32,
+    /// The saved level is a planet rather than a station.
+    pub on_planet: bool,
+    pub seed: u64,
+    /// Starting weapon, used when no buffs have been saved yet.
+    pub weapon: String,
+    /// `None` on the first station, before anything has been carried over.
+    pub buffs: Option<SavedPlayerBuffs>,
+    /// Summary stats so far. Missing from older saves, which start a fresh tally.
+    #[serde(default)]
+    pub stats: RunStats,
+}
+
+/// Just the version, read first so a mismatched save is reported cleanly
+/// instead of as a parse error on whichever field changed.
+#[derive(serde::Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+pub struct RunSavePlugin;
+
+impl Plugin for RunSavePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(
+                OnEnter(GameState::Loading),
+                write_run_save
+                    .run_if(not(resource_exists::<TestRoomMarker>))
+                    .run_if(not(resource_exists::<TestPlanetMode>))
+                    .run_if(not(resource_exists::<ReplayPlayback>)),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                delete_run_save.run_if(not(resource_exists::<ReplayPlayback>)),
+            );
+    }
+}
+
+fn save_path() -> PathBuf {
+    crate::settings::config_dir().join("run.ron")
+}
+
+/// Reads the run save, if there is a usable one.
+pub fn load_run_save() -> Option<RunSave> {
+    let text = std::fs::read_to_string(save_path()).ok()?;
+
+    let header: SaveHeader = match ron::from_str(&text) {
+        Ok(h) => h,
+        Err(e) => {
+            warn!("Ignoring unreadable run save: {e}");
+            return None;
+        }
+    };
+    if header.version != SAVE_VERSION {
+        warn!("Ignoring run save from version {} (expected {})", header.version, SAVE_VERSION);
+        return None;
+    }
+
+    match ron::from_str(&text) {
+        Ok(save) => Some(save),
+        Err(e) => {
+            warn!("Ignoring unreadable run save: {e}");
+            None
+        }
+    }
+}
+
+pub fn delete_run_save() {
+    let path = save_path();
+    if path.exists() {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+fn write_run_save(
+    station_level: Res<StationLevel>,
+    planet_count: Res<PlanetCount>,
+    planet: Option<Res<PlanetLevelMarker>>,
+    seed: Res<RunSeed>,
+    weapon: Res<SelectedWeapon>,
+    buffs: Option<Res<SavedPlayerBuffs>>,
+    stats: Res<RunStats>,
+) {
+    let save = RunSave {
+        version: SAVE_VERSION,
+        station_level: station_level.0,
+        planet_count: planet_count.0,
+        on_planet: planet.is_some(),
+        seed: seed.0,
+        weapon: weapon.0.clone(),
+        buffs: buffs.map(|b| b.clone()),
+        stats: stats.clone(),
+    };
+    match ron::to_string(&save) {
+        Ok(s) => {
+            let path = save_path();
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            if let Err(e) = std::fs::write(path, s) {
+                warn!("Failed to write run save: {e}");
+            }
+        }
+        Err(e) => warn!("Failed to serialize run save: {e}"),
+    }
+}
+
+/// Puts a loaded save back into the world. Enter `GameState::Loading` afterwards.
+pub fn resume_run(commands: &mut Commands, save: RunSave) {
+    commands.insert_resource(StationLevel(save.station_level));
+    commands.insert_resource(PlanetCount(save.planet_count));
+    commands.insert_resource(RunSeed(save.seed));
+    commands.insert_resource(SelectedWeapon(save.weapon));
+    commands.insert_resource(save.stats);
+    match save.buffs {
+        Some(buffs) => commands.insert_resource(buffs),
+        None => commands.remove_resource::<SavedPlayerBuffs>(),
+    }
+    if save.on_planet {
+        commands.insert_resource(PlanetLevelMarker);
+    } else {
+        commands.remove_resource::<PlanetLevelMarker>();
+    }
+}
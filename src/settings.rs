@@ -2,7 +2,8 @@ use bevy::prelude::*;
 use bevy::audio::Volume;
 use bevy::window::{WindowMode, PrimaryWindow, MonitorSelection, VideoModeSelection};
 use crate::{GameMusicVolume, MusicTrack, FONT_PATH};
-use std::path::PathBuf;
+use crate::locale::{loc_text, tr, Language, Locales, FALLBACK_LOCALE};
+use std::path::{Path, PathBuf};
 
 pub struct SettingsPlugin;
 
@@ -26,12 +27,12 @@ pub enum GameWindowMode {
 }
 
 impl GameWindowMode {
-    pub fn label(self) -> &'static str {
-        match self {
-            Self::Windowed => "Windowed",
-            Self::BorderlessFullscreen => "Borderless FS",
-            Self::Fullscreen => "Fullscreen",
-        }
+    pub fn label(self) -> String {
+        tr(match self {
+            Self::Windowed => "settings.window_mode.windowed",
+            Self::BorderlessFullscreen => "settings.window_mode.borderless",
+            Self::Fullscreen => "settings.window_mode.fullscreen",
+        })
     }
 
     fn next(self) -> Self {
@@ -59,6 +60,242 @@ impl GameWindowMode {
     }
 }
 
+// ── Corner map ────────────────────────────────────────────────────────────────
+
+/// Where the always-on minimap sits during play, if anywhere.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub enum MapCorner {
+    Off,
+    #[default]
+    TopRight,
+    BottomRight,
+    BottomLeft,
+    TopLeft,
+}
+
+impl MapCorner {
+    const ALL: [MapCorner; 5] = [Self::Off, Self::TopRight, Self::BottomRight, Self::BottomLeft, Self::TopLeft];
+
+    pub fn label(self) -> String {
+        tr(match self {
+            Self::Off => "settings.map_corner.off",
+            Self::TopRight => "settings.map_corner.top_right",
+            Self::BottomRight => "settings.map_corner.bottom_right",
+            Self::BottomLeft => "settings.map_corner.bottom_left",
+            Self::TopLeft => "settings.map_corner.top_left",
+        })
+    }
+
+    fn step(self, by: isize) -> Self {
+        let i = Self::ALL.iter().position(|c| *c == self).unwrap_or(0) as isize;
+        Self::ALL[(i + by).rem_euclid(Self::ALL.len() as isize) as usize]
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub enum MapSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl MapSize {
+    pub fn px(self) -> f32 {
+        match self {
+            Self::Small => 140.0,
+            Self::Medium => 190.0,
+            Self::Large => 250.0,
+        }
+    }
+
+    pub fn label(self) -> String {
+        tr(match self {
+            Self::Small => "settings.map_size.small",
+            Self::Medium => "settings.map_size.medium",
+            Self::Large => "settings.map_size.large",
+        })
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::Small => Self::Medium,
+            Self::Medium => Self::Large,
+            Self::Large => Self::Small,
+        }
+    }
+
+    fn prev(self) -> Self {
+        match self {
+            Self::Small => Self::Large,
+            Self::Medium => Self::Small,
+            Self::Large => Self::Medium,
+        }
+    }
+}
+
+/// Options for the corner minimap drawn by `minimap.rs` during play.
+#[derive(Resource, Clone, Copy, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct CornerMapSettings {
+    pub corner: MapCorner,
+    pub size: MapSize,
+    /// 0.3..=1.0
+    pub opacity: f32,
+    /// Turn the map so the aim direction always points up.
+    pub rotating: bool,
+}
+
+impl Default for CornerMapSettings {
+    fn default() -> Self {
+        Self { corner: MapCorner::default(), size: MapSize::default(), opacity: 0.8, rotating: false }
+    }
+}
+
+// ── Accessibility ─────────────────────────────────────────────────────────────
+
+/// Palette used for the RED/GRN/BLU/YLW station colour puzzle.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub enum ColorVision {
+    #[default]
+    Normal,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl ColorVision {
+    const ALL: [ColorVision; 4] = [Self::Normal, Self::Deuteranopia, Self::Protanopia, Self::Tritanopia];
+
+    pub fn label(self) -> String {
+        tr(match self {
+            Self::Normal => "settings.color_vision.normal",
+            Self::Deuteranopia => "settings.color_vision.deuteranopia",
+            Self::Protanopia => "settings.color_vision.protanopia",
+            Self::Tritanopia => "settings.color_vision.tritanopia",
+        })
+    }
+
+    fn step(self, by: isize) -> Self {
+        let i = Self::ALL.iter().position(|c| *c == self).unwrap_or(0) as isize;
+        Self::ALL[(i + by).rem_euclid(Self::ALL.len() as isize) as usize]
+    }
+}
+
+/// Colours the player can pick for the health bar ends.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum HudColor {
+    Green,
+    Cyan,
+    Blue,
+    White,
+    Yellow,
+    Orange,
+    Red,
+    Magenta,
+}
+
+impl HudColor {
+    const ALL: [HudColor; 8] = [
+        Self::Green, Self::Cyan, Self::Blue, Self::White,
+        Self::Yellow, Self::Orange, Self::Red, Self::Magenta,
+    ];
+
+    pub fn label(self) -> String {
+        tr(match self {
+            Self::Green => "settings.hud_color.green",
+            Self::Cyan => "settings.hud_color.cyan",
+            Self::Blue => "settings.hud_color.blue",
+            Self::White => "settings.hud_color.white",
+            Self::Yellow => "settings.hud_color.yellow",
+            Self::Orange => "settings.hud_color.orange",
+            Self::Red => "settings.hud_color.red",
+            Self::Magenta => "settings.hud_color.magenta",
+        })
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Self::Green => Color::srgb(0.0, 1.0, 0.0),
+            Self::Cyan => Color::srgb(0.2, 0.85, 1.0),
+            Self::Blue => Color::srgb(0.2, 0.4, 1.0),
+            Self::White => Color::srgb(0.95, 0.95, 0.95),
+            Self::Yellow => Color::srgb(1.0, 0.9, 0.1),
+            Self::Orange => Color::srgb(1.0, 0.55, 0.0),
+            Self::Red => Color::srgb(1.0, 0.0, 0.0),
+            Self::Magenta => Color::srgb(0.9, 0.2, 0.9),
+        }
+    }
+
+    fn step(self, by: isize) -> Self {
+        let i = Self::ALL.iter().position(|c| *c == self).unwrap_or(0) as isize;
+        Self::ALL[(i + by).rem_euclid(Self::ALL.len() as isize) as usize]
+    }
+}
+
+/// Shapes drawn next to puzzle colours so they never rely on hue alone.
+/// Index matches `StationColors` values: 0=RED 1=GRN 2=BLU 3=YLW.
+pub const COLOR_GLYPHS: [&str; 4] = ["●", "▲", "■", "◆"];
+
+/// Colour-blind palettes, glyph overlays and HUD colours.
+#[derive(Resource, Clone, Copy, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    pub color_vision: ColorVision,
+    /// Draw a shape on colour chips and terminal slots.
+    pub glyphs: bool,
+    pub health_full: HudColor,
+    pub health_low: HudColor,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            color_vision: ColorVision::Normal,
+            glyphs: false,
+            health_full: HudColor::Green,
+            health_low: HudColor::Red,
+        }
+    }
+}
+
+impl AccessibilitySettings {
+    /// On-screen colour for a station colour value (0=RED 1=GRN 2=BLU 3=YLW).
+    /// The colour-blind palettes move the four hues apart along the axes each
+    /// condition can still see, and vary brightness as a second cue.
+    pub fn puzzle_color(&self, value: u8) -> Color {
+        let [r, g, b] = match (self.color_vision, value) {
+            (ColorVision::Normal, 0) => [1.0, 0.25, 0.25],
+            (ColorVision::Normal, 1) => [0.2, 0.9, 0.3],
+            (ColorVision::Normal, 2) => [0.3, 0.5, 1.0],
+            (ColorVision::Normal, _) => [1.0, 0.9, 0.2],
+            (ColorVision::Deuteranopia, 0) => [0.84, 0.37, 0.0],
+            (ColorVision::Deuteranopia, 1) => [0.35, 0.7, 0.9],
+            (ColorVision::Deuteranopia, 2) => [0.0, 0.27, 0.7],
+            (ColorVision::Deuteranopia, _) => [0.94, 0.89, 0.26],
+            (ColorVision::Protanopia, 0) => [0.9, 0.55, 0.0],
+            (ColorVision::Protanopia, 1) => [0.35, 0.7, 0.9],
+            (ColorVision::Protanopia, 2) => [0.0, 0.27, 0.7],
+            (ColorVision::Protanopia, _) => [0.97, 0.95, 0.6],
+            (ColorVision::Tritanopia, 0) => [0.86, 0.15, 0.3],
+            (ColorVision::Tritanopia, 1) => [0.0, 0.6, 0.55],
+            (ColorVision::Tritanopia, 2) => [0.15, 0.2, 0.5],
+            (ColorVision::Tritanopia, _) => [1.0, 0.75, 0.8],
+        };
+        Color::srgb(r, g, b)
+    }
+
+    /// The glyph for a colour value, or nothing when glyphs are off.
+    pub fn puzzle_glyph(&self, value: u8) -> Option<&'static str> {
+        self.glyphs.then(|| COLOR_GLYPHS[value as usize % COLOR_GLYPHS.len()])
+    }
+
+    /// Health bar colour for a fill ratio in 0..=1.
+    pub fn health_color(&self, ratio: f32) -> Color {
+        self.health_low.color().mix(&self.health_full.color(), ratio.clamp(0.0, 1.0))
+    }
+}
+
 // ── KeyBindings ───────────────────────────────────────────────────────────────
 
 /// A binding that can be either a keyboard key or a mouse button.
@@ -96,7 +333,9 @@ impl InputBinding {
 
 /// All remappable player actions. Each field stores the KeyCode currently bound to that action.
 /// Serialized into config.ron so bindings persist between sessions.
+/// Missing fields fall back to their defaults so older configs keep loading.
 #[derive(Resource, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct KeyBindings {
     pub move_left:   KeyCode,
     pub move_right:  KeyCode,
@@ -108,6 +347,10 @@ pub struct KeyBindings {
     pub inventory:   KeyCode,
     pub toggle_music: KeyCode,
     pub pause:        KeyCode,
+    pub active_item:  KeyCode,
+    pub hotbar_1:     KeyCode,
+    pub hotbar_2:     KeyCode,
+    pub hotbar_3:     KeyCode,
     pub shoot: InputBinding,
     pub broom: InputBinding,
 }
@@ -125,6 +368,10 @@ impl Default for KeyBindings {
             inventory:    KeyCode::Tab,
             toggle_music: KeyCode::KeyM,
             pause:        KeyCode::Escape,
+            active_item:  KeyCode::KeyF,
+            hotbar_1:     KeyCode::Digit1,
+            hotbar_2:     KeyCode::Digit2,
+            hotbar_3:     KeyCode::Digit3,
             shoot: InputBinding::Mouse(MouseButton::Left),
             broom: InputBinding::Mouse(MouseButton::Right),
         }
@@ -145,6 +392,10 @@ impl KeyBindings {
             BindableAction::Inventory   => self.inventory,
             BindableAction::ToggleMusic => self.toggle_music,
             BindableAction::Pause       => self.pause,
+            BindableAction::ActiveItem  => self.active_item,
+            BindableAction::Hotbar1     => self.hotbar_1,
+            BindableAction::Hotbar2     => self.hotbar_2,
+            BindableAction::Hotbar3     => self.hotbar_3,
             BindableAction::Shoot | BindableAction::Broom => unreachable!("use binding_for() for Shoot/Broom"),
         }
     }
@@ -162,6 +413,10 @@ impl KeyBindings {
             BindableAction::Inventory   => self.inventory    = key,
             BindableAction::ToggleMusic => self.toggle_music = key,
             BindableAction::Pause       => self.pause        = key,
+            BindableAction::ActiveItem  => self.active_item  = key,
+            BindableAction::Hotbar1     => self.hotbar_1     = key,
+            BindableAction::Hotbar2     => self.hotbar_2     = key,
+            BindableAction::Hotbar3     => self.hotbar_3     = key,
             BindableAction::Shoot | BindableAction::Broom => {}
         }
     }
@@ -246,24 +501,29 @@ pub enum BindableAction {
     MoveLeft, MoveRight, MoveUp, MoveDown,
     Dash, SwapWeapon, Interact,
     Inventory, ToggleMusic, Pause,
+    ActiveItem, Hotbar1, Hotbar2, Hotbar3,
 }
 
 impl BindableAction {
-    fn label(self) -> &'static str {
-        match self {
-            Self::Shoot       => "Shoot",
-            Self::Broom       => "Broom",
-            Self::MoveLeft    => "Move Left",
-            Self::MoveRight   => "Move Right",
-            Self::MoveUp      => "Move Up",
-            Self::MoveDown    => "Move Down",
-            Self::Dash        => "Dash",
-            Self::SwapWeapon  => "Swap Weapon",
-            Self::Interact    => "Interact",
-            Self::Inventory   => "Inventory",
-            Self::ToggleMusic => "Toggle Music",
-            Self::Pause       => "Pause",
-        }
+    fn label(self) -> String {
+        tr(match self {
+            Self::Shoot       => "action.shoot",
+            Self::Broom       => "action.broom",
+            Self::MoveLeft    => "action.move_left",
+            Self::MoveRight   => "action.move_right",
+            Self::MoveUp      => "action.move_up",
+            Self::MoveDown    => "action.move_down",
+            Self::Dash        => "action.dash",
+            Self::SwapWeapon  => "action.swap_weapon",
+            Self::Interact    => "action.interact",
+            Self::Inventory   => "action.inventory",
+            Self::ToggleMusic => "action.toggle_music",
+            Self::Pause       => "action.pause",
+            Self::ActiveItem  => "action.active_item",
+            Self::Hotbar1     => "action.hotbar1",
+            Self::Hotbar2     => "action.hotbar2",
+            Self::Hotbar3     => "action.hotbar3",
+        })
     }
 }
 
@@ -280,8 +540,46 @@ pub struct BindingState {
 #[derive(Component)] pub struct BindingButton(pub BindableAction);
 #[derive(Component)] pub struct BindingLabel(pub BindableAction);
 #[derive(Component)] struct ControlsBackButton;
+#[derive(Component)] pub struct AccessibilityUI;
 #[derive(Component)] struct VolumeDisplay;
 #[derive(Component)] struct WindowModeDisplay;
+#[derive(Component)] struct LanguageDisplay;
+
+#[derive(Component)]
+enum CornerMapDisplay {
+    Corner,
+    Size,
+    Opacity,
+    Mode,
+}
+
+#[derive(Component)]
+enum AccessibilityDisplay {
+    Vision,
+    Glyphs,
+    HealthFull,
+    HealthLow,
+}
+
+#[derive(Component)]
+enum AccessibilityButton {
+    VisionLeft,
+    VisionRight,
+    GlyphsToggle,
+    HealthFullLeft,
+    HealthFullRight,
+    HealthLowLeft,
+    HealthLowRight,
+    Back,
+}
+
+/// Preview of one puzzle colour on the accessibility page.
+#[derive(Component)]
+struct PaletteSwatch(u8);
+
+/// Preview of the health bar at a fixed fill ratio.
+#[derive(Component)]
+struct HealthSwatch(f32);
 
 #[derive(Component)]
 enum SettingsButton {
@@ -289,87 +587,381 @@ enum SettingsButton {
     VolumeUp,
     WindowModeLeft,
     WindowModeRight,
+    LanguageLeft,
+    LanguageRight,
+    MapCornerLeft,
+    MapCornerRight,
+    MapSizeLeft,
+    MapSizeRight,
+    MapOpacityDown,
+    MapOpacityUp,
+    MapModeToggle,
     Controls,
+    Accessibility,
     Back,
 }
 
 // ── Config + persistence ──────────────────────────────────────────────────────
+//
+// Settings are split across two files in config_dir():
+//   config.ron   — version, volume, window mode, corner map, accessibility and language
+//   bindings.ron — version and key bindings
+// Each file is read on its own, so a bad bindings file resets only the
+// bindings. An unreadable file is copied to `<name>.bak` before defaults are
+// used, and the reason is shown as a toast on the main menu.
+
+/// Bump when either settings file changes shape, and add a step to `migrate_config`.
+pub const CONFIG_VERSION: u32 = 2;
+
+/// Files written before versioning have no `version` field; that layout is version 1.
+fn legacy_version() -> u32 { 1 }
+
+/// Just the version, read first so the rest can be parsed with the right layout.
+#[derive(serde::Deserialize)]
+struct ConfigHeader {
+    #[serde(default = "legacy_version")]
+    version: u32,
+}
 
-#[derive(serde::Serialize, serde::Deserialize)]
-struct Config {
+// Version 1 kept everything in one config.ron. Each section is read from it
+// on its own, with serde skipping the other fields, so one bad value resets
+// only that setting.
+
+#[derive(serde::Deserialize)]
+struct V1Volume {
     volume: f32,
+}
+
+#[derive(serde::Deserialize)]
+struct V1WindowMode {
     window_mode_index: u8,
+}
+
+#[derive(serde::Deserialize)]
+struct V1KeyBindings {
     #[serde(default)]
     key_bindings: KeyBindings,
+}
+
+/// Old home of the Beam Rifle unlock, now in profile.ron.
+#[derive(serde::Deserialize)]
+struct V1BeamRifle {
     #[serde(default)]
     beam_rifle_unlocked: bool,
 }
 
+/// config.ron from version 2 on.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Config {
+    version: u32,
+    volume: f32,
+    window_mode_index: u8,
+    #[serde(default)]
+    corner_map: CornerMapSettings,
+    #[serde(default)]
+    accessibility: AccessibilitySettings,
+    #[serde(default = "default_language")]
+    language: String,
+}
+
+fn default_language() -> String { FALLBACK_LOCALE.to_string() }
+
 impl Default for Config {
     fn default() -> Self {
-        Self { volume: 0.5, window_mode_index: 1, key_bindings: KeyBindings::default(), beam_rifle_unlocked: false }
+        Self {
+            version: CONFIG_VERSION,
+            volume: 0.5,
+            window_mode_index: 1,
+            corner_map: CornerMapSettings::default(),
+            accessibility: AccessibilitySettings::default(),
+            language: default_language(),
+        }
     }
 }
 
-fn load_config_raw() -> Config {
-    let path = config_path();
-    std::fs::read_to_string(&path)
-        .ok()
-        .and_then(|s| ron::from_str(&s).ok())
-        .unwrap_or_default()
+/// bindings.ron.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct BindingsFile {
+    version: u32,
+    #[serde(default)]
+    bindings: KeyBindings,
 }
 
-pub fn load_beam_rifle_unlock() -> bool {
-    load_config_raw().beam_rifle_unlocked
+/// Settings as read at startup, plus anything that had to be reset on the way.
+pub struct LoadedConfig {
+    pub volume: f32,
+    pub window_mode: GameWindowMode,
+    pub bindings: KeyBindings,
+    pub corner_map: CornerMapSettings,
+    pub accessibility: AccessibilitySettings,
+    pub language: String,
+    pub warnings: Vec<String>,
 }
 
-pub fn save_beam_rifle_unlock() {
-    let mut cfg = load_config_raw();
-    cfg.beam_rifle_unlocked = true;
-    if let Ok(s) = ron::to_string(&cfg) {
-        let path = config_path();
-        if let Some(parent) = path.parent() {
-            let _ = std::fs::create_dir_all(parent);
-        }
-        let _ = std::fs::write(path, s);
-    }
-}
+/// String keys for why settings were reset at startup. Shown once as a toast on the main menu.
+#[derive(Resource, Default)]
+pub struct SettingsWarnings(pub Vec<String>);
 
-fn config_path() -> PathBuf {
+/// Per-user folder holding config.ron and the run save.
+pub(crate) fn config_dir() -> PathBuf {
     dirs::config_dir()
         .unwrap_or_else(|| PathBuf::from("."))
         .join("cleanup_crew")
-        .join("config.ron")
 }
 
-pub fn load_config() -> (f32, GameWindowMode, KeyBindings) {
-    let cfg = load_config_raw();
+fn config_path() -> PathBuf {
+    config_dir().join("config.ron")
+}
+
+fn bindings_path() -> PathBuf {
+    config_dir().join("bindings.ron")
+}
+
+/// Copies an unreadable settings file to `<name>.bak` so a reset never loses it.
+fn back_up(path: &Path) {
+    let Some(name) = path.file_name() else { return };
+    let backup = path.with_file_name(format!("{}.bak", name.to_string_lossy()));
+    if let Err(e) = std::fs::copy(path, &backup) {
+        warn!("Could not back up '{}': {e}", path.display());
+    }
+}
 
-    let mode = match cfg.window_mode_index {
+/// Reads the Beam Rifle flag from a version 1 config.ron, if that's what's on disk.
+/// Must run before `load_config`, which rewrites the file without it.
+pub fn load_legacy_beam_rifle_unlock() -> bool {
+    std::fs::read_to_string(config_path())
+        .ok()
+        .and_then(|s| ron::from_str::<V1BeamRifle>(&s).ok())
+        .is_some_and(|cfg| cfg.beam_rifle_unlocked)
+}
+
+/// Reads one section of a version 1 config.ron, falling back to `None` and a
+/// `warning` when it can't be read.
+fn v1_section<T: serde::de::DeserializeOwned>(text: &str, name: &str, warning: &str, warnings: &mut Vec<String>) -> Option<T> {
+    match ron::from_str(text) {
+        Ok(section) => Some(section),
+        Err(e) => {
+            warn!("Could not read {name} from version 1 config.ron: {e}; using the default");
+            warnings.push(warning.to_string());
+            None
+        }
+    }
+}
+
+/// Brings an older config.ron up to `CONFIG_VERSION`, one step per version.
+/// Returns the current-layout config plus any bindings the old file carried.
+/// Sections reset along the way add their warning keys to `warnings`.
+fn migrate_config(version: u32, text: &str, warnings: &mut Vec<String>) -> Result<(Config, Option<KeyBindings>), String> {
+    let mut bindings = None;
+    let mut config = match version {
+        1 => {
+            // Only a file that isn't RON at all fails as a whole.
+            ron::from_str::<ron::Value>(text).map_err(|e| e.to_string())?;
+            let defaults = Config::default();
+            let volume = v1_section::<V1Volume>(text, "volume", "settings.warn.volume_reset", warnings)
+                .map_or(defaults.volume, |s| s.volume);
+            let window_mode_index = v1_section::<V1WindowMode>(text, "window mode", "settings.warn.window_reset", warnings)
+                .map_or(defaults.window_mode_index, |s| s.window_mode_index);
+            // 1 → 2: key bindings move out to bindings.ron.
+            bindings = Some(
+                v1_section::<V1KeyBindings>(text, "key bindings", "settings.warn.bindings_reset", warnings)
+                    .map_or_else(KeyBindings::default, |s| s.key_bindings),
+            );
+            Config { version: 2, volume, window_mode_index, ..defaults }
+        }
+        CONFIG_VERSION => ron::from_str(text).map_err(|e| e.to_string())?,
+        v => return Err(format!("unknown version {v}")),
+    };
+    config.version = CONFIG_VERSION;
+    Ok((config, bindings))
+}
+
+pub fn load_config() -> LoadedConfig {
+    let mut warnings = Vec::new();
+    let mut migrated = false;
+
+    let path = config_path();
+    let (config, carried_bindings) = match std::fs::read_to_string(&path) {
+        Err(_) => (Config::default(), None),
+        Ok(text) => {
+            let result = ron::from_str::<ConfigHeader>(&text)
+                .map_err(|e| e.to_string())
+                .and_then(|h| migrate_config(h.version, &text, &mut warnings).map(|r| (h.version, r)));
+            match result {
+                Ok((version, loaded)) => {
+                    if version != CONFIG_VERSION {
+                        info!("Migrating config.ron from version {version} to {CONFIG_VERSION}");
+                        back_up(&path);
+                        migrated = true;
+                    }
+                    loaded
+                }
+                Err(e) => {
+                    warn!("Could not read '{}': {e}; using default volume and window mode", path.display());
+                    back_up(&path);
+                    warnings.push("settings.warn.display_reset".to_string());
+                    (Config::default(), None)
+                }
+            }
+        }
+    };
+
+    // Bindings carried over from an old config.ron win over any bindings.ron.
+    let bindings = match carried_bindings {
+        Some(b) => b,
+        None => {
+            let path = bindings_path();
+            match std::fs::read_to_string(&path) {
+                Err(_) => KeyBindings::default(),
+                Ok(text) => match ron::from_str::<BindingsFile>(&text) {
+                    Ok(file) if file.version <= CONFIG_VERSION => file.bindings,
+                    Ok(file) => {
+                        warn!("'{}' is version {} (expected {CONFIG_VERSION}); using default bindings", path.display(), file.version);
+                        back_up(&path);
+                        warnings.push("settings.warn.bindings_newer".to_string());
+                        KeyBindings::default()
+                    }
+                    Err(e) => {
+                        warn!("Could not read '{}': {e}; using default bindings", path.display());
+                        back_up(&path);
+                        warnings.push("settings.warn.bindings_reset".to_string());
+                        KeyBindings::default()
+                    }
+                },
+            }
+        }
+    };
+
+    let window_mode = match config.window_mode_index {
         0 => GameWindowMode::Windowed,
         2 => GameWindowMode::Fullscreen,
         _ => GameWindowMode::BorderlessFullscreen,
     };
-    (cfg.volume, mode, cfg.key_bindings)
+
+    // Write the split files straight away so a migration only ever runs once.
+    if migrated {
+        save_config(config.volume, window_mode, &bindings, &config.corner_map, &config.accessibility, &config.language);
+    }
+
+    LoadedConfig {
+        volume: config.volume,
+        window_mode,
+        bindings,
+        corner_map: config.corner_map,
+        accessibility: config.accessibility,
+        language: config.language,
+        warnings,
+    }
 }
 
-fn save_config(volume: f32, mode: GameWindowMode, bindings: &KeyBindings) {
+fn write_ron<T: serde::Serialize>(path: PathBuf, value: &T) {
+    match ron::to_string(value) {
+        Ok(s) => {
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            if let Err(e) = std::fs::write(&path, s) {
+                warn!("Failed to write '{}': {e}", path.display());
+            }
+        }
+        Err(e) => warn!("Failed to serialize '{}': {e}", path.display()),
+    }
+}
+
+fn save_config(
+    volume: f32,
+    mode: GameWindowMode,
+    bindings: &KeyBindings,
+    corner_map: &CornerMapSettings,
+    accessibility: &AccessibilitySettings,
+    language: &str,
+) {
     let cfg = Config {
+        version: CONFIG_VERSION,
         volume,
         window_mode_index: match mode {
             GameWindowMode::Windowed => 0,
             GameWindowMode::BorderlessFullscreen => 1,
             GameWindowMode::Fullscreen => 2,
         },
-        key_bindings: bindings.clone(),
-        beam_rifle_unlocked: load_config_raw().beam_rifle_unlocked,
+        corner_map: *corner_map,
+        accessibility: *accessibility,
+        language: language.to_string(),
     };
-    if let Ok(s) = ron::to_string(&cfg) {
-        let path = config_path();
-        if let Some(parent) = path.parent() {
-            let _ = std::fs::create_dir_all(parent);
+    write_ron(config_path(), &cfg);
+    write_ron(bindings_path(), &BindingsFile { version: CONFIG_VERSION, bindings: bindings.clone() });
+}
+
+// ── Settings reset toast ──────────────────────────────────────────────────────
+
+#[derive(Component)]
+struct SettingsToast(Timer);
+
+const TOAST_SECS: f32 = 6.0;
+
+fn show_settings_warnings(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    warnings: Res<SettingsWarnings>,
+) {
+    if warnings.0.is_empty() { return; }
+    let font: Handle<Font> = assets.load(FONT_PATH);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Px(20.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ZIndex(500),
+            SettingsToast(Timer::from_seconds(TOAST_SECS, TimerMode::Once)),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    padding: UiRect::axes(Val::Px(18.0), Val::Px(10.0)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.35, 0.2, 0.0, 0.92)),
+                BorderColor(Color::srgba(1.0, 0.7, 0.2, 0.9)),
+                BorderRadius::all(Val::Px(6.0)),
+            ))
+            .with_children(|panel| {
+                for line in &warnings.0 {
+                    panel.spawn((
+                        Text::new(tr(line)),
+                        TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                }
+                panel.spawn((
+                    Text::new(tr("settings.warn.backup")),
+                    TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                    TextColor(Color::srgb(1.0, 0.85, 0.6)),
+                ));
+            });
+        });
+
+    // Only warn once per launch.
+    commands.remove_resource::<SettingsWarnings>();
+}
+
+fn tick_settings_toast(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q: Query<(Entity, &mut SettingsToast)>,
+) {
+    for (e, mut toast) in &mut q {
+        toast.0.tick(time.delta());
+        if toast.0.finished() {
+            commands.entity(e).despawn();
         }
-        let _ = std::fs::write(path, s);
     }
 }
 
@@ -379,14 +971,23 @@ impl Plugin for SettingsPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<BindingState>()
+            .add_systems(
+                OnEnter(crate::GameState::Menu),
+                show_settings_warnings.run_if(resource_exists::<SettingsWarnings>),
+            )
+            .add_systems(Update, tick_settings_toast)
             .add_systems(Update, handle_settings_buttons.run_if(resource_exists::<SettingsOrigin>))
             .add_systems(Update, update_volume_display)
-            .add_systems(Update, update_window_mode_display)
+            .add_systems(Update, update_window_mode_display.after(crate::locale::apply_language))
+            .add_systems(Update, update_language_display)
+            .add_systems(Update, update_corner_map_display.after(crate::locale::apply_language))
             .add_systems(Update, sync_volume_to_sinks)
             .add_systems(Update, sync_window_mode)
             .add_systems(Update, handle_controls_buttons.run_if(any_with_component::<ControlsUI>))
             .add_systems(Update, listen_for_key.run_if(any_with_component::<ControlsUI>))
-            .add_systems(Update, sync_controls_ui.run_if(any_with_component::<ControlsUI>));
+            .add_systems(Update, sync_controls_ui.run_if(any_with_component::<ControlsUI>))
+            .add_systems(Update, handle_accessibility_buttons.run_if(any_with_component::<AccessibilityUI>))
+            .add_systems(Update, update_accessibility_display.run_if(any_with_component::<AccessibilityUI>));
     }
 }
 
@@ -421,7 +1022,7 @@ pub fn open_settings(
         .with_children(|root| {
             root.spawn((
                 Node {
-                    width: Val::Px(380.0),
+                    width: Val::Px(420.0),
                     padding: UiRect::all(Val::Px(28.0)),
                     flex_direction: FlexDirection::Column,
                     align_items: AlignItems::Center,
@@ -436,7 +1037,7 @@ pub fn open_settings(
                 // Title
                 panel.spawn((Node::default(),)).with_children(|c| {
                     c.spawn((
-                        Text::new("SETTINGS"),
+                        loc_text("settings.title"),
                         TextFont { font: font.clone(), font_size: 34.0, ..default() },
                         TextColor(Color::WHITE),
                     ));
@@ -453,7 +1054,7 @@ pub fn open_settings(
                     .with_children(|row| {
                         row.spawn((Node::default(),)).with_children(|c| {
                             c.spawn((
-                                Text::new("Music Volume"),
+                                loc_text("settings.music_volume"),
                                 TextFont { font: font.clone(), font_size: 22.0, ..default() },
                                 TextColor(Color::srgb(0.85, 0.85, 0.85)),
                             ));
@@ -495,7 +1096,7 @@ pub fn open_settings(
                     .with_children(|row| {
                         row.spawn((Node::default(),)).with_children(|c| {
                             c.spawn((
-                                Text::new("Window Mode"),
+                                loc_text("settings.window_mode"),
                                 TextFont { font: font.clone(), font_size: 22.0, ..default() },
                                 TextColor(Color::srgb(0.85, 0.85, 0.85)),
                             ));
@@ -526,28 +1127,23 @@ pub fn open_settings(
                         });
                     });
 
-                // Controls button
+                spawn_option_row(panel, &font, "settings.language", ("<", SettingsButton::LanguageLeft), (">", SettingsButton::LanguageRight), LanguageDisplay);
+
+                // Corner minimap rows — labels are filled in by update_corner_map_display.
+                spawn_option_row(panel, &font, "settings.minimap", ("<", SettingsButton::MapCornerLeft), (">", SettingsButton::MapCornerRight), CornerMapDisplay::Corner);
+                spawn_option_row(panel, &font, "settings.map_size", ("<", SettingsButton::MapSizeLeft), (">", SettingsButton::MapSizeRight), CornerMapDisplay::Size);
+                spawn_option_row(panel, &font, "settings.map_opacity", ("-", SettingsButton::MapOpacityDown), ("+", SettingsButton::MapOpacityUp), CornerMapDisplay::Opacity);
+                spawn_option_row(panel, &font, "settings.map_mode", ("<", SettingsButton::MapModeToggle), (">", SettingsButton::MapModeToggle), CornerMapDisplay::Mode);
+
+                // Controls and accessibility pages
                 panel
-                    .spawn((
-                        Button,
-                        SettingsButton::Controls,
-                        Node {
-                            width: Val::Px(240.0),
-                            height: Val::Px(50.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            ..default()
-                        },
-                        BackgroundColor(Color::srgba(0.1, 0.2, 0.35, 0.9)),
-                        BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.3)),
-                        BorderRadius::all(Val::Px(6.0)),
-                    ))
-                    .with_children(|b| {
-                        b.spawn((
-                            Text::new("Controls"),
-                            TextFont { font: font.clone(), font_size: 24.0, ..default() },
-                            TextColor(Color::WHITE),
-                        ));
+                    .spawn((Node {
+                        column_gap: Val::Px(12.0),
+                        ..default()
+                    },))
+                    .with_children(|row| {
+                        spawn_page_button(row, font.clone(), "settings.controls", SettingsButton::Controls);
+                        spawn_page_button(row, font.clone(), "settings.accessibility", SettingsButton::Accessibility);
                     });
 
                 // Back button
@@ -569,7 +1165,7 @@ pub fn open_settings(
                     ))
                     .with_children(|b| {
                         b.spawn((
-                            Text::new("Back"),
+                            loc_text("common.back"),
                             TextFont { font: font.clone(), font_size: 24.0, ..default() },
                             TextColor(Color::WHITE),
                         ));
@@ -578,7 +1174,58 @@ pub fn open_settings(
         });
 }
 
-fn spawn_small_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, label: &str, button: SettingsButton) {
+/// A `< value >` row labelled by string key; the value text is kept up to date elsewhere.
+fn spawn_option_row<B: Component, D: Component>(
+    panel: &mut ChildSpawnerCommands,
+    font: &Handle<Font>,
+    label: &'static str,
+    (left_glyph, left): (&str, B),
+    (right_glyph, right): (&str, B),
+    display: D,
+) {
+    panel
+        .spawn((Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        },))
+        .with_children(|row| {
+            row.spawn((Node::default(),)).with_children(|c| {
+                c.spawn((
+                    loc_text(label),
+                    TextFont { font: font.clone(), font_size: 22.0, ..default() },
+                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                ));
+            });
+
+            row.spawn((Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                ..default()
+            },))
+            .with_children(|ctrl| {
+                spawn_small_button(ctrl, font.clone(), left_glyph, left);
+                ctrl.spawn((Node {
+                    width: Val::Px(130.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },))
+                .with_children(|c| {
+                    c.spawn((
+                        Text::new(""),
+                        TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                        TextColor(Color::WHITE),
+                        display,
+                    ));
+                });
+                spawn_small_button(ctrl, font.clone(), right_glyph, right);
+            });
+        });
+}
+
+fn spawn_small_button<B: Component>(parent: &mut ChildSpawnerCommands, font: Handle<Font>, label: &str, button: B) {
     parent
         .spawn((
             Button,
@@ -603,12 +1250,42 @@ fn spawn_small_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, lab
         });
 }
 
+/// A wide button, labelled by string key, that opens another settings page.
+fn spawn_page_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, label: &'static str, button: SettingsButton) {
+    parent
+        .spawn((
+            Button,
+            button,
+            Node {
+                width: Val::Px(176.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.2, 0.35, 0.9)),
+            BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.3)),
+            BorderRadius::all(Val::Px(6.0)),
+        ))
+        .with_children(|b| {
+            b.spawn((
+                loc_text(label),
+                TextFont { font, font_size: 22.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
 fn handle_settings_buttons(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut interactions: Query<(&Interaction, &SettingsButton), (Changed<Interaction>, With<Button>)>,
     mut volume: ResMut<GameMusicVolume>,
     mut window_mode: ResMut<GameWindowMode>,
+    mut corner_map: ResMut<CornerMapSettings>,
+    accessibility: Res<AccessibilitySettings>,
+    mut language: ResMut<Language>,
+    locales: Res<Locales>,
     bindings: Res<KeyBindings>,
     ui_q: Query<Entity, With<SettingsUI>>,
 ) {
@@ -629,11 +1306,41 @@ fn handle_settings_buttons(
             SettingsButton::WindowModeRight => {
                 *window_mode = window_mode.next();
             }
+            SettingsButton::LanguageLeft => {
+                language.0 = locales.step(&language.0, -1);
+            }
+            SettingsButton::LanguageRight => {
+                language.0 = locales.step(&language.0, 1);
+            }
+            SettingsButton::MapCornerLeft => {
+                corner_map.corner = corner_map.corner.step(-1);
+            }
+            SettingsButton::MapCornerRight => {
+                corner_map.corner = corner_map.corner.step(1);
+            }
+            SettingsButton::MapSizeLeft => {
+                corner_map.size = corner_map.size.prev();
+            }
+            SettingsButton::MapSizeRight => {
+                corner_map.size = corner_map.size.next();
+            }
+            SettingsButton::MapOpacityDown => {
+                corner_map.opacity = (corner_map.opacity - 0.1).max(0.3);
+            }
+            SettingsButton::MapOpacityUp => {
+                corner_map.opacity = (corner_map.opacity + 0.1).min(1.0);
+            }
+            SettingsButton::MapModeToggle => {
+                corner_map.rotating = !corner_map.rotating;
+            }
             SettingsButton::Controls => {
                 open_controls(&mut commands, &asset_server, &bindings);
             }
+            SettingsButton::Accessibility => {
+                open_accessibility(&mut commands, &asset_server);
+            }
             SettingsButton::Back => {
-                save_config(volume.0, *window_mode, &bindings);
+                save_config(volume.0, *window_mode, &bindings, &corner_map, &accessibility, &language.0);
                 commands.remove_resource::<SettingsOrigin>();
                 for e in &ui_q {
                     commands.entity(e).despawn();
@@ -656,14 +1363,44 @@ fn update_volume_display(
 
 fn update_window_mode_display(
     mode: Res<GameWindowMode>,
+    language: Res<Language>,
     mut text_q: Query<&mut Text, With<WindowModeDisplay>>,
 ) {
-    if !mode.is_changed() { return; }
+    if !mode.is_changed() && !language.is_changed() { return; }
     for mut t in &mut text_q {
         *t = Text::new(mode.label());
     }
 }
 
+fn update_language_display(
+    language: Res<Language>,
+    locales: Res<Locales>,
+    added: Query<(), Added<LanguageDisplay>>,
+    mut text_q: Query<&mut Text, With<LanguageDisplay>>,
+) {
+    if !language.is_changed() && added.is_empty() { return; }
+    for mut t in &mut text_q {
+        *t = Text::new(locales.name_of(&language.0));
+    }
+}
+
+fn update_corner_map_display(
+    settings: Res<CornerMapSettings>,
+    language: Res<Language>,
+    added: Query<(), Added<CornerMapDisplay>>,
+    mut text_q: Query<(&CornerMapDisplay, &mut Text)>,
+) {
+    if !settings.is_changed() && !language.is_changed() && added.is_empty() { return; }
+    for (display, mut t) in &mut text_q {
+        *t = Text::new(match display {
+            CornerMapDisplay::Corner => settings.corner.label(),
+            CornerMapDisplay::Size => settings.size.label(),
+            CornerMapDisplay::Opacity => format!("{}%", (settings.opacity * 100.0).round() as u32),
+            CornerMapDisplay::Mode => tr(if settings.rotating { "settings.map_mode.rotating" } else { "settings.map_mode.fixed" }),
+        });
+    }
+}
+
 fn sync_volume_to_sinks(
     volume: Res<GameMusicVolume>,
     mut sinks: Query<&mut AudioSink, With<MusicTrack>>,
@@ -693,6 +1430,8 @@ const ALL_ACTIONS: &[BindableAction] = &[
     BindableAction::Dash,       BindableAction::SwapWeapon,
     BindableAction::Interact,   BindableAction::Inventory,
     BindableAction::ToggleMusic, BindableAction::Pause,
+    BindableAction::ActiveItem, BindableAction::Hotbar1,
+    BindableAction::Hotbar2,    BindableAction::Hotbar3,
 ];
 
 pub fn open_controls(commands: &mut Commands, assets: &AssetServer, bindings: &KeyBindings) {
@@ -715,7 +1454,7 @@ pub fn open_controls(commands: &mut Commands, assets: &AssetServer, bindings: &K
         .with_children(|root| {
             root.spawn((
                 Node {
-                    width: Val::Px(480.0),
+                    width: Val::Px(880.0),
                     padding: UiRect::all(Val::Px(28.0)),
                     flex_direction: FlexDirection::Column,
                     align_items: AlignItems::Center,
@@ -730,16 +1469,26 @@ pub fn open_controls(commands: &mut Commands, assets: &AssetServer, bindings: &K
                 // Title
                 panel.spawn((Node::default(),)).with_children(|c| {
                     c.spawn((
-                        Text::new("CONTROLS"),
+                        loc_text("settings.controls_title"),
                         TextFont { font: font.clone(), font_size: 30.0, ..default() },
                         TextColor(Color::WHITE),
                     ));
                 });
 
-                // One row per action
-                for &action in ALL_ACTIONS {
-                    spawn_binding_row(panel, font.clone(), action, bindings.binding_for(action));
-                }
+                // One row per action, wrapped into two columns so the list fits on screen
+                panel
+                    .spawn((Node {
+                        width: Val::Percent(100.0),
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::SpaceBetween,
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    },))
+                    .with_children(|grid| {
+                        for &action in ALL_ACTIONS {
+                            spawn_binding_row(grid, font.clone(), action, bindings.binding_for(action));
+                        }
+                    });
 
                 // Back button
                 panel
@@ -760,7 +1509,7 @@ pub fn open_controls(commands: &mut Commands, assets: &AssetServer, bindings: &K
                     ))
                     .with_children(|b| {
                         b.spawn((
-                            Text::new("Back"),
+                            loc_text("common.back"),
                             TextFont { font: font.clone(), font_size: 24.0, ..default() },
                             TextColor(Color::WHITE),
                         ));
@@ -777,7 +1526,7 @@ fn spawn_binding_row(
 ) {
     parent
         .spawn((Node {
-            width: Val::Percent(100.0),
+            width: Val::Px(390.0),
             justify_content: JustifyContent::SpaceBetween,
             align_items: AlignItems::Center,
             ..default()
@@ -831,6 +1580,9 @@ fn handle_controls_buttons(
     volume: Res<GameMusicVolume>,
     window_mode: Res<GameWindowMode>,
     bindings: Res<KeyBindings>,
+    corner_map: Res<CornerMapSettings>,
+    accessibility: Res<AccessibilitySettings>,
+    language: Res<Language>,
 ) {
     // Start listening when a binding button is pressed
     for (interaction, btn) in &btn_q {
@@ -842,7 +1594,7 @@ fn handle_controls_buttons(
     // Back: save and close
     for interaction in &back_q {
         if *interaction == Interaction::Pressed {
-            save_config(volume.0, *window_mode, &bindings);
+            save_config(volume.0, *window_mode, &bindings, &corner_map, &accessibility, &language.0);
             binding_state.listening_for = None;
             for e in &controls_ui_q {
                 commands.entity(e).despawn();
@@ -901,3 +1653,248 @@ fn sync_controls_ui(
         };
     }
 }
+
+// ── Accessibility overlay ─────────────────────────────────────────────────────
+
+pub fn open_accessibility(commands: &mut Commands, assets: &AssetServer) {
+    let font: Handle<Font> = assets.load(FONT_PATH);
+    let symbol_font: Handle<Font> = assets.load(crate::SYMBOL_FONT_PATH);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.45)),
+            ZIndex(400),
+            AccessibilityUI,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    width: Val::Px(480.0),
+                    padding: UiRect::all(Val::Px(28.0)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(18.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.05, 0.05, 0.14, 0.97)),
+                BorderColor(Color::srgba(0.3, 0.3, 0.7, 0.8)),
+                BorderRadius::all(Val::Px(10.0)),
+            ))
+            .with_children(|panel| {
+                // Title
+                panel.spawn((Node::default(),)).with_children(|c| {
+                    c.spawn((
+                        loc_text("settings.accessibility_title"),
+                        TextFont { font: font.clone(), font_size: 30.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+
+                // Labels and previews are filled in by update_accessibility_display.
+                spawn_option_row(panel, &font, "settings.color_palette", ("<", AccessibilityButton::VisionLeft), (">", AccessibilityButton::VisionRight), AccessibilityDisplay::Vision);
+                spawn_option_row(panel, &font, "settings.color_glyphs", ("<", AccessibilityButton::GlyphsToggle), (">", AccessibilityButton::GlyphsToggle), AccessibilityDisplay::Glyphs);
+
+                // Puzzle colour preview
+                panel
+                    .spawn((Node {
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    },))
+                    .with_children(|row| {
+                        for value in 0..4u8 {
+                            row.spawn((
+                                Node {
+                                    width: Val::Px(84.0),
+                                    height: Val::Px(40.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(Color::NONE),
+                                BorderRadius::all(Val::Px(4.0)),
+                            ))
+                            .with_children(|b| {
+                                b.spawn((
+                                    Text::new(""),
+                                    TextFont { font: symbol_font.clone(), font_size: 18.0, ..default() },
+                                    TextColor(Color::BLACK),
+                                    PaletteSwatch(value),
+                                ));
+                            });
+                        }
+                    });
+
+                spawn_option_row(panel, &font, "settings.health_full", ("<", AccessibilityButton::HealthFullLeft), (">", AccessibilityButton::HealthFullRight), AccessibilityDisplay::HealthFull);
+                spawn_option_row(panel, &font, "settings.health_low", ("<", AccessibilityButton::HealthLowLeft), (">", AccessibilityButton::HealthLowRight), AccessibilityDisplay::HealthLow);
+
+                // Health bar preview, empty to full
+                panel
+                    .spawn((Node {
+                        width: Val::Px(360.0),
+                        height: Val::Px(14.0),
+                        ..default()
+                    },))
+                    .with_children(|bar| {
+                        for i in 0..8 {
+                            bar.spawn((
+                                Node {
+                                    flex_grow: 1.0,
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::NONE),
+                                HealthSwatch(i as f32 / 7.0),
+                            ));
+                        }
+                    });
+
+                // Back button
+                panel
+                    .spawn((
+                        Button,
+                        AccessibilityButton::Back,
+                        Node {
+                            width: Val::Px(240.0),
+                            height: Val::Px(50.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            margin: UiRect { top: Val::Px(8.0), ..default() },
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.25, 0.1, 0.1, 0.9)),
+                        BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.3)),
+                        BorderRadius::all(Val::Px(6.0)),
+                    ))
+                    .with_children(|b| {
+                        b.spawn((
+                            loc_text("common.back"),
+                            TextFont { font: font.clone(), font_size: 24.0, ..default() },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            });
+        });
+}
+
+fn handle_accessibility_buttons(
+    mut commands: Commands,
+    interactions: Query<(&Interaction, &AccessibilityButton), (Changed<Interaction>, With<Button>)>,
+    ui_q: Query<Entity, With<AccessibilityUI>>,
+    mut accessibility: ResMut<AccessibilitySettings>,
+    volume: Res<GameMusicVolume>,
+    window_mode: Res<GameWindowMode>,
+    bindings: Res<KeyBindings>,
+    corner_map: Res<CornerMapSettings>,
+    language: Res<Language>,
+) {
+    for (interaction, button) in &interactions {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            AccessibilityButton::VisionLeft => {
+                accessibility.color_vision = accessibility.color_vision.step(-1);
+            }
+            AccessibilityButton::VisionRight => {
+                accessibility.color_vision = accessibility.color_vision.step(1);
+            }
+            AccessibilityButton::GlyphsToggle => {
+                accessibility.glyphs = !accessibility.glyphs;
+            }
+            AccessibilityButton::HealthFullLeft => {
+                accessibility.health_full = accessibility.health_full.step(-1);
+            }
+            AccessibilityButton::HealthFullRight => {
+                accessibility.health_full = accessibility.health_full.step(1);
+            }
+            AccessibilityButton::HealthLowLeft => {
+                accessibility.health_low = accessibility.health_low.step(-1);
+            }
+            AccessibilityButton::HealthLowRight => {
+                accessibility.health_low = accessibility.health_low.step(1);
+            }
+            AccessibilityButton::Back => {
+                save_config(volume.0, *window_mode, &bindings, &corner_map, &accessibility, &language.0);
+                for e in &ui_q {
+                    commands.entity(e).despawn();
+                }
+            }
+        }
+    }
+}
+
+fn update_accessibility_display(
+    settings: Res<AccessibilitySettings>,
+    added: Query<(), Added<AccessibilityDisplay>>,
+    mut text_q: Query<(&AccessibilityDisplay, &mut Text)>,
+    mut swatch_q: Query<(&PaletteSwatch, &mut Text, &ChildOf), Without<AccessibilityDisplay>>,
+    mut bg_q: Query<&mut BackgroundColor, Without<HealthSwatch>>,
+    mut health_q: Query<(&HealthSwatch, &mut BackgroundColor)>,
+) {
+    if !settings.is_changed() && added.is_empty() { return; }
+    for (display, mut t) in &mut text_q {
+        *t = Text::new(match display {
+            AccessibilityDisplay::Vision => settings.color_vision.label(),
+            AccessibilityDisplay::Glyphs => tr(if settings.glyphs { "common.on" } else { "common.off" }),
+            AccessibilityDisplay::HealthFull => settings.health_full.label(),
+            AccessibilityDisplay::HealthLow => settings.health_low.label(),
+        });
+    }
+    for (swatch, mut t, parent) in &mut swatch_q {
+        *t = Text::new(crate::station_color::color_label(&settings, swatch.0));
+        if let Ok(mut bg) = bg_q.get_mut(parent.parent()) {
+            bg.0 = settings.puzzle_color(swatch.0);
+        }
+    }
+    for (swatch, mut bg) in &mut health_q {
+        bg.0 = settings.health_color(swatch.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1_CONFIG: &str = "(volume: 0.3, window_mode_index: 2, key_bindings: (dash: KeyQ), beam_rifle_unlocked: true)";
+
+    #[test]
+    fn v1_config_migrates_and_round_trips() {
+        let mut warnings = Vec::new();
+        let (config, bindings) = migrate_config(1, V1_CONFIG, &mut warnings).expect("v1 config should migrate");
+        assert!(warnings.is_empty());
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.volume, 0.3);
+        assert_eq!(config.window_mode_index, 2);
+        assert_eq!(config.accessibility, AccessibilitySettings::default());
+        assert_eq!(config.language, FALLBACK_LOCALE);
+        assert_eq!(bindings.expect("v1 bindings carried over").dash, KeyCode::KeyQ);
+
+        let text = ron::to_string(&config).expect("migrated config should serialize");
+        let (reloaded, carried) = migrate_config(CONFIG_VERSION, &text, &mut warnings).expect("saved config should reload");
+        assert!(carried.is_none());
+        assert_eq!(reloaded.volume, config.volume);
+        assert_eq!(reloaded.window_mode_index, config.window_mode_index);
+        assert_eq!(reloaded.corner_map, config.corner_map);
+        assert_eq!(reloaded.accessibility, config.accessibility);
+        assert_eq!(reloaded.language, config.language);
+    }
+
+    #[test]
+    fn bad_v1_section_resets_only_itself() {
+        let text = "(volume: \"loud\", window_mode_index: 2, key_bindings: (dash: KeyQ))";
+        let mut warnings = Vec::new();
+        let (config, bindings) = migrate_config(1, text, &mut warnings).expect("v1 config should migrate");
+        assert_eq!(warnings, ["settings.warn.volume_reset"]);
+        assert_eq!(config.volume, Config::default().volume);
+        assert_eq!(config.window_mode_index, 2);
+        assert_eq!(bindings.expect("v1 bindings carried over").dash, KeyCode::KeyQ);
+    }
+}
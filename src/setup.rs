@@ -1,6 +1,8 @@
 use bevy::prelude::*;
-use crate::{GameState, FONT_PATH, SelectedWeapon, SelectedRun, StationLevel, PlanetCount, SavedPlayerBuffs, BeamRifleUnlocked};
-use crate::weapons::WeaponType;
+use crate::{GameState, FONT_PATH, SelectedWeapon, SelectedRun, StationLevel, PlanetCount, RunSeed, SavedPlayerBuffs};
+use crate::locale::{tr, tr_args};
+use crate::profile::{Profile, RunEvent, UnlockDef, UnlockRegistry, Unlockable};
+use crate::weapons::{WeaponDef, WeaponRegistry, DEFAULT_WEAPON};
 
 pub struct SetupPlugin;
 
@@ -10,7 +12,7 @@ impl Plugin for SetupPlugin {
             .add_systems(OnEnter(GameState::Setup), setup_screen)
             .add_systems(
                 Update,
-                (handle_weapon_buttons, handle_run_buttons, handle_action_buttons)
+                (handle_weapon_buttons, handle_run_buttons, handle_crew_buttons, handle_action_buttons)
                     .run_if(in_state(GameState::Setup)),
             )
             .add_systems(OnExit(GameState::Setup), cleanup_setup);
@@ -20,9 +22,12 @@ impl Plugin for SetupPlugin {
 #[derive(Component)]
 struct SetupUI;
 
-/// Marks a weapon card button with which weapon it represents.
+/// Marks a weapon card button with which weapon definition it represents.
 #[derive(Component)]
-struct WeaponCard(WeaponType);
+struct WeaponCard {
+    id: String,
+    locked: bool,
+}
 
 #[derive(Component)]
 enum RunCycleButton {
@@ -33,6 +38,15 @@ enum RunCycleButton {
 #[derive(Component)]
 struct RunLabel;
 
+#[derive(Component)]
+enum CrewCycleButton {
+    Prev,
+    Next,
+}
+
+#[derive(Component)]
+struct CrewLabel;
+
 #[derive(Component)]
 enum SetupActionButton {
     StartRun,
@@ -43,16 +57,26 @@ const CARD_SELECTED_BG: Color = Color::srgba(0.05, 0.15, 0.35, 0.95);
 const CARD_SELECTED_BORDER: Color = Color::srgba(0.2, 0.8, 1.0, 1.0);
 const CARD_UNSELECTED_BG: Color = Color::srgba(0.07, 0.07, 0.12, 0.9);
 const CARD_UNSELECTED_BORDER: Color = Color::srgba(0.3, 0.3, 0.5, 0.5);
+const LOCKED_TEXT: Color = Color::srgba(0.5, 0.4, 0.25, 1.0);
+const UNLOCKED_TEXT: Color = Color::srgba(0.4, 0.9, 0.5, 1.0);
+const SECTION_LABEL: Color = Color::srgba(0.7, 0.7, 0.9, 1.0);
+/// String key for the crew used when nothing else is picked.
+const DEFAULT_CREW_NAME: &str = "setup.default_crew";
 
 fn setup_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut selected_weapon: ResMut<SelectedWeapon>,
-    unlocked: Res<BeamRifleUnlocked>,
+    profile: Res<Profile>,
+    unlocks: Res<UnlockRegistry>,
+    registry: Res<WeaponRegistry>,
 ) {
-    // If BeamRifle is somehow selected but not unlocked, reset to Zapper.
-    if selected_weapon.0 == WeaponType::BeamRifle && !unlocked.0 {
-        selected_weapon.0 = WeaponType::Zapper;
+    // If a locked or since-removed weapon is selected, reset to the default.
+    let selectable = registry
+        .get(&selected_weapon.0)
+        .is_some_and(|w| unlocks.weapon_available(&profile, &w.def.id, w.def.requires_unlock));
+    if !selectable {
+        selected_weapon.0 = DEFAULT_WEAPON.to_string();
     }
 
     let font = asset_server.load(FONT_PATH);
@@ -74,108 +98,233 @@ fn setup_screen(
         .with_children(|root| {
             root.spawn((
                 Node {
-                    width: Val::Px(580.0),
                     padding: UiRect::all(Val::Px(28.0)),
-                    flex_direction: FlexDirection::Column,
-                    align_items: AlignItems::Center,
-                    row_gap: Val::Px(18.0),
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(28.0),
                     ..default()
                 },
                 BackgroundColor(Color::srgba(0.05, 0.05, 0.14, 0.97)),
                 BorderColor(Color::srgba(0.3, 0.3, 0.6, 0.8)),
                 BorderRadius::all(Val::Px(10.0)),
             ))
-            .with_children(|panel| {
-                // Title
-                panel.spawn((
-                    Text::new("LOADOUT"),
-                    TextFont { font: font.clone(), font_size: 38.0, ..default() },
-                    TextColor(Color::WHITE),
-                ));
-
-                // Weapon section label
-                panel.spawn((
-                    Text::new("WEAPON"),
-                    TextFont { font: font.clone(), font_size: 18.0, ..default() },
-                    TextColor(Color::srgba(0.7, 0.7, 0.9, 1.0)),
-                ));
-
-                // Weapon cards row
-                panel
-                    .spawn(Node {
-                        flex_direction: FlexDirection::Row,
-                        column_gap: Val::Px(20.0),
-                        ..default()
-                    })
-                    .with_children(|row| {
-                        spawn_weapon_card(row, font.clone(), WeaponType::Zapper,    "Zapper",     "High burst, slow rate", selected_weapon.0, true);
-                        spawn_weapon_card(row, font.clone(), WeaponType::BeamRifle, "Beam Rifle", "Fast rate, lower burst", selected_weapon.0, unlocked.0);
-                    });
+            .with_children(|columns| {
+                columns.spawn(Node {
+                    width: Val::Px(564.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(18.0),
+                    ..default()
+                })
+                .with_children(|panel| {
+                    // Title
+                    panel.spawn((
+                        Text::new(tr("setup.title")),
+                        TextFont { font: font.clone(), font_size: 38.0, ..default() },
+                        TextColor(Color::WHITE),
+                    ));
 
-                // Run section label
-                panel.spawn((
-                    Text::new("RUN"),
-                    TextFont { font: font.clone(), font_size: 18.0, ..default() },
-                    TextColor(Color::srgba(0.7, 0.7, 0.9, 1.0)),
-                ));
+                    // Weapon section label
+                    panel.spawn((
+                        Text::new(tr("setup.weapon")),
+                        TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                        TextColor(Color::srgba(0.7, 0.7, 0.9, 1.0)),
+                    ));
 
-                // Run selector row: < label >
-                panel
-                    .spawn(Node {
+                    // Weapon cards, wrapping three to a row
+                    panel
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            flex_wrap: FlexWrap::Wrap,
+                            justify_content: JustifyContent::Center,
+                            column_gap: Val::Px(16.0),
+                            row_gap: Val::Px(16.0),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            for weapon in registry.iter() {
+                                let available = unlocks.weapon_available(&profile, &weapon.def.id, weapon.def.requires_unlock);
+                                let condition = if available {
+                                    None
+                                } else {
+                                    Some(unlocks.for_weapon(&weapon.def.id).map_or_else(
+                                        || tr("setup.locked"),
+                                        |d| condition_text(d, &profile),
+                                    ))
+                                };
+                                spawn_weapon_card(row, font.clone(), &weapon.def, &selected_weapon.0, condition);
+                            }
+                        });
+
+                    // Run section label
+                    panel.spawn((
+                        Text::new(tr("setup.run_section")),
+                        TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                        TextColor(Color::srgba(0.7, 0.7, 0.9, 1.0)),
+                    ));
+
+                    // Run selector row: < label >
+                    panel
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(14.0),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            spawn_cycle_button(row, font.clone(), "<", RunCycleButton::Prev);
+
+                            row.spawn(Node {
+                                width: Val::Px(120.0),
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            })
+                            .with_children(|c| {
+                                c.spawn((
+                                    Text::new(tr_args("setup.run", &[("run", &1)])),
+                                    TextFont { font: font.clone(), font_size: 22.0, ..default() },
+                                    TextColor(Color::WHITE),
+                                    RunLabel,
+                                ));
+                            });
+
+                            spawn_cycle_button(row, font.clone(), ">", RunCycleButton::Next);
+                        });
+
+                    // Action buttons
+                    spawn_action_button(panel, font.clone(), &tr("setup.start_run"), SetupActionButton::StartRun, Color::srgba(0.08, 0.42, 0.08, 0.9));
+                    spawn_action_button(panel, font.clone(), &tr("common.back"),     SetupActionButton::Back,     Color::srgba(0.25, 0.08, 0.08, 0.9));
+                });
+
+                // Right column: crew cosmetics and everything else that unlocks
+                columns.spawn(Node {
+                    width: Val::Px(300.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|side| {
+                    side.spawn((
+                        Text::new(tr("setup.crew")),
+                        TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                        TextColor(SECTION_LABEL),
+                    ));
+
+                    // Crew selector row: < name >
+                    side.spawn(Node {
                         flex_direction: FlexDirection::Row,
                         align_items: AlignItems::Center,
-                        column_gap: Val::Px(14.0),
+                        column_gap: Val::Px(10.0),
+                        margin: UiRect { bottom: Val::Px(8.0), ..default() },
                         ..default()
                     })
                     .with_children(|row| {
-                        spawn_cycle_button(row, font.clone(), "<", RunCycleButton::Prev);
+                        spawn_crew_button(row, font.clone(), "<", CrewCycleButton::Prev);
 
                         row.spawn(Node {
-                            width: Val::Px(120.0),
+                            width: Val::Px(170.0),
                             justify_content: JustifyContent::Center,
                             ..default()
                         })
                         .with_children(|c| {
                             c.spawn((
-                                Text::new("Run 1"),
-                                TextFont { font: font.clone(), font_size: 22.0, ..default() },
-                                TextColor(Color::WHITE),
-                                RunLabel,
+                                Text::new(crew_name(&profile, &unlocks)),
+                                TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                                TextColor(unlocks.crew_tint(&profile)),
+                                CrewLabel,
                             ));
                         });
 
-                        spawn_cycle_button(row, font.clone(), ">", RunCycleButton::Next);
+                        spawn_crew_button(row, font.clone(), ">", CrewCycleButton::Next);
                     });
 
-                // Action buttons
-                spawn_action_button(panel, font.clone(), "Start Run", SetupActionButton::StartRun, Color::srgba(0.08, 0.42, 0.08, 0.9));
-                spawn_action_button(panel, font.clone(), "Back",      SetupActionButton::Back,     Color::srgba(0.25, 0.08, 0.08, 0.9));
+                    side.spawn((
+                        Text::new(tr("setup.unlocks")),
+                        TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                        TextColor(SECTION_LABEL),
+                    ));
+
+                    // Weapons already show their condition on their own card.
+                    for def in unlocks.iter().filter(|d| !matches!(d.grants, Unlockable::Weapon(_))) {
+                        spawn_unlock_card(side, font.clone(), def, &profile);
+                    }
+                });
             });
         });
 }
 
+/// Condition with progress, e.g. "Defeat 100 enemies (37/100)".
+fn condition_text(def: &UnlockDef, profile: &Profile) -> String {
+    let (have, need) = def.condition.progress(&profile.stats);
+    format!("{} ({}/{})", def.condition.describe(), have.min(need), need)
+}
+
+fn crew_name(profile: &Profile, unlocks: &UnlockRegistry) -> String {
+    profile
+        .crew
+        .as_deref()
+        .filter(|id| profile.is_unlocked(id))
+        .and_then(|id| unlocks.get(id))
+        .map_or_else(|| tr(DEFAULT_CREW_NAME), |d| d.name())
+}
+
+fn spawn_unlock_card(parent: &mut ChildSpawnerCommands, font: Handle<Font>, def: &UnlockDef, profile: &Profile) {
+    let unlocked = profile.is_unlocked(&def.id);
+    let (status, status_color) = if unlocked {
+        (tr("setup.unlocked"), UNLOCKED_TEXT)
+    } else {
+        (condition_text(def, profile), LOCKED_TEXT)
+    };
+
+    parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(CARD_UNSELECTED_BG),
+            BorderColor(if unlocked { CARD_SELECTED_BORDER } else { CARD_UNSELECTED_BORDER }),
+            BorderRadius::all(Val::Px(6.0)),
+        ))
+        .with_children(|card| {
+            card.spawn((
+                Text::new(def.name()),
+                TextFont { font: font.clone(), font_size: 16.0, ..default() },
+                TextColor(if unlocked { Color::WHITE } else { Color::srgba(0.45, 0.45, 0.5, 1.0) }),
+            ));
+            card.spawn((
+                Text::new(status),
+                TextFont { font, font_size: 12.0, ..default() },
+                TextColor(status_color),
+            ));
+        });
+}
+
 fn spawn_weapon_card(
     parent: &mut ChildSpawnerCommands,
     font: Handle<Font>,
-    weapon_type: WeaponType,
-    name: &str,
-    desc: &str,
-    selected: WeaponType,
-    is_available: bool,
+    def: &WeaponDef,
+    selected: &str,
+    locked_condition: Option<String>,
 ) {
-    let is_selected = is_available && weapon_type == selected;
+    let is_available = locked_condition.is_none();
+    let is_selected = is_available && def.id == selected;
     let bg = if is_selected { CARD_SELECTED_BG } else { CARD_UNSELECTED_BG };
     let border = if is_selected { CARD_SELECTED_BORDER } else { CARD_UNSELECTED_BORDER };
     let name_color = if is_available { Color::WHITE } else { Color::srgba(0.45, 0.45, 0.5, 1.0) };
-    let desc_text = if is_available { desc.to_string() } else { "Complete Planet 1".to_string() };
-    let desc_color = if is_available { Color::srgba(0.75, 0.75, 0.85, 1.0) } else { Color::srgba(0.5, 0.4, 0.25, 1.0) };
+    let desc_text = locked_condition.unwrap_or_else(|| def.description());
+    let desc_color = if is_available { Color::srgba(0.75, 0.75, 0.85, 1.0) } else { LOCKED_TEXT };
 
     parent
         .spawn((
             Button,
-            WeaponCard(weapon_type),
+            WeaponCard { id: def.id.clone(), locked: !is_available },
             Node {
-                width: Val::Px(185.0),
+                width: Val::Px(170.0),
                 height: Val::Px(110.0),
                 flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::Center,
@@ -190,7 +339,7 @@ fn spawn_weapon_card(
         ))
         .with_children(|card| {
             card.spawn((
-                Text::new(name),
+                Text::new(def.name()),
                 TextFont { font: font.clone(), font_size: 22.0, ..default() },
                 TextColor(name_color),
             ));
@@ -227,6 +376,31 @@ fn spawn_cycle_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, lab
         });
 }
 
+fn spawn_crew_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, label: &str, btn: CrewCycleButton) {
+    parent
+        .spawn((
+            Button,
+            btn,
+            Node {
+                width: Val::Px(38.0),
+                height: Val::Px(38.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.3, 0.9)),
+            BorderColor(Color::srgba(0.4, 0.4, 0.7, 0.6)),
+            BorderRadius::all(Val::Px(5.0)),
+        ))
+        .with_children(|b| {
+            b.spawn((
+                Text::new(label),
+                TextFont { font, font_size: 22.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
 fn spawn_action_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, label: &str, btn: SetupActionButton, bg: Color) {
     parent
         .spawn((
@@ -254,7 +428,6 @@ fn spawn_action_button(parent: &mut ChildSpawnerCommands, font: Handle<Font>, la
 
 fn handle_weapon_buttons(
     mut selected: ResMut<SelectedWeapon>,
-    unlocked: Res<BeamRifleUnlocked>,
     interactions: Query<(&Interaction, &WeaponCard), (Changed<Interaction>, With<Button>)>,
     mut cards: Query<(&WeaponCard, &mut BackgroundColor, &mut BorderColor)>,
 ) {
@@ -263,10 +436,10 @@ fn handle_weapon_buttons(
             continue;
         }
         // Ignore clicks on locked weapons.
-        if card.0 == WeaponType::BeamRifle && !unlocked.0 {
+        if card.locked {
             continue;
         }
-        selected.0 = card.0;
+        selected.0 = card.id.clone();
     }
 
     if !selected.is_changed() {
@@ -274,8 +447,7 @@ fn handle_weapon_buttons(
     }
 
     for (card, mut bg, mut border) in &mut cards {
-        let is_locked = card.0 == WeaponType::BeamRifle && !unlocked.0;
-        let is_selected = !is_locked && card.0 == selected.0;
+        let is_selected = !card.locked && card.id == selected.0;
         if is_selected {
             *bg = BackgroundColor(CARD_SELECTED_BG);
             *border = BorderColor(CARD_SELECTED_BORDER);
@@ -298,7 +470,36 @@ fn handle_run_buttons(
         // Only "Run 1" exists for now — both buttons are no-ops.
         let _ = (btn, &selected);
         if let Ok(mut text) = label_q.single_mut() {
-            text.0 = format!("Run {}", selected.0 + 1);
+            text.0 = tr_args("setup.run", &[("run", &(selected.0 + 1))]);
+        }
+    }
+}
+
+/// Cycles through the default crew and every unlocked crew, saving the pick.
+fn handle_crew_buttons(
+    mut profile: ResMut<Profile>,
+    unlocks: Res<UnlockRegistry>,
+    interactions: Query<(&Interaction, &CrewCycleButton), (Changed<Interaction>, With<Button>)>,
+    mut label_q: Query<(&mut Text, &mut TextColor), With<CrewLabel>>,
+) {
+    for (interaction, btn) in &interactions {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let options: Vec<Option<String>> = std::iter::once(None)
+            .chain(unlocks.crews(&profile).map(|d| Some(d.id.clone())))
+            .collect();
+        let current = options.iter().position(|c| *c == profile.crew).unwrap_or(0);
+        let next = match btn {
+            CrewCycleButton::Prev => (current + options.len() - 1) % options.len(),
+            CrewCycleButton::Next => (current + 1) % options.len(),
+        };
+        profile.crew = options[next].clone();
+        crate::profile::save_profile(&profile);
+
+        if let Ok((mut text, mut color)) = label_q.single_mut() {
+            text.0 = crew_name(&profile, &unlocks);
+            color.0 = unlocks.crew_tint(&profile);
         }
     }
 }
@@ -307,6 +508,7 @@ fn handle_action_buttons(
     mut commands: Commands,
     interactions: Query<(&Interaction, &SetupActionButton), (Changed<Interaction>, With<Button>)>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut run_events: EventWriter<RunEvent>,
 ) {
     for (interaction, btn) in &interactions {
         if *interaction != Interaction::Pressed {
@@ -316,7 +518,10 @@ fn handle_action_buttons(
             SetupActionButton::StartRun => {
                 commands.insert_resource(StationLevel(0));
                 commands.insert_resource(PlanetCount(0));
+                commands.insert_resource(RunSeed::default());
                 commands.remove_resource::<SavedPlayerBuffs>();
+                crate::run_save::delete_run_save();
+                run_events.write(RunEvent::RunStarted);
                 next_state.set(GameState::Loading);
             }
             SetupActionButton::Back => {
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use rand::random_range;
+use crate::locale::tr_args;
 use crate::player::{Player, aabb_overlap};
 use crate::rewards::RewardPopup;
 use crate::{GameEntity, GameState, PlanetLevelMarker, StationLevel, FONT_PATH, TILE_SIZE, Z_ENTITIES};
@@ -141,7 +142,7 @@ fn collect_code_fragment(
             // Floating confirmation text.
             let font: Handle<Font> = asset_server.load(FONT_PATH);
             commands.spawn((
-                Text2d::new(format!("Code Fragment: Station {} = {}", frag.station_index + 1, frag.digit)),
+                Text2d::new(tr_args("clue.code_fragment", &[("station", &(frag.station_index + 1)), ("value", &frag.digit)])),
                 TextFont { font, font_size: 18.0, ..default() },
                 TextColor(Color::srgb(0.2, 1.0, 1.0)),
                 Transform::from_translation(fp + Vec3::new(0.0, TILE_SIZE, 10.0)),
@@ -2,10 +2,22 @@ use bevy::prelude::*;
 use rand::random_range;
 use crate::player::{Player, aabb_overlap};
 use crate::rewards::RewardPopup;
-use crate::{GameEntity, GameState, PlanetLevelMarker, StationLevel, FONT_PATH, TILE_SIZE, Z_ENTITIES};
+use crate::{GameEntity, GameState, PlanetLevelMarker, StationLevel, FONT_PATH, SYMBOL_FONT_PATH, TILE_SIZE, Z_ENTITIES};
 use crate::room::RoomVec;
-
-const COLOR_NAMES: [&str; 4] = ["RED", "GRN", "BLU", "YLW"];
+use crate::locale::{tr, tr_args};
+use crate::settings::AccessibilitySettings;
+
+/// String keys for each colour value's short name.
+pub const COLOR_NAMES: [&str; 4] = ["color.red", "color.green", "color.blue", "color.yellow"];
+
+/// Name of a colour value, with its glyph in front when glyphs are on.
+pub fn color_label(settings: &AccessibilitySettings, value: u8) -> String {
+    let name = tr(COLOR_NAMES[value as usize]);
+    match settings.puzzle_glyph(value) {
+        Some(glyph) => format!("{glyph} {name}"),
+        None => name,
+    }
+}
 
 // ── Resources ─────────────────────────────────────────────────────────────────
 
@@ -25,6 +37,10 @@ pub struct ColorChip {
     pub color: u8,
 }
 
+/// Shape drawn over a colour chip so it reads without colour.
+#[derive(Component)]
+struct ChipGlyph;
+
 #[derive(Resource)]
 pub struct ColorChipRes {
     pub img: Handle<Image>,
@@ -44,6 +60,12 @@ impl Plugin for StationColorPlugin {
             .add_systems(
                 Update,
                 collect_color_chip.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                restyle_color_chips
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(resource_changed::<AccessibilitySettings>),
             );
     }
 }
@@ -73,6 +95,8 @@ fn spawn_color_chip(
     station_level: Res<StationLevel>,
     rooms: Res<RoomVec>,
     planet: Option<Res<PlanetLevelMarker>>,
+    accessibility: Res<AccessibilitySettings>,
+    asset_server: Res<AssetServer>,
 ) {
     if planet.is_some() { return; }
 
@@ -97,13 +121,21 @@ fn spawn_color_chip(
     let pos = Vec3::new(floor.x, floor.y, Z_ENTITIES);
 
     let mut sprite = Sprite::from_image(res.img.clone());
-    sprite.color = Color::srgb(1.0, 0.3, 0.3);
+    sprite.color = accessibility.puzzle_color(color);
 
     commands.spawn((
         sprite,
         Transform::from_translation(pos),
         ColorChip { station_index, color },
         GameEntity,
+    ))
+    .with_child((
+        Text2d::new(crate::settings::COLOR_GLYPHS[color as usize]),
+        TextFont { font: asset_server.load(SYMBOL_FONT_PATH), font_size: 14.0, ..default() },
+        TextColor(Color::WHITE),
+        Transform::from_xyz(0.0, TILE_SIZE * 0.6, 1.0),
+        if accessibility.glyphs { Visibility::Inherited } else { Visibility::Hidden },
+        ChipGlyph,
     ));
 }
 
@@ -112,6 +144,7 @@ fn collect_color_chip(
     player_q: Query<&Transform, With<Player>>,
     chip_q: Query<(Entity, &Transform, &ColorChip)>,
     mut colors: ResMut<StationColors>,
+    accessibility: Res<AccessibilitySettings>,
     asset_server: Res<AssetServer>,
 ) {
     let Ok(player_tf) = player_q.single() else { return };
@@ -124,15 +157,15 @@ fn collect_color_chip(
             colors.colors[chip.station_index] = Some(chip.color);
             commands.entity(entity).despawn();
 
-            let font: Handle<Font> = asset_server.load(FONT_PATH);
+            // The symbol font covers both the name and the glyph.
+            let font: Handle<Font> = asset_server.load(if accessibility.glyphs { SYMBOL_FONT_PATH } else { FONT_PATH });
             commands.spawn((
-                Text2d::new(format!(
-                    "Color Chip: Station {} = {}",
-                    chip.station_index + 1,
-                    COLOR_NAMES[chip.color as usize]
-                )),
+                Text2d::new(tr_args("clue.color_chip", &[
+                    ("station", &(chip.station_index + 1)),
+                    ("value", &color_label(&accessibility, chip.color)),
+                ])),
                 TextFont { font, font_size: 18.0, ..default() },
-                TextColor(Color::srgb(1.0, 0.4, 0.4)),
+                TextColor(accessibility.puzzle_color(chip.color)),
                 Transform::from_translation(cp + Vec3::new(0.0, TILE_SIZE, 10.0)),
                 RewardPopup { timer: Timer::from_seconds(2.0, TimerMode::Once) },
                 GameEntity,
@@ -140,3 +173,17 @@ fn collect_color_chip(
         }
     }
 }
+
+/// Applies a palette or glyph change to chips already on the floor.
+fn restyle_color_chips(
+    accessibility: Res<AccessibilitySettings>,
+    mut chip_q: Query<(&ColorChip, &mut Sprite)>,
+    mut glyph_q: Query<&mut Visibility, With<ChipGlyph>>,
+) {
+    for (chip, mut sprite) in &mut chip_q {
+        sprite.color = accessibility.puzzle_color(chip.color);
+    }
+    for mut vis in &mut glyph_q {
+        *vis = if accessibility.glyphs { Visibility::Inherited } else { Visibility::Hidden };
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use rand::random_range;
+use crate::locale::tr_args;
 use crate::player::{Player, aabb_overlap};
 use crate::rewards::RewardPopup;
 use crate::{GameEntity, GameState, PlanetLevelMarker, StationLevel, TILE_SIZE, Z_ENTITIES};
@@ -126,11 +127,10 @@ fn collect_symbol_chip(
 
             let font: Handle<Font> = asset_server.load(crate::SYMBOL_FONT_PATH);
             commands.spawn((
-                Text2d::new(format!(
-                    "Symbol Chip: Station {} = {}",
-                    chip.station_index + 1,
-                    SYMBOL_CHARS[chip.symbol as usize]
-                )),
+                Text2d::new(tr_args("clue.symbol_chip", &[
+                    ("station", &(chip.station_index + 1)),
+                    ("value", &SYMBOL_CHARS[chip.symbol as usize]),
+                ])),
                 TextFont { font, font_size: 18.0, ..default() },
                 TextColor(Color::srgb(0.8, 0.4, 1.0)),
                 Transform::from_translation(cp + Vec3::new(0.0, TILE_SIZE, 10.0)),
@@ -0,0 +1,404 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::{GameEntity, GameState};
+use crate::player::Player;
+
+// ── Kinds ──────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum StatusKind {
+    /// Lower top speed and fire rate, per stack.
+    Slow,
+    /// Damage over time, per stack. Skips the player's shield and armor.
+    Burn,
+    /// Can't move or shoot.
+    Stun,
+    /// Can't move; fires at half rate.
+    Freeze,
+    /// Takes extra damage from every source, per stack.
+    Vulnerable,
+    /// Damage over time from an empty air tank. Skips the player's shield
+    /// and armor, like Burn.
+    Suffocation,
+}
+
+impl StatusKind {
+    pub const ALL: [StatusKind; 6] = [
+        StatusKind::Slow,
+        StatusKind::Burn,
+        StatusKind::Stun,
+        StatusKind::Freeze,
+        StatusKind::Vulnerable,
+        StatusKind::Suffocation,
+    ];
+
+    pub fn max_stacks(self) -> u32 {
+        match self {
+            StatusKind::Slow => 3,
+            StatusKind::Burn => 5,
+            StatusKind::Stun | StatusKind::Freeze => 1,
+            StatusKind::Vulnerable => 3,
+            StatusKind::Suffocation => 3,
+        }
+    }
+
+    /// One-letter tag for the HUD.
+    pub fn letter(self) -> &'static str {
+        match self {
+            StatusKind::Slow => "S",
+            StatusKind::Burn => "B",
+            StatusKind::Stun => "!",
+            StatusKind::Freeze => "F",
+            StatusKind::Vulnerable => "V",
+            StatusKind::Suffocation => "O",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            StatusKind::Slow => Color::srgb(0.55, 0.55, 0.9),
+            StatusKind::Burn => Color::srgb(1.0, 0.45, 0.1),
+            StatusKind::Stun => Color::srgb(1.0, 0.95, 0.3),
+            StatusKind::Freeze => Color::srgb(0.6, 0.95, 1.0),
+            StatusKind::Vulnerable => Color::srgb(0.9, 0.2, 0.6),
+            StatusKind::Suffocation => Color::srgb(0.5, 0.7, 0.5),
+        }
+    }
+}
+
+// ── Tuning ─────────────────────────────────────────────────────────────────
+
+const SLOW_PER_STACK: f32 = 0.2;
+const SLOW_FLOOR: f32 = 0.4;
+const FREEZE_FIRE_MULT: f32 = 0.5;
+const BURN_DPS: f32 = 5.0;
+const SUFFOCATION_DPS: f32 = 5.0;
+const VULNERABLE_PER_STACK: f32 = 0.2;
+
+const ICON_SIZE: f32 = 5.0;
+const ICON_GAP: f32 = 2.0;
+
+// ── Components ─────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub remaining: f32,
+    pub stacks: u32,
+}
+
+/// Timed, stackable debuffs on a player or enemy. Inserted on first
+/// `ApplyStatus`; movement, firing and damage code read the multipliers.
+#[derive(Component, Default)]
+pub struct StatusEffects(pub Vec<StatusEffect>);
+
+impl StatusEffects {
+    /// Adds stacks (capped) and extends the timer to at least `duration`.
+    pub fn apply(&mut self, kind: StatusKind, duration: f32, stacks: u32) {
+        if let Some(e) = self.0.iter_mut().find(|e| e.kind == kind) {
+            e.stacks = (e.stacks + stacks).min(kind.max_stacks());
+            e.remaining = e.remaining.max(duration);
+        } else {
+            self.0.push(StatusEffect {
+                kind,
+                remaining: duration,
+                stacks: stacks.clamp(1, kind.max_stacks()),
+            });
+        }
+    }
+
+    pub fn stacks(&self, kind: StatusKind) -> u32 {
+        self.0.iter().find(|e| e.kind == kind).map_or(0, |e| e.stacks)
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.stacks(kind) > 0
+    }
+
+    /// Scales top speed. Zero while stunned or frozen.
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.has(StatusKind::Stun) || self.has(StatusKind::Freeze) {
+            return 0.0;
+        }
+        (1.0 - SLOW_PER_STACK * self.stacks(StatusKind::Slow) as f32).max(SLOW_FLOOR)
+    }
+
+    /// Scales how fast fire cooldowns tick. Zero while stunned.
+    pub fn fire_rate_multiplier(&self) -> f32 {
+        if self.has(StatusKind::Stun) {
+            return 0.0;
+        }
+        let freeze = if self.has(StatusKind::Freeze) { FREEZE_FIRE_MULT } else { 1.0 };
+        let slow = (1.0 - SLOW_PER_STACK * self.stacks(StatusKind::Slow) as f32).max(SLOW_FLOOR);
+        freeze * slow
+    }
+
+    pub fn damage_taken_multiplier(&self) -> f32 {
+        1.0 + VULNERABLE_PER_STACK * self.stacks(StatusKind::Vulnerable) as f32
+    }
+
+    /// Combined damage per second from all DoT effects.
+    fn dps(&self) -> f32 {
+        BURN_DPS * self.stacks(StatusKind::Burn) as f32
+            + SUFFOCATION_DPS * self.stacks(StatusKind::Suffocation) as f32
+    }
+}
+
+/// Shorthand for damage code that may or may not have `StatusEffects` in hand.
+pub fn damage_taken_multiplier(status: Option<&StatusEffects>) -> f32 {
+    status.map_or(1.0, StatusEffects::damage_taken_multiplier)
+}
+
+pub fn speed_multiplier(status: Option<&StatusEffects>) -> f32 {
+    status.map_or(1.0, StatusEffects::speed_multiplier)
+}
+
+pub fn fire_rate_multiplier(status: Option<&StatusEffects>) -> f32 {
+    status.map_or(1.0, StatusEffects::fire_rate_multiplier)
+}
+
+/// Attach to a bullet to apply a status to whatever it hits.
+#[derive(Component, Clone, Copy)]
+pub struct OnHitStatus {
+    pub kind: StatusKind,
+    pub duration: f32,
+    pub stacks: u32,
+}
+
+/// World-space icon row above an enemy's health bar.
+#[derive(Component)]
+struct StatusIcon(StatusKind);
+
+/// HUD slot for one status kind on the player.
+#[derive(Component)]
+struct StatusHudSlot(StatusKind);
+
+#[derive(Component)]
+struct StatusHudStacks(StatusKind);
+
+// ── Event ──────────────────────────────────────────────────────────────────
+
+/// Hook for weapons, rewards and hazards: apply `stacks` of `kind` to
+/// `target` for at least `duration` seconds.
+#[derive(Event)]
+pub struct ApplyStatus {
+    pub target: Entity,
+    pub kind: StatusKind,
+    pub duration: f32,
+    pub stacks: u32,
+}
+
+// ── Plugin ─────────────────────────────────────────────────────────────────
+
+pub struct StatusPlugin;
+
+impl Plugin for StatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ApplyStatus>()
+            .add_systems(OnEnter(GameState::Playing), spawn_status_hud)
+            .add_systems(
+                Update,
+                (
+                    apply_status_events,
+                    tick_status_effects.after(apply_status_events).in_set(crate::enemies::EnemyDamageSet),
+                    spawn_enemy_status_icons,
+                    update_enemy_status_icons.after(tick_status_effects),
+                    update_status_hud.after(tick_status_effects),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+// ── Systems ────────────────────────────────────────────────────────────────
+
+pub(crate) fn apply_status_events(
+    mut commands: Commands,
+    mut events: EventReader<ApplyStatus>,
+    mut targets: Query<Option<&mut StatusEffects>>,
+) {
+    // Targets without the component yet are batched so several events in one
+    // frame don't overwrite each other's insert.
+    let mut fresh: HashMap<Entity, StatusEffects> = HashMap::new();
+    for ev in events.read() {
+        match targets.get_mut(ev.target) {
+            Ok(Some(mut status)) => status.apply(ev.kind, ev.duration, ev.stacks),
+            Ok(None) => fresh.entry(ev.target).or_default().apply(ev.kind, ev.duration, ev.stacks),
+            Err(_) => {}
+        }
+    }
+    for (entity, status) in fresh {
+        commands.entity(entity).try_insert(status);
+    }
+}
+
+/// Counts down effects and deals DoT. DoT skips the player's shield and armor
+/// but is still scaled by vulnerability; an elite's affix shield soaks it
+/// after the fact like any other damage.
+pub(crate) fn tick_status_effects(
+    time: Res<Time>,
+    mut players: Query<(&mut StatusEffects, &mut crate::player::Health), With<Player>>,
+    mut enemies: Query<(&mut StatusEffects, &mut crate::enemies::Health), Without<Player>>,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
+) {
+    use crate::run_history::{DamageSource, StatEvent};
+
+    let dt = time.delta_secs();
+    let mut taken = 0.0;
+    for (mut status, mut hp) in &mut players {
+        let dot = status.dps() * status.damage_taken_multiplier() * dt;
+        hp.0 -= dot;
+        taken += dot;
+        expire(&mut status, dt);
+    }
+    let mut dealt = 0.0;
+    for (mut status, mut hp) in &mut enemies {
+        let dot = status.dps() * status.damage_taken_multiplier() * dt;
+        hp.0 -= dot;
+        dealt += dot;
+        expire(&mut status, dt);
+    }
+    // Summed so ticking DoT doesn't send one event per entity per frame.
+    if taken > 0.0 {
+        stat_events.write(StatEvent::DamageTaken(DamageSource::Status, taken));
+    }
+    if dealt > 0.0 {
+        stat_events.write(StatEvent::DamageDealt(DamageSource::Status, dealt));
+    }
+}
+
+fn expire(status: &mut StatusEffects, dt: f32) {
+    for e in &mut status.0 {
+        e.remaining -= dt;
+    }
+    status.0.retain(|e| e.remaining > 0.0);
+}
+
+fn spawn_enemy_status_icons(
+    mut commands: Commands,
+    q: Query<Entity, (Added<StatusEffects>, With<crate::enemies::Enemy>)>,
+) {
+    let y = crate::enemies::BAR_Y_OFFSET + crate::enemies::BAR_HEIGHT + ICON_SIZE * 2.0 + 4.0;
+    for entity in &q {
+        commands.entity(entity).with_children(|parent| {
+            for kind in StatusKind::ALL {
+                parent.spawn((
+                    Sprite::from_color(kind.color(), Vec2::splat(ICON_SIZE)),
+                    Transform::from_xyz(0.0, y, 3.0),
+                    Visibility::Hidden,
+                    StatusIcon(kind),
+                ));
+            }
+        });
+    }
+}
+
+/// Shows one icon per active effect, packed and centred over the bar.
+fn update_enemy_status_icons(
+    enemies: Query<(&StatusEffects, &Children)>,
+    mut icons: Query<(&StatusIcon, &mut Transform, &mut Visibility)>,
+) {
+    for (status, children) in &enemies {
+        let active = status.0.len() as f32;
+        let start_x = -(active - 1.0) * (ICON_SIZE + ICON_GAP) * 0.5;
+        for child in children.iter() {
+            let Ok((icon, mut tf, mut vis)) = icons.get_mut(child) else { continue };
+            match status.0.iter().position(|e| e.kind == icon.0) {
+                Some(slot) => {
+                    *vis = Visibility::Inherited;
+                    tf.translation.x = start_x + slot as f32 * (ICON_SIZE + ICON_GAP);
+                }
+                None => *vis = Visibility::Hidden,
+            }
+        }
+    }
+}
+
+fn spawn_status_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font: Handle<Font> = asset_server.load(crate::FONT_PATH);
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(40.0),
+                left: Val::Px(10.0),
+                column_gap: Val::Px(4.0),
+                ..default()
+            },
+            ZIndex(10),
+            GameEntity,
+        ))
+        .with_children(|row| {
+            for kind in StatusKind::ALL {
+                row.spawn((
+                    Node {
+                        width: Val::Px(22.0),
+                        height: Val::Px(22.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        display: Display::None,
+                        ..default()
+                    },
+                    BackgroundColor(kind.color().with_alpha(0.85)),
+                    BorderRadius::all(Val::Px(3.0)),
+                    StatusHudSlot(kind),
+                ))
+                .with_children(|slot| {
+                    slot.spawn((
+                        Text::new(kind.letter()),
+                        TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                        TextColor(Color::BLACK),
+                        StatusHudStacks(kind),
+                    ));
+                });
+            }
+        });
+}
+
+fn update_status_hud(
+    player_q: Query<Option<&StatusEffects>, With<Player>>,
+    mut slots: Query<(&StatusHudSlot, &mut Node)>,
+    mut texts: Query<(&StatusHudStacks, &mut Text)>,
+) {
+    let Ok(status) = player_q.single() else { return };
+    for (slot, mut node) in &mut slots {
+        let stacks = status.map_or(0, |s| s.stacks(slot.0));
+        node.display = if stacks > 0 { Display::Flex } else { Display::None };
+    }
+    for (label, mut text) in &mut texts {
+        let stacks = status.map_or(0, |s| s.stacks(label.0));
+        text.0 = if stacks > 1 {
+            format!("{}{}", label.0.letter(), stacks)
+        } else {
+            label.0.letter().to_string()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::{Armor, Health, Shield};
+    use std::time::Duration;
+
+    #[test]
+    fn burn_skips_player_shield_and_armor() {
+        let mut app = App::new();
+        app.add_event::<crate::run_history::StatEvent>()
+            .init_resource::<Time>()
+            .add_systems(Update, tick_status_effects);
+        let mut status = StatusEffects::default();
+        status.apply(StatusKind::Burn, 2.0, 1);
+        let player = app.world_mut().spawn((Player, Health(100.0), Shield::new(50.0), Armor(100.0), status)).id();
+
+        for _ in 0..60 {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            app.update();
+        }
+
+        let world = app.world();
+        let hp = world.get::<Health>(player).unwrap().0;
+        assert!((hp - (100.0 - BURN_DPS)).abs() < 0.5, "hp {hp}");
+        assert_eq!(world.get::<Shield>(player).unwrap().current, 50.0);
+    }
+}
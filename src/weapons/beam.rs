@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::{GameEntity, TILE_SIZE};
+use crate::player::Player;
+use crate::replay::{Action, PlayerActions};
+use crate::table;
+use crate::window;
+use super::{FireMode, SfxCooldown, WeaponInventory, WeaponRegistry};
+
+// ── Components ───────────────────────────────────────────────────────────────
+
+/// The stretched sprite drawn along the current beam segment.
+#[derive(Component)]
+pub struct BeamVisual;
+
+// ── Tuning ───────────────────────────────────────────────────────────────────
+
+/// Offset from the player's centre the beam starts at, matching projectile spawns.
+const MUZZLE_OFFSET: f32 = 16.0;
+/// Ray-march step as a fraction of a tile; small enough not to skip a wall corner.
+const STEP_FRACTION: f32 = 0.25;
+const TABLE_HALF: f32 = TILE_SIZE * 0.5;
+
+// ── Setup ────────────────────────────────────────────────────────────────────
+
+pub fn spawn_beam_visual(mut commands: Commands) {
+    commands.spawn((
+        Sprite::default(),
+        Transform::from_xyz(0.0, 0.0, 910.0),
+        Visibility::Hidden,
+        BeamVisual,
+        GameEntity,
+    ));
+}
+
+// ── Raycast ──────────────────────────────────────────────────────────────────
+
+/// What a beam ray ran into before `range` ran out.
+struct BeamHit {
+    length: f32,
+    table: Option<Entity>,
+    glass: Vec<Entity>,
+}
+
+/// Marches from `from` along `dir` through the wall grid. Stops at the first
+/// solid wall tile or intact table; intact windows are collected and passed through.
+fn cast(
+    from: Vec2,
+    dir: Vec2,
+    range: f32,
+    wall_grid: &crate::map::WallGrid,
+    tables: &[(Entity, Vec2)],
+    windows: &HashMap<(i32, i32), Entity>,
+) -> BeamHit {
+    let step = wall_grid.cell_size * STEP_FRACTION;
+    let mut hit = BeamHit { length: range, table: None, glass: Vec::new() };
+    let mut d = 0.0;
+    while d < range {
+        d = (d + step).min(range);
+        let p = from + dir * d;
+
+        let (col, row) = wall_grid.world_to_tile(p);
+        if wall_grid.is_wall_tile(col, row) {
+            match windows.get(&(col, row)) {
+                Some(&glass) => {
+                    if !hit.glass.contains(&glass) {
+                        hit.glass.push(glass);
+                    }
+                }
+                None => {
+                    hit.length = d;
+                    return hit;
+                }
+            }
+        }
+
+        if let Some(&(table, _)) = tables.iter().find(|(_, t)| {
+            (p.x - t.x).abs() < TABLE_HALF && (p.y - t.y).abs() < TABLE_HALF
+        }) {
+            hit.length = d;
+            hit.table = Some(table);
+            return hit;
+        }
+    }
+    hit
+}
+
+// ── Systems ──────────────────────────────────────────────────────────────────
+
+/// Fires the equipped beam weapon while the shoot binding is held: raycasts,
+/// damages everything along the segment, spends the weapon's ammo and
+/// updates the beam sprite.
+pub fn fire_beam(
+    mut commands: Commands,
+    time: Res<Time>,
+    actions: Res<PlayerActions>,
+    mut player_q: Query<(&Transform, &mut WeaponInventory), With<Player>>,
+    registry: Res<WeaponRegistry>,
+    mut sfx: ResMut<SfxCooldown>,
+    mut enemies: Query<
+        (Entity, &Transform, &mut crate::enemies::Health, Option<&crate::collidable::Collider>, Option<&crate::status::StatusEffects>, Has<crate::enemies::Reaper>),
+        With<crate::enemies::Enemy>,
+    >,
+    reaper_state: Res<crate::enemies::reaper::ReaperState>,
+    mut tables: Query<(Entity, &Transform, &mut table::Health, &table::TableState), With<table::Table>>,
+    mut windows: Query<(Entity, &Transform, &mut window::Health, &window::GlassState), With<window::Window>>,
+    wall_grid: Res<crate::map::WallGrid>,
+    mut visual_q: Query<
+        (&mut Sprite, &mut Transform, &mut Visibility),
+        (With<BeamVisual>, Without<Player>, Without<crate::enemies::Enemy>, Without<table::Table>, Without<window::Window>),
+    >,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
+) {
+    let dt = time.delta_secs();
+    let Ok((mut sprite, mut beam_tf, mut visibility)) = visual_q.single_mut() else { return };
+    *visibility = Visibility::Hidden;
+
+    let Ok((player_tf, mut inv)) = player_q.single_mut() else { return };
+    let firing = (|| {
+        let weapon = inv.current();
+        let loaded = registry.get_or_default(&weapon.id);
+        let FireMode::Beam { range, width } = loaded.def.fire_mode else { return None };
+        if !weapon.has_ammo() || !actions.pressed(Action::Fire) {
+            return None;
+        }
+
+        let world_pos = actions.aim?;
+        let player_pos = player_tf.translation.truncate();
+        let dir = (world_pos - player_pos).normalize_or_zero();
+        if dir == Vec2::ZERO {
+            return None;
+        }
+        Some((loaded, player_pos + dir * MUZZLE_OFFSET, dir, range, width))
+    })();
+    let Some((loaded, from, dir, range, width)) = firing else { return };
+
+    // One shot's worth of ammo and damage per `fire_rate` seconds of contact.
+    let weapon = inv.current_mut();
+    let shots = dt / weapon.fire_rate.max(f32::EPSILON);
+    weapon.spend_ammo(shots);
+    let weapon = inv.current();
+
+    let intact_tables: Vec<(Entity, Vec2)> = tables
+        .iter()
+        .filter(|(_, _, _, state)| **state == table::TableState::Intact)
+        .map(|(e, tf, _, _)| (e, tf.translation.truncate()))
+        .collect();
+    let intact_windows: HashMap<(i32, i32), Entity> = windows
+        .iter()
+        .filter(|(_, _, _, state)| **state == window::GlassState::Intact)
+        .map(|(e, tf, _, _)| (wall_grid.world_to_tile(tf.translation.truncate()), e))
+        .collect();
+    let hit = cast(from, dir, range, &wall_grid, &intact_tables, &intact_windows);
+
+    let damage = weapon.damage * shots;
+
+    // Enemies along the segment, nearest first, up to the pierce count.
+    let mut along: Vec<(f32, Entity)> = enemies
+        .iter()
+        // Like bullets, the beam only hurts the reaper once it's vulnerable.
+        .filter(|(_, _, _, _, _, is_reaper)| !is_reaper || reaper_state.vulnerable)
+        .filter_map(|(e, tf, _, collider, _, _)| {
+            let half = collider
+                .map(|c| c.half_extents)
+                .unwrap_or_else(|| Vec2::splat(crate::enemies::ENEMY_SIZE * 0.5));
+            let rel = tf.translation.truncate() - from;
+            let t = rel.dot(dir);
+            let off = (rel - dir * t).length();
+            (t >= 0.0 && t <= hit.length && off <= half.max_element() + width * 0.5).then_some((t, e))
+        })
+        .collect();
+    along.sort_by(|a, b| a.0.total_cmp(&b.0));
+    for &(_, e) in along.iter().take(1 + weapon.effective_pierce_count() as usize) {
+        if let Ok((_, _, mut hp, _, status, _)) = enemies.get_mut(e) {
+            let dealt = damage * crate::status::damage_taken_multiplier(status);
+            hp.0 -= dealt;
+            stat_events.write(crate::run_history::StatEvent::DamageDealt(crate::run_history::DamageSource::Beam, dealt));
+        }
+    }
+
+    if let Some(t) = hit.table
+        && let Ok((_, _, mut hp, _)) = tables.get_mut(t)
+    {
+        hp.0 -= damage;
+    }
+    for glass in hit.glass {
+        if let Ok((_, _, mut hp, _)) = windows.get_mut(glass) {
+            hp.0 -= damage;
+        }
+    }
+
+    sprite.image = loaded.image.clone();
+    sprite.custom_size = Some(Vec2::new(hit.length, width));
+    beam_tf.translation = (from + dir * hit.length * 0.5).extend(beam_tf.translation.z);
+    beam_tf.rotation = Quat::from_rotation_z(dir.to_angle());
+    *visibility = Visibility::Inherited;
+
+    super::play_player_sfx(&mut commands, &mut sfx, &loaded.sound);
+}
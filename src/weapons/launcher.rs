@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+use crate::GameEntity;
+use crate::bullet::Velocity;
+use crate::player::{Player, Health, Armor, Shield, aabb_overlap};
+use crate::run_history::{DamageSource, StatEvent};
+use crate::status::StatusEffects;
+use crate::table;
+use super::{LoadedWeapon, Weapon};
+
+// ── Components ───────────────────────────────────────────────────────────────
+
+/// A lobbed projectile. It arcs over everything from `start` to `landing`
+/// and bursts there, or early if it clips a wall.
+#[derive(Component)]
+pub struct Grenade {
+    start: Vec2,
+    landing: Vec2,
+    elapsed: f32,
+    flight_time: f32,
+    base_scale: f32,
+    damage: f32,
+    radius: f32,
+    knockback: f32,
+    self_damage: f32,
+}
+
+/// Short-lived blast flash left behind by a grenade.
+#[derive(Component)]
+pub struct Explosion(Timer);
+
+// ── Tuning ───────────────────────────────────────────────────────────────────
+
+const MIN_FLIGHT_TIME: f32 = 0.15;
+/// Extra scale at the top of the arc, to sell the lob.
+const ARC_SCALE: f32 = 0.6;
+/// Damage multiplier at the very edge of the blast radius.
+const EDGE_DAMAGE: f32 = 0.4;
+const EXPLOSION_COLOR: Color = Color::srgba(1.0, 0.55, 0.15, 0.6);
+const EXPLOSION_TIME: f32 = 0.25;
+
+// ── Spawn ────────────────────────────────────────────────────────────────────
+
+pub fn spawn_grenade(
+    commands: &mut Commands,
+    loaded: &LoadedWeapon,
+    weapon: &Weapon,
+    pos: Vec2,
+    landing: Vec2,
+    radius: f32,
+    self_damage: f32,
+) {
+    let sprite = match &loaded.atlas {
+        Some(layout) => Sprite::from_atlas_image(
+            loaded.image.clone(),
+            TextureAtlas { layout: layout.clone(), index: 0 },
+        ),
+        None => Sprite::from_image(loaded.image.clone()),
+    };
+    commands.spawn((
+        sprite,
+        Transform {
+            translation: pos.extend(910.0),
+            scale: Vec3::splat(weapon.bullet_size),
+            ..Default::default()
+        },
+        Grenade {
+            start: pos,
+            landing,
+            elapsed: 0.0,
+            flight_time: (pos.distance(landing) / weapon.bullet_speed.max(1.0)).max(MIN_FLIGHT_TIME),
+            base_scale: weapon.bullet_size,
+            damage: weapon.damage,
+            radius,
+            knockback: loaded.def.knockback,
+            self_damage,
+        },
+        GameEntity,
+    ));
+}
+
+// ── Systems ──────────────────────────────────────────────────────────────────
+
+pub fn move_grenades(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut grenades: Query<(Entity, &mut Transform, &mut Grenade)>,
+    mut enemies: Query<
+        (&Transform, &mut crate::enemies::Health, Option<&mut crate::enemies::Velocity>, Option<&StatusEffects>, Has<crate::enemies::Reaper>),
+        (With<crate::enemies::Enemy>, Without<table::Table>, Without<Grenade>),
+    >,
+    reaper_state: Res<crate::enemies::reaper::ReaperState>,
+    mut tables: Query<
+        (&Transform, &mut table::Health, &mut crate::enemies::Velocity, &table::TableState),
+        (With<table::Table>, Without<Grenade>),
+    >,
+    mut player_q: Query<
+        (&Transform, &mut Velocity, &mut Health, &Armor, &mut Shield, Option<&StatusEffects>),
+        (With<Player>, Without<Grenade>),
+    >,
+    wall_grid: Res<crate::map::WallGrid>,
+    mut stat_events: EventWriter<StatEvent>,
+) {
+    for (entity, mut tf, mut grenade) in &mut grenades {
+        grenade.elapsed += time.delta_secs();
+        let t = (grenade.elapsed / grenade.flight_time).min(1.0);
+        let pos = grenade.start.lerp(grenade.landing, t);
+        tf.translation = pos.extend(tf.translation.z);
+        tf.scale = Vec3::splat(grenade.base_scale * (1.0 + ARC_SCALE * (t * std::f32::consts::PI).sin()));
+
+        let hit_wall = wall_grid
+            .nearby(pos, 1)
+            .into_iter()
+            .any(|(wall_pos, wall_half)| aabb_overlap(pos.x, pos.y, Vec2::ZERO, wall_pos.x, wall_pos.y, wall_half));
+        if t < 1.0 && !hit_wall {
+            continue;
+        }
+
+        commands.entity(entity).despawn();
+        commands.spawn((
+            Sprite {
+                color: EXPLOSION_COLOR,
+                custom_size: Some(Vec2::splat(grenade.radius * 2.0)),
+                ..default()
+            },
+            Transform::from_translation(pos.extend(905.0)),
+            Explosion(Timer::from_seconds(EXPLOSION_TIME, TimerMode::Once)),
+            GameEntity,
+        ));
+
+        // Returns the damage multiplier and push for something at `at`, if it's in the blast.
+        let blast = |at: Vec2| -> Option<(f32, Vec2)> {
+            let d = at.distance(pos);
+            if d > grenade.radius { return None; }
+            let falloff = 1.0 - (1.0 - EDGE_DAMAGE) * d / grenade.radius;
+            Some((falloff, (at - pos).normalize_or_zero() * grenade.knockback * falloff))
+        };
+
+        for (etf, mut hp, vel, status, is_reaper) in &mut enemies {
+            // The reaper only takes blast damage once it's vulnerable, and is never pushed.
+            if is_reaper && !reaper_state.vulnerable { continue; }
+            let Some((mult, push)) = blast(etf.translation.truncate()) else { continue };
+            let dealt = grenade.damage * mult * crate::status::damage_taken_multiplier(status);
+            hp.0 -= dealt;
+            stat_events.write(StatEvent::DamageDealt(DamageSource::Grenade, dealt));
+            if is_reaper { continue; }
+            if let Some(mut vel) = vel {
+                **vel += push;
+            }
+        }
+
+        for (ttf, mut hp, mut vel, state) in &mut tables {
+            if *state != table::TableState::Intact { continue; }
+            let Some((mult, push)) = blast(ttf.translation.truncate()) else { continue };
+            hp.0 -= grenade.damage * mult;
+            **vel += push;
+        }
+
+        if let Ok((ptf, mut vel, mut hp, armor, mut shield, status)) = player_q.single_mut()
+            && let Some((mult, push)) = blast(ptf.translation.truncate())
+        {
+            **vel += push;
+            if shield.current >= 1.0 {
+                shield.current -= 1.0;
+            } else {
+                let taken = grenade.damage * mult * grenade.self_damage
+                    * crate::player::armor_factor(armor.0)
+                    * crate::status::damage_taken_multiplier(status);
+                hp.0 -= taken;
+                stat_events.write(StatEvent::DamageTaken(DamageSource::Grenade, taken));
+            }
+        }
+    }
+}
+
+pub fn fade_explosions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut explosions: Query<(Entity, &mut Sprite, &mut Explosion)>,
+) {
+    for (entity, mut sprite, mut explosion) in &mut explosions {
+        explosion.0.tick(time.delta());
+        if explosion.0.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        sprite.color = EXPLOSION_COLOR.with_alpha(EXPLOSION_COLOR.alpha() * explosion.0.fraction_remaining());
+    }
+}
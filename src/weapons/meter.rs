@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use crate::GameEntity;
+use crate::player::Player;
+use super::{AmmoDef, FireMode, WeaponInventory, WeaponRegistry};
+
+// ── HUD ─────────────────────────────────────────────────────────────────────
+
+const METER_WIDTH: f32 = 120.0;
+const METER_HEIGHT: f32 = 8.0;
+const FILL_COLOR: Color = Color::srgb(0.3, 0.8, 1.0);
+const FULL_COLOR: Color = Color::srgb(1.0, 0.9, 0.3);
+const HEAT_COLOR: Color = Color::srgb(1.0, 0.5, 0.2);
+const OVERHEAT_COLOR: Color = Color::srgb(1.0, 0.15, 0.1);
+
+/// Root of the weapon meter: charge for charge weapons, otherwise heat or
+/// reload progress. Hidden for weapons with none of those.
+#[derive(Component)]
+pub struct WeaponMeter;
+
+#[derive(Component)]
+pub struct WeaponMeterFill;
+
+pub fn spawn_weapon_meter(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(70.0),
+                left: Val::Px(10.0),
+                width: Val::Px(METER_WIDTH),
+                height: Val::Px(METER_HEIGHT),
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.8)),
+            BorderRadius::all(Val::Px(2.0)),
+            WeaponMeter,
+            GameEntity,
+        ))
+        .with_children(|meter| {
+            meter.spawn((
+                Node {
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(FILL_COLOR),
+                BorderRadius::all(Val::Px(2.0)),
+                WeaponMeterFill,
+            ));
+        });
+}
+
+pub fn update_weapon_meter(
+    player_q: Query<&WeaponInventory, With<Player>>,
+    registry: Res<WeaponRegistry>,
+    mut meter_q: Query<&mut Node, (With<WeaponMeter>, Without<WeaponMeterFill>)>,
+    mut fill_q: Query<(&mut Node, &mut BackgroundColor), With<WeaponMeterFill>>,
+) {
+    let Ok(inv) = player_q.single() else { return };
+    let Ok(mut meter) = meter_q.single_mut() else { return };
+    let Ok((mut fill, mut color)) = fill_q.single_mut() else { return };
+
+    let weapon = inv.current();
+    let (t, fill_color) = match registry.get_or_default(&weapon.id).def.fire_mode {
+        FireMode::Charge { max_time, .. } => {
+            let t = (weapon.charge / max_time.max(f32::EPSILON)).clamp(0.0, 1.0);
+            (t, if t >= 1.0 { FULL_COLOR } else { FILL_COLOR })
+        }
+        _ => match weapon.ammo_def {
+            Some(AmmoDef::Heat { .. }) => {
+                (weapon.heat, if weapon.overheated { OVERHEAT_COLOR } else { HEAT_COLOR })
+            }
+            Some(AmmoDef::Magazine { reload_time, .. }) if weapon.reload_left > 0.0 => {
+                (1.0 - weapon.reload_left / reload_time.max(f32::EPSILON), FILL_COLOR)
+            }
+            _ => {
+                meter.display = Display::None;
+                return;
+            }
+        },
+    };
+    meter.display = Display::Flex;
+    fill.width = Val::Percent(t * 100.0);
+    color.0 = fill_color;
+}
@@ -1,63 +1,164 @@
-pub mod zapper;
-pub mod beam_rifle;
+pub mod registry;
+pub mod meter;
+pub mod launcher;
+pub mod beam;
+pub mod mods;
 
-pub use beam_rifle::BeamRifleRes;
+pub use registry::{WeaponDef, WeaponRegistry, LoadedWeapon, FireMode, AmmoDef, DEFAULT_WEAPON};
+pub use mods::{WeaponMod, BulletMods};
 
 use bevy::prelude::*;
 use bevy::audio::Volume;
-use crate::GameEntity;
-use crate::bullet::{Bullet, BulletOwner, Velocity, AnimationTimer, AnimationFrameCount, Piercing, HitEnemies};
+use std::collections::HashMap;
+use rand::Rng;
+use crate::{GameEntity, RunRng};
+use crate::locale::{tr, tr_args};
+use crate::bullet::{Bullet, BulletOwner, Velocity, AnimationTimer, AnimationFrameCount, Piercing, HitEnemies, Knockback, DamageFalloff};
 use crate::collidable::Collider;
 
+/// A weapon instance. Base stats come from its `WeaponDef`; rewards then
+/// modify the copy held here, so `id` is only used to look up visuals/behaviour.
 #[derive(Component, Clone)]
 pub struct Weapon {
-    pub weapon_type: WeaponType,
+    pub id: String,
+    /// String-table key for the weapon's name; see `name`.
+    pub name_key: String,
     pub fire_rate: f32,
     pub bullet_speed: f32,
     pub damage: f32,
     pub bullet_size: f32,
     pub shoot_timer: Timer,
+    /// Pierce from the definition, before pickups.
+    pub base_pierce: u32,
     pub piercing_pickups: u32,
+    /// Seconds the shoot binding has been held, for `FireMode::Charge` weapons.
+    pub charge: f32,
+    pub ammo_def: Option<AmmoDef>,
+    /// 0..=1 gauge for `AmmoDef::Heat` weapons.
+    pub heat: f32,
+    /// Set when `heat` hits 1; firing is locked until it vents back to 0.
+    pub overheated: bool,
+    /// Rounds left for `AmmoDef::Magazine` weapons.
+    pub ammo: u32,
+    /// Seconds left on the current reload; 0 when not reloading.
+    pub reload_left: f32,
+    /// Seconds since this weapon last fired; venting waits for `VENT_DELAY`.
+    pub since_fired: f32,
+    /// Multiplier on venting and reload speed. Raised by attack-speed rewards.
+    pub cooling: f32,
+    pub mod_slots: u32,
+    /// Installed attachments, oldest first.
+    pub mods: Vec<WeaponMod>,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub enum WeaponType {
-    Zapper,
-    BeamRifle,
-}
+/// Heat only starts venting this long after the last shot.
+const VENT_DELAY: f32 = 0.3;
 
-impl WeaponType {
-    pub fn name(self) -> &'static str {
-        match self {
-            WeaponType::Zapper => "Zapper",
-            WeaponType::BeamRifle => "Beam Rifle",
-        }
+impl Weapon {
+    pub fn name(&self) -> String {
+        tr(&self.name_key)
     }
-}
 
-impl Weapon {
-    pub fn new(weapon_type: WeaponType) -> Self {
-        match weapon_type {
-            WeaponType::Zapper => zapper::new(),
-            WeaponType::BeamRifle => beam_rifle::new(),
+    pub fn from_def(def: &WeaponDef) -> Self {
+        Self {
+            id: def.id.clone(),
+            name_key: def.name_key.clone(),
+            fire_rate: def.fire_rate,
+            bullet_speed: def.speed,
+            damage: def.damage,
+            bullet_size: def.size,
+            shoot_timer: Timer::from_seconds(def.fire_rate, TimerMode::Once),
+            base_pierce: def.pierce,
+            piercing_pickups: 0,
+            charge: 0.0,
+            ammo_def: def.ammo,
+            heat: 0.0,
+            overheated: false,
+            ammo: match def.ammo {
+                Some(AmmoDef::Magazine { size, .. }) => size,
+                _ => 0,
+            },
+            reload_left: 0.0,
+            since_fired: VENT_DELAY,
+            cooling: 1.0,
+            mod_slots: def.mod_slots,
+            mods: Vec::new(),
         }
     }
 
     pub fn can_shoot(&self) -> bool {
-        self.shoot_timer.finished()
+        self.shoot_timer.finished() && self.has_ammo()
+    }
+
+    /// False while overheated, reloading or out of rounds.
+    pub fn has_ammo(&self) -> bool {
+        match self.ammo_def {
+            Some(AmmoDef::Heat { .. }) => !self.overheated,
+            Some(AmmoDef::Magazine { .. }) => self.reload_left <= 0.0 && self.ammo > 0,
+            None => true,
+        }
     }
 
     pub fn reset_timer(&mut self) {
         self.shoot_timer.reset();
     }
 
+    /// Spends `shots` worth of heat or rounds. Beams pass fractional shots.
+    pub fn spend_ammo(&mut self, shots: f32) {
+        self.since_fired = 0.0;
+        match self.ammo_def {
+            Some(AmmoDef::Heat { per_shot, .. }) => {
+                self.heat = (self.heat + per_shot * shots).min(1.0);
+                if self.heat >= 1.0 {
+                    self.overheated = true;
+                }
+            }
+            Some(AmmoDef::Magazine { reload_time, .. }) => {
+                self.ammo = self.ammo.saturating_sub(shots.ceil() as u32);
+                if self.ammo == 0 {
+                    self.reload_left = reload_time;
+                }
+            }
+            None => {}
+        }
+    }
+
     pub fn tick(&mut self, delta: std::time::Duration) {
         self.shoot_timer.tick(delta);
+        let dt = delta.as_secs_f32();
+        self.since_fired += dt;
+        match self.ammo_def {
+            Some(AmmoDef::Heat { vent_time, .. }) if self.since_fired >= VENT_DELAY => {
+                self.heat = (self.heat - dt * self.cooling / vent_time.max(f32::EPSILON)).max(0.0);
+                if self.heat <= 0.0 {
+                    self.overheated = false;
+                }
+            }
+            Some(AmmoDef::Magazine { size, .. }) if self.reload_left > 0.0 => {
+                self.reload_left -= dt * self.cooling;
+                if self.reload_left <= 0.0 {
+                    self.reload_left = 0.0;
+                    self.ammo = size;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Short HUD readout of heat or rounds, empty for weapons without ammo.
+    pub fn ammo_label(&self) -> String {
+        match self.ammo_def {
+            Some(AmmoDef::Heat { .. }) if self.overheated => tr("weapon.overheat"),
+            Some(AmmoDef::Heat { .. }) => tr_args("weapon.heat", &[("pct", &format!("{:.0}", self.heat * 100.0))]),
+            Some(AmmoDef::Magazine { .. }) if self.reload_left > 0.0 => tr("weapon.reloading"),
+            Some(AmmoDef::Magazine { size, .. }) => format!("{}/{}", self.ammo, size),
+            None => String::new(),
+        }
     }
 
     pub fn effective_pierce_count(&self) -> u32 {
         let p = self.piercing_pickups;
-        if p <= 4 { p } else { 4 + (p - 4) / 2 }
+        self.base_pierce + if p <= 4 { p } else { 4 + (p - 4) / 2 }
     }
 }
 
@@ -65,11 +166,13 @@ impl Weapon {
 pub struct WeaponInventory {
     pub weapons: Vec<Weapon>,
     pub equipped: usize,
+    /// Mods picked up with no free slot, or swapped out of a weapon.
+    pub stash: Vec<WeaponMod>,
 }
 
 impl WeaponInventory {
     pub fn new(weapon: Weapon) -> Self {
-        Self { weapons: vec![weapon], equipped: 0 }
+        Self { weapons: vec![weapon], equipped: 0, stash: Vec::new() }
     }
 
     pub fn current(&self) -> &Weapon {
@@ -86,14 +189,11 @@ impl WeaponInventory {
         }
     }
 
-    pub fn equipped_name(&self) -> &'static str {
-        self.current().weapon_type.name()
+    pub fn equipped_name(&self) -> String {
+        self.current().name()
     }
 }
 
-#[derive(Resource)]
-pub struct BulletRes(pub Handle<Image>, pub Handle<TextureAtlasLayout>);
-
 #[derive(Resource)]
 pub struct EnemyBulletRes(pub Handle<Image>, pub Handle<TextureAtlasLayout>);
 
@@ -106,6 +206,13 @@ pub struct WeaponSounds {
 #[derive(Component)]
 pub struct WeaponNameDisplay;
 
+/// Heat or magazine readout shown beside the weapon name.
+#[derive(Component)]
+pub struct WeaponAmmoDisplay;
+
+const AMMO_COLOR: Color = Color::srgb(0.75, 0.75, 0.85);
+const AMMO_LOCKED_COLOR: Color = Color::srgb(1.0, 0.3, 0.2);
+
 /// Minimum seconds between plays of the same sound to prevent clipping.
 const SFX_MIN_INTERVAL: f32 = 0.12;
 /// Per-instance volume; even 3 overlapping tracks stay well under 1.0.
@@ -114,8 +221,8 @@ const SFX_VOLUME: f32 = 0.4;
 /// Tracks cooldowns per sound channel so the same effect can't stack into clipping.
 #[derive(Resource, Default)]
 pub struct SfxCooldown {
-    pub player_laser: f32,
-    pub player_shoot: f32,
+    /// Player weapon sounds, keyed by clip so weapons sharing a sound share a cooldown.
+    pub player: HashMap<AssetId<AudioSource>, f32>,
     pub enemy_laser:  f32,
 }
 
@@ -124,12 +231,19 @@ pub struct WeaponPlugin;
 impl Plugin for WeaponPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SfxCooldown>()
-            .add_systems(Startup, load_weapon_assets)
-            .add_systems(OnEnter(crate::GameState::Playing), spawn_weapon_hud)
+            .add_plugins(mods::ModsPlugin)
+            .add_systems(Startup, (load_weapon_assets, registry::load_registry))
+            .add_systems(OnEnter(crate::GameState::Playing), (spawn_weapon_hud, meter::spawn_weapon_meter, beam::spawn_beam_visual))
             .add_systems(Update, tick_sfx_cooldowns)
             .add_systems(
                 Update,
-                (update_weapon_timers, update_weapon_hud, cycle_weapons)
+                (update_weapon_timers, update_weapon_hud, cycle_weapons, meter::update_weapon_meter, beam::fire_beam.in_set(crate::enemies::EnemyDamageSet))
+                    .run_if(in_state(crate::GameState::Playing))
+                    .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+            )
+            .add_systems(
+                Update,
+                (launcher::move_grenades.in_set(crate::enemies::EnemyDamageSet), launcher::fade_explosions)
                     .run_if(in_state(crate::GameState::Playing))
                     .run_if(not(resource_exists::<crate::pause::IsPaused>)),
             );
@@ -141,11 +255,6 @@ fn load_weapon_assets(
     asset_server: Res<AssetServer>,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
 ) {
-    let bullet_animate_image: Handle<Image> = asset_server.load("bullet_animation.png");
-    let bullet_animate_layout = TextureAtlasLayout::from_grid(UVec2::splat(100), 3, 1, None, None);
-    let bullet_animate_handle = texture_atlases.add(bullet_animate_layout);
-    commands.insert_resource(BulletRes(bullet_animate_image, bullet_animate_handle));
-
     let enemy_bullet_image: Handle<Image> = asset_server.load("enemy_bullet_animation.png");
     let enemy_bullet_layout = TextureAtlasLayout::from_grid(UVec2::splat(100), 3, 1, None, None);
     let enemy_bullet_handle = texture_atlases.add(enemy_bullet_layout);
@@ -154,141 +263,210 @@ fn load_weapon_assets(
     let laser_sound: Handle<AudioSource> = asset_server.load("audio/laser_zap.ogg");
     let shoot_sound: Handle<AudioSource> = asset_server.load("audio/shoot.ogg");
     commands.insert_resource(WeaponSounds { laser: laser_sound, shoot: shoot_sound });
-
-    let beam_bullet = asset_server.load("beam.png");
-    commands.insert_resource(BeamRifleRes { bullet: beam_bullet });
 }
 
 fn spawn_weapon_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
     let font: Handle<Font> = asset_server.load(crate::FONT_PATH);
-    commands.spawn((
-        Text::new("Zapper"),
-        TextFont { font, font_size: 20.0, ..default() },
-        TextColor(Color::WHITE),
-        Node {
-            position_type: PositionType::Absolute,
-            bottom: Val::Px(10.0),
-            left: Val::Px(10.0),
-            ..default()
-        },
-        WeaponNameDisplay,
-        GameEntity,
-    ));
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                left: Val::Px(10.0),
+                column_gap: Val::Px(12.0),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            GameEntity,
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Text::new(""),
+                TextFont { font: font.clone(), font_size: 20.0, ..default() },
+                TextColor(Color::WHITE),
+                WeaponNameDisplay,
+            ));
+            row.spawn((
+                Text::new(""),
+                TextFont { font, font_size: 16.0, ..default() },
+                TextColor(AMMO_COLOR),
+                WeaponAmmoDisplay,
+            ));
+        });
 }
 
 fn update_weapon_timers(
     time: Res<Time>,
-    mut inventories: Query<&mut WeaponInventory>,
+    mut inventories: Query<(&mut WeaponInventory, Option<&crate::status::StatusEffects>)>,
 ) {
-    for mut inv in &mut inventories {
+    for (mut inv, status) in &mut inventories {
+        let delta = time.delta().mul_f32(crate::status::fire_rate_multiplier(status));
         for weapon in &mut inv.weapons {
-            weapon.tick(time.delta());
+            weapon.tick(delta);
         }
     }
 }
 
 fn update_weapon_hud(
     player_q: Query<&WeaponInventory, With<crate::player::Player>>,
-    mut text_q: Query<&mut Text, With<WeaponNameDisplay>>,
+    mut text_q: Query<&mut Text, (With<WeaponNameDisplay>, Without<WeaponAmmoDisplay>)>,
+    mut ammo_q: Query<(&mut Text, &mut TextColor), With<WeaponAmmoDisplay>>,
 ) {
     let Ok(inv) = player_q.single() else { return; };
     let Ok(mut text) = text_q.single_mut() else { return; };
-    text.0 = inv.equipped_name().to_string();
+    text.0 = inv.equipped_name();
+
+    let Ok((mut ammo_text, mut ammo_color)) = ammo_q.single_mut() else { return; };
+    let weapon = inv.current();
+    ammo_text.0 = weapon.ammo_label();
+    ammo_color.0 = if weapon.has_ammo() { AMMO_COLOR } else { AMMO_LOCKED_COLOR };
 }
 
 fn tick_sfx_cooldowns(time: Res<Time>, mut sfx: ResMut<SfxCooldown>) {
     let dt = time.delta_secs();
-    sfx.player_laser = (sfx.player_laser - dt).max(0.0);
-    sfx.player_shoot = (sfx.player_shoot - dt).max(0.0);
+    for cooldown in sfx.player.values_mut() {
+        *cooldown = (*cooldown - dt).max(0.0);
+    }
     sfx.enemy_laser  = (sfx.enemy_laser  - dt).max(0.0);
 }
 
 fn cycle_weapons(
-    input: Res<ButtonInput<KeyCode>>,
+    actions: Res<crate::replay::PlayerActions>,
     mut player_q: Query<&mut WeaponInventory, With<crate::player::Player>>,
-    bindings: Res<crate::settings::KeyBindings>,
 ) {
-    if !input.just_pressed(bindings.swap_weapon) { return; }
+    if !actions.just_pressed(crate::replay::Action::SwapWeapon) { return; }
     let Ok(mut inv) = player_q.single_mut() else { return; };
     inv.cycle_next();
 }
 
+/// Fires the equipped weapon at `target`: spawns its projectiles, plays its
+/// sound and resets its timer and charge. Returns the recoil impulse for the
+/// shooter's velocity.
 pub fn fire_weapon(
     commands: &mut Commands,
     inventory: &mut WeaponInventory,
-    bullet_res: &BulletRes,
-    beam_res: &BeamRifleRes,
-    weapon_sounds: &WeaponSounds,
+    registry: &WeaponRegistry,
     sfx: &mut SfxCooldown,
     pos: Vec2,
     dir: Vec2,
-) {
-    let sound_opt = {
-        let weapon = inventory.current();
-        match weapon.weapon_type {
-            WeaponType::Zapper => {
-                spawn_bullet(commands, bullet_res, weapon, pos, dir);
-                if sfx.player_laser <= 0.0 {
-                    sfx.player_laser = SFX_MIN_INTERVAL;
-                    Some(weapon_sounds.laser.clone())
-                } else { None }
-            }
-            WeaponType::BeamRifle => {
-                beam_rifle::spawn_bullet(commands, beam_res, weapon, pos, dir);
-                if sfx.player_shoot <= 0.0 {
-                    sfx.player_shoot = SFX_MIN_INTERVAL;
-                    Some(weapon_sounds.shoot.clone())
-                } else { None }
+    target: Vec2,
+    rng: &mut RunRng,
+) -> Vec2 {
+    let loaded = registry.get_or_default(&inventory.current().id);
+    let def = &loaded.def;
+
+    // Fire a scaled copy so charge bonuses don't stick to the weapon.
+    let mut weapon = inventory.current().clone();
+    if let FireMode::Charge { max_time, damage_scale, size_scale, pierce_bonus } = def.fire_mode {
+        let t = (weapon.charge / max_time.max(f32::EPSILON)).clamp(0.0, 1.0);
+        weapon.damage *= 1.0 + (damage_scale - 1.0) * t;
+        weapon.bullet_size *= 1.0 + (size_scale - 1.0) * t;
+        weapon.base_pierce += (pierce_bonus as f32 * t).round() as u32;
+    }
+
+    let count = def.projectile_count.max(1);
+    let spread = def.spread.to_radians();
+    for i in 0..count {
+        let angle = if count == 1 {
+            (rng.random::<f32>() - 0.5) * spread
+        } else {
+            -spread * 0.5 + spread * i as f32 / (count - 1) as f32
+        };
+        let shot_dir = Vec2::from_angle(angle).rotate(dir);
+        match def.fire_mode {
+            FireMode::Lob { range, radius, self_damage } => {
+                let offset = (target - pos).clamp_length_max(range);
+                let landing = pos + Vec2::from_angle(angle).rotate(offset);
+                launcher::spawn_grenade(commands, loaded, &weapon, pos, landing, radius, self_damage);
             }
+            _ => spawn_bullet(commands, loaded, &weapon, pos, shot_dir),
         }
-    };
-    if let Some(sound) = sound_opt {
+    }
+
+    play_player_sfx(commands, sfx, &loaded.sound);
+
+    let recoil = -dir.normalize_or_zero() * def.recoil;
+    let current = inventory.current_mut();
+    current.reset_timer();
+    current.charge = 0.0;
+    current.spend_ammo(1.0);
+    recoil
+}
+
+/// Plays a player weapon sound unless the same clip played too recently.
+fn play_player_sfx(commands: &mut Commands, sfx: &mut SfxCooldown, sound: &Handle<AudioSource>) {
+    let cooldown = sfx.player.entry(sound.id()).or_default();
+    if *cooldown <= 0.0 {
+        *cooldown = SFX_MIN_INTERVAL;
         commands.spawn((
-            AudioPlayer::new(sound),
+            AudioPlayer::new(sound.clone()),
             PlaybackSettings { volume: Volume::Linear(SFX_VOLUME), ..PlaybackSettings::DESPAWN },
         ));
     }
-    inventory.current_mut().reset_timer();
 }
 
 pub fn spawn_bullet(
     commands: &mut Commands,
-    bullet_res: &BulletRes,
+    loaded: &LoadedWeapon,
     weapon: &Weapon,
     pos: Vec2,
     dir: Vec2,
 ) {
+    let def = &loaded.def;
     let normalized_dir = dir.normalize_or_zero();
 
-    let mut bullet = commands.spawn((
-        Sprite::from_atlas_image(
-            bullet_res.0.clone(),
-            TextureAtlas {
-                layout: bullet_res.1.clone(),
-                index: 0,
-            },
+    let sprite = match &loaded.atlas {
+        Some(layout) => Sprite::from_atlas_image(
+            loaded.image.clone(),
+            TextureAtlas { layout: layout.clone(), index: 0 },
         ),
+        None => Sprite::from_image(loaded.image.clone()),
+    };
+    let rotation = if def.sprite.rotate {
+        Quat::from_rotation_z(normalized_dir.to_angle())
+    } else {
+        Quat::IDENTITY
+    };
+
+    let mut bullet = commands.spawn((
+        sprite,
         Transform {
             translation: Vec3::new(pos.x, pos.y, 910.0),
-            scale: Vec3::splat(weapon.bullet_size),
-            ..Default::default()
+            rotation,
+            scale: Vec3::splat(weapon.modded_bullet_size()),
         },
-        AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
-        AnimationFrameCount(3),
         Velocity(normalized_dir * weapon.bullet_speed),
         Bullet,
         BulletOwner::Player,
         Collider {
-            half_extents: Vec2::splat(5.0),
+            half_extents: Vec2::from(def.sprite.collider),
         },
         BulletDamage(weapon.damage),
         HitEnemies::default(),
         GameEntity,
     ));
+    if let Some(atlas) = &def.sprite.atlas {
+        bullet.insert((
+            AnimationTimer(Timer::from_seconds(atlas.frame_time, TimerMode::Repeating)),
+            AnimationFrameCount((atlas.columns * atlas.rows) as usize),
+        ));
+    }
     let pierce = weapon.effective_pierce_count();
     if pierce > 0 {
         bullet.insert(Piercing(pierce));
     }
+    if def.knockback > 0.0 {
+        bullet.insert(Knockback(def.knockback));
+    }
+    if let Some(falloff) = &def.falloff {
+        bullet.insert(DamageFalloff { origin: pos, falloff: falloff.clone() });
+    }
+    if let Some((mods, status)) = weapon.bullet_mods() {
+        bullet.insert(mods);
+        if let Some(status) = status {
+            bullet.insert(status);
+        }
+    }
 }
 
 #[derive(Component)]
@@ -0,0 +1,319 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::{GameEntity, GameState, RunRng, TILE_SIZE};
+use crate::bullet::{Bullet, BulletOwner, Velocity};
+use crate::player::{Player, aabb_overlap};
+use crate::locale::{tr, tr_args};
+use crate::rewards::{RewardFont, RewardPopup};
+use crate::status::{OnHitStatus, StatusKind};
+use super::{Weapon, WeaponInventory};
+
+// ── Mods ─────────────────────────────────────────────────────────────────────
+
+/// An attachment that sits in one of a weapon's mod slots and changes the
+/// bullets it fires.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub enum WeaponMod {
+    /// Bullets bounce off walls a couple of times.
+    Ricochet,
+    /// Bullets steer towards the nearest enemy.
+    Homing,
+    /// Bullets burst into two smaller ones on their first enemy hit.
+    Split,
+    /// Hits apply Burn.
+    Incendiary,
+    /// Hits apply Slow.
+    Cryo,
+    /// Bigger bullets with a bigger hitbox.
+    Oversized,
+    /// Bullets ignore breach suction.
+    VacuumProof,
+}
+
+impl WeaponMod {
+    pub const ALL: [WeaponMod; 7] = [
+        WeaponMod::Ricochet,
+        WeaponMod::Homing,
+        WeaponMod::Split,
+        WeaponMod::Incendiary,
+        WeaponMod::Cryo,
+        WeaponMod::Oversized,
+        WeaponMod::VacuumProof,
+    ];
+
+    pub fn name(self) -> String {
+        tr(match self {
+            WeaponMod::Ricochet    => "mod.ricochet",
+            WeaponMod::Homing      => "mod.homing",
+            WeaponMod::Split       => "mod.split",
+            WeaponMod::Incendiary  => "mod.incendiary",
+            WeaponMod::Cryo        => "mod.cryo",
+            WeaponMod::Oversized   => "mod.oversized",
+            WeaponMod::VacuumProof => "mod.vacuum_proof",
+        })
+    }
+
+    /// Single-letter tag for the pickup sprite and inventory slot readout.
+    pub fn letter(self) -> &'static str {
+        match self {
+            WeaponMod::Ricochet    => "R",
+            WeaponMod::Homing      => "H",
+            WeaponMod::Split       => "S",
+            WeaponMod::Incendiary  => "I",
+            WeaponMod::Cryo        => "C",
+            WeaponMod::Oversized   => "O",
+            WeaponMod::VacuumProof => "V",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            WeaponMod::Ricochet    => Color::srgb(0.9, 0.9, 0.4),
+            WeaponMod::Homing      => Color::srgb(0.4, 1.0, 0.6),
+            WeaponMod::Split       => Color::srgb(1.0, 0.5, 0.9),
+            WeaponMod::Incendiary  => StatusKind::Burn.color(),
+            WeaponMod::Cryo        => StatusKind::Slow.color(),
+            WeaponMod::Oversized   => Color::srgb(0.7, 0.6, 1.0),
+            WeaponMod::VacuumProof => Color::srgb(0.6, 0.8, 0.9),
+        }
+    }
+
+    fn random(rng: &mut RunRng) -> Self {
+        Self::ALL[rng.random_range(0..Self::ALL.len())]
+    }
+}
+
+// ── Components ───────────────────────────────────────────────────────────────
+
+/// Per-bullet state for the mods of the weapon that fired it.
+#[derive(Component, Clone, Copy, Default)]
+pub struct BulletMods {
+    pub ricochets_left: u32,
+    pub homing: bool,
+    pub split: bool,
+    pub vacuum_proof: bool,
+    /// Multiplier on the bullet's collision half-extents.
+    pub hitbox_scale: f32,
+}
+
+/// A mod lying on the floor, waiting to be walked over.
+#[derive(Component)]
+pub struct ModPickup(pub WeaponMod);
+
+// ── Tuning ───────────────────────────────────────────────────────────────────
+
+const RICOCHETS: u32 = 2;
+const HOMING_RADIUS: f32 = TILE_SIZE * 10.0;
+/// Max turn rate in radians per second.
+const HOMING_TURN: f32 = 4.0;
+const OVERSIZED_SCALE: f32 = 1.5;
+const INCENDIARY_BURN: OnHitStatus = OnHitStatus { kind: StatusKind::Burn, duration: 3.0, stacks: 1 };
+const CRYO_SLOW: OnHitStatus = OnHitStatus { kind: StatusKind::Slow, duration: 2.0, stacks: 1 };
+/// Angle either side of the parent's heading for split bullets.
+const SPLIT_ANGLE: f32 = 0.5;
+const SPLIT_DAMAGE: f32 = 0.5;
+const SPLIT_SCALE: f32 = 0.7;
+const PICKUP_SIZE: f32 = 18.0;
+
+// ── Weapon helpers ───────────────────────────────────────────────────────────
+
+impl Weapon {
+    pub fn has_mod(&self, m: WeaponMod) -> bool {
+        self.mods.contains(&m)
+    }
+
+    pub fn free_mod_slots(&self) -> usize {
+        (self.mod_slots as usize).saturating_sub(self.mods.len())
+    }
+
+    /// Bullet scale after mods.
+    pub fn modded_bullet_size(&self) -> f32 {
+        if self.has_mod(WeaponMod::Oversized) { self.bullet_size * OVERSIZED_SCALE } else { self.bullet_size }
+    }
+
+    /// Components to add to each bullet this weapon fires, if it has any mods.
+    pub fn bullet_mods(&self) -> Option<(BulletMods, Option<OnHitStatus>)> {
+        if self.mods.is_empty() {
+            return None;
+        }
+        let mods = BulletMods {
+            ricochets_left: if self.has_mod(WeaponMod::Ricochet) { RICOCHETS } else { 0 },
+            homing: self.has_mod(WeaponMod::Homing),
+            split: self.has_mod(WeaponMod::Split),
+            vacuum_proof: self.has_mod(WeaponMod::VacuumProof),
+            hitbox_scale: if self.has_mod(WeaponMod::Oversized) { OVERSIZED_SCALE } else { 1.0 },
+        };
+        // Only one on-hit status fits on a bullet; the later slot wins.
+        let status = self.mods.iter().rev().find_map(|m| match m {
+            WeaponMod::Incendiary => Some(INCENDIARY_BURN),
+            WeaponMod::Cryo => Some(CRYO_SLOW),
+            _ => None,
+        });
+        Some((mods, status))
+    }
+}
+
+impl WeaponInventory {
+    /// Fits `m` into the weapon at `slot`; a full weapon swaps its oldest mod
+    /// into the stash. Returns false if there's no weapon there.
+    pub fn install_mod(&mut self, slot: usize, m: WeaponMod) -> bool {
+        let Some(weapon) = self.weapons.get_mut(slot) else { return false };
+        if weapon.mod_slots == 0 {
+            return false;
+        }
+        if weapon.free_mod_slots() == 0 {
+            let old = weapon.mods.remove(0);
+            self.stash.push(old);
+        }
+        weapon.mods.push(m);
+        true
+    }
+}
+
+// ── Systems ──────────────────────────────────────────────────────────────────
+
+pub struct ModsPlugin;
+
+impl Plugin for ModsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (drop_mods_from_elites, pick_up_mods, steer_homing_bullets)
+                .run_if(in_state(GameState::Playing))
+                .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+        );
+    }
+}
+
+fn spawn_mod_pickup(commands: &mut Commands, font: &Handle<Font>, at: Vec2, m: WeaponMod) {
+    commands
+        .spawn((
+            Sprite {
+                color: m.color(),
+                custom_size: Some(Vec2::splat(PICKUP_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(at.extend(crate::Z_ENTITIES)),
+            ModPickup(m),
+            GameEntity,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Text2d::new(m.letter()),
+                TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                TextColor(Color::BLACK),
+                Transform::from_xyz(0.0, 0.0, 0.1),
+            ));
+        });
+}
+
+/// Every elite leaves a random mod behind.
+fn drop_mods_from_elites(
+    mut commands: Commands,
+    mut events: EventReader<crate::enemies::affix::EliteDeathEvent>,
+    font: Res<RewardFont>,
+    mut rng: ResMut<RunRng>,
+) {
+    for ev in events.read() {
+        spawn_mod_pickup(&mut commands, &font.0, ev.pos.truncate(), WeaponMod::random(&mut rng));
+    }
+}
+
+/// Walking over a mod slots it into the equipped weapon, or stashes it if full.
+fn pick_up_mods(
+    mut commands: Commands,
+    mut player_q: Query<(&Transform, &mut WeaponInventory), With<Player>>,
+    pickups: Query<(Entity, &Transform, &ModPickup)>,
+    font: Res<RewardFont>,
+) {
+    let Ok((player_tf, mut inv)) = player_q.single_mut() else { return };
+    let pp = player_tf.translation;
+    let half = Vec2::splat(TILE_SIZE * 0.5);
+
+    for (entity, tf, pickup) in &pickups {
+        let at = tf.translation;
+        if !aabb_overlap(pp.x, pp.y, half, at.x, at.y, Vec2::splat(PICKUP_SIZE * 0.5)) {
+            continue;
+        }
+        commands.entity(entity).despawn();
+
+        let equipped = inv.equipped;
+        let label = if inv.current().free_mod_slots() > 0 {
+            inv.install_mod(equipped, pickup.0);
+            tr_args("mod.installed", &[("name", &pickup.0.name())])
+        } else {
+            inv.stash.push(pickup.0);
+            tr_args("mod.stashed", &[("name", &pickup.0.name())])
+        };
+        commands.spawn((
+            Text2d::new(label),
+            TextFont { font: font.0.clone(), font_size: 18.0, ..default() },
+            TextColor(pickup.0.color()),
+            Transform::from_translation(Vec3::new(at.x, at.y + TILE_SIZE, 10.0)),
+            RewardPopup { timer: Timer::from_seconds(1.5, TimerMode::Once) },
+            GameEntity,
+        ));
+    }
+}
+
+fn steer_homing_bullets(
+    time: Res<Time>,
+    mut bullets: Query<(&Transform, &mut Velocity, &BulletMods, &BulletOwner), With<Bullet>>,
+    enemies: Query<&Transform, (With<crate::enemies::Enemy>, With<crate::enemies::ActiveEnemy>)>,
+) {
+    let max_turn = HOMING_TURN * time.delta_secs();
+    for (tf, mut vel, mods, owner) in &mut bullets {
+        if !mods.homing || !matches!(owner, BulletOwner::Player) {
+            continue;
+        }
+        let pos = tf.translation.truncate();
+        let Some(target) = enemies
+            .iter()
+            .map(|e| e.translation.truncate())
+            .filter(|e| e.distance_squared(pos) <= HOMING_RADIUS * HOMING_RADIUS)
+            .min_by(|a, b| a.distance_squared(pos).total_cmp(&b.distance_squared(pos)))
+        else {
+            continue;
+        };
+        let turn = vel.0.angle_to(target - pos).clamp(-max_turn, max_turn);
+        vel.0 = Vec2::from_angle(turn).rotate(vel.0);
+    }
+}
+
+/// Spawns the two smaller bullets a `Split` bullet bursts into on its first
+/// enemy hit. They skip the enemy that was hit and can't split again.
+pub(crate) fn spawn_split_bullets(
+    commands: &mut Commands,
+    sprite: &Sprite,
+    scale: f32,
+    pos: Vec2,
+    velocity: Vec2,
+    damage: f32,
+    hit: Entity,
+    mods: BulletMods,
+    status: Option<OnHitStatus>,
+) {
+    for side in [-1.0, 1.0] {
+        let mut hit_enemies = crate::bullet::HitEnemies::default();
+        hit_enemies.0.insert(hit);
+        let mut child = commands.spawn((
+            sprite.clone(),
+            Transform {
+                translation: pos.extend(910.0),
+                scale: Vec3::splat(scale * SPLIT_SCALE),
+                ..default()
+            },
+            Velocity(Vec2::from_angle(SPLIT_ANGLE * side).rotate(velocity)),
+            Bullet,
+            BulletOwner::Player,
+            crate::collidable::Collider { half_extents: Vec2::splat(5.0 * SPLIT_SCALE) },
+            super::BulletDamage(damage * SPLIT_DAMAGE),
+            hit_enemies,
+            BulletMods { split: false, ..mods },
+            GameEntity,
+        ));
+        if let Some(status) = status {
+            child.insert(status);
+        }
+    }
+}
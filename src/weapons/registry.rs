@@ -0,0 +1,237 @@
+use bevy::prelude::*;
+use bevy::asset::io::file::FileAssetReader;
+use serde::Deserialize;
+use crate::locale::tr;
+use super::Weapon;
+
+/// Directory (relative to the asset root) holding one `.ron` file per weapon.
+const WEAPON_DIR: &str = "weapons";
+
+/// Weapon handed out when a saved or selected id no longer has a definition.
+pub const DEFAULT_WEAPON: &str = "zapper";
+
+// ── Definition files ─────────────────────────────────────────────────────────
+
+/// One weapon as described in `assets/weapons/<id>.ron`.
+#[derive(Deserialize, Clone)]
+pub struct WeaponDef {
+    pub id: String,
+    /// String-table keys for the setup card.
+    pub name_key: String,
+    pub description_key: String,
+    /// Sort key for the setup screen and the fallback weapon.
+    #[serde(default)]
+    pub order: i32,
+    /// Locked on the setup screen until the planet chest has been opened once.
+    #[serde(default)]
+    pub requires_unlock: bool,
+    /// Seconds between shots.
+    pub fire_rate: f32,
+    #[serde(default = "one")]
+    pub projectile_count: u32,
+    /// Total fan angle in degrees. A single projectile gets a random jitter within it.
+    #[serde(default)]
+    pub spread: f32,
+    pub speed: f32,
+    pub damage: f32,
+    pub size: f32,
+    pub sprite: WeaponSprite,
+    pub sound: String,
+    /// Enemies a projectile passes through before piercing pickups are counted.
+    #[serde(default)]
+    pub pierce: u32,
+    /// Velocity pushed back onto the shooter per shot.
+    #[serde(default)]
+    pub recoil: f32,
+    /// Velocity added to an enemy on hit, away from the projectile.
+    #[serde(default)]
+    pub knockback: f32,
+    #[serde(default)]
+    pub fire_mode: FireMode,
+    /// Damage drop-off with distance travelled, for pellets.
+    #[serde(default)]
+    pub falloff: Option<Falloff>,
+    /// Heat or magazine limits on top of `fire_rate`; `None` fires freely.
+    #[serde(default)]
+    pub ammo: Option<AmmoDef>,
+    /// How many `WeaponMod`s the weapon can hold.
+    #[serde(default = "two")]
+    pub mod_slots: u32,
+}
+
+impl WeaponDef {
+    pub fn name(&self) -> String {
+        tr(&self.name_key)
+    }
+
+    pub fn description(&self) -> String {
+        tr(&self.description_key)
+    }
+}
+
+/// Resource a weapon spends per shot.
+#[derive(Deserialize, Clone, Copy)]
+pub enum AmmoDef {
+    /// Each shot adds `per_shot` (of a 0..=1 gauge). Hitting 1 overheats the
+    /// weapon until it has fully vented, which takes `vent_time` from full.
+    Heat { per_shot: f32, vent_time: f32 },
+    /// `size` rounds, then an automatic `reload_time` second reload.
+    Magazine { size: u32, reload_time: f32 },
+}
+
+/// How holding and releasing the shoot binding turns into shots.
+#[derive(Deserialize, Clone, Default)]
+pub enum FireMode {
+    /// Fires every `fire_rate` seconds while held.
+    #[default]
+    Auto,
+    /// Charges while held and fires on release. At full charge damage and
+    /// size are multiplied by their scale and `pierce_bonus` is added.
+    Charge {
+        max_time: f32,
+        damage_scale: f32,
+        size_scale: f32,
+        #[serde(default)]
+        pierce_bonus: u32,
+    },
+    /// Lobs a grenade at the cursor (up to `range`) that bursts for `damage`
+    /// within `radius`, scaled down towards the edge.
+    Lob {
+        range: f32,
+        radius: f32,
+        /// Fraction of the blast damage the shooter takes if caught in it.
+        #[serde(default = "half")]
+        self_damage: f32,
+    },
+    /// Continuous hitscan beam while held. Deals `damage` and spends one
+    /// shot of `ammo` every `fire_rate` seconds' worth of contact, so damage
+    /// and attack-speed rewards still apply.
+    Beam {
+        range: f32,
+        width: f32,
+    },
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Falloff {
+    /// Full damage up to this distance.
+    pub start: f32,
+    /// `min` damage multiplier from this distance on.
+    pub end: f32,
+    pub min: f32,
+}
+
+impl Falloff {
+    pub fn multiplier(&self, distance: f32) -> f32 {
+        let t = ((distance - self.start) / (self.end - self.start).max(1.0)).clamp(0.0, 1.0);
+        1.0 - t * (1.0 - self.min)
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct WeaponSprite {
+    pub image: String,
+    /// Horizontal strip animation; `None` for a still image.
+    #[serde(default)]
+    pub atlas: Option<WeaponAtlas>,
+    pub collider: (f32, f32),
+    /// Rotate the sprite to face the direction of travel.
+    #[serde(default)]
+    pub rotate: bool,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct WeaponAtlas {
+    pub cell: u32,
+    pub columns: u32,
+    pub rows: u32,
+    pub frame_time: f32,
+}
+
+fn one() -> u32 { 1 }
+fn half() -> f32 { 0.5 }
+fn two() -> u32 { 2 }
+
+// ── Registry ────────────────────────────────────────────────────────────────
+
+/// A definition together with the asset handles its projectiles need.
+pub struct LoadedWeapon {
+    pub def: WeaponDef,
+    pub image: Handle<Image>,
+    pub atlas: Option<Handle<TextureAtlasLayout>>,
+    pub sound: Handle<AudioSource>,
+}
+
+/// Every weapon definition found at startup, sorted by `order`.
+#[derive(Resource)]
+pub struct WeaponRegistry {
+    weapons: Vec<LoadedWeapon>,
+}
+
+impl WeaponRegistry {
+    pub fn get(&self, id: &str) -> Option<&LoadedWeapon> {
+        self.weapons.iter().find(|w| w.def.id == id)
+    }
+
+    /// Looks up `id`, falling back to the default weapon (or the first one) if it's gone.
+    pub fn get_or_default(&self, id: &str) -> &LoadedWeapon {
+        self.get(id)
+            .or_else(|| self.get(DEFAULT_WEAPON))
+            .unwrap_or(&self.weapons[0])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LoadedWeapon> {
+        self.weapons.iter()
+    }
+
+    /// A fresh weapon instance with the base stats from its definition.
+    pub fn weapon(&self, id: &str) -> Weapon {
+        Weapon::from_def(&self.get_or_default(id).def)
+    }
+}
+
+fn read_defs() -> Vec<WeaponDef> {
+    let dir = FileAssetReader::get_base_path().join("assets").join(WEAPON_DIR);
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        warn!("Could not open weapon directory '{}'", dir.display());
+        return Vec::new();
+    };
+
+    let mut defs = Vec::new();
+    for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+        if path.extension().is_none_or(|ext| ext != "ron") {
+            continue;
+        }
+        match std::fs::read_to_string(&path).map_err(|e| e.to_string())
+            .and_then(|s| ron::from_str::<WeaponDef>(&s).map_err(|e| e.to_string()))
+        {
+            Ok(def) => defs.push(def),
+            Err(e) => warn!("Skipping weapon file '{}': {e}", path.display()),
+        }
+    }
+    defs.sort_by(|a, b| a.order.cmp(&b.order).then_with(|| a.id.cmp(&b.id)));
+    defs
+}
+
+pub(super) fn load_registry(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let defs = read_defs();
+    assert!(!defs.is_empty(), "no weapon definitions found in assets/{WEAPON_DIR}");
+
+    let weapons = defs.into_iter().map(|def| {
+        let atlas = def.sprite.atlas.as_ref().map(|a| {
+            texture_atlases.add(TextureAtlasLayout::from_grid(UVec2::splat(a.cell), a.columns, a.rows, None, None))
+        });
+        LoadedWeapon {
+            image: asset_server.load(def.sprite.image.clone()),
+            sound: asset_server.load(def.sound.clone()),
+            atlas,
+            def,
+        }
+    }).collect();
+
+    commands.insert_resource(WeaponRegistry { weapons });
+}
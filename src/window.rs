@@ -61,11 +61,13 @@ fn check_for_broken_windows(
     window_graphics: Res<WindowGraphics>,
     mut table_q: Query<&mut crate::enemies::Velocity, With<crate::table::Table>>,
     mut wall_grid: Option<ResMut<crate::map::WallGrid>>,
+    mut stat_events: EventWriter<crate::run_history::StatEvent>,
 ) {
     for (entity, health, mut sprite, mut state, transform) in query.iter_mut() {
         if health.0 <= 0.0 && *state == GlassState::Intact {
             // info!("Window breaking at {:?}", transform.translation.truncate());
             *state = GlassState::Broken;
+            stat_events.write(crate::run_history::StatEvent::WindowBroken);
             if let Some(ref mut wg) = wall_grid {
                 wg.remove(transform.translation.truncate());
             }
@@ -112,6 +114,7 @@ fn check_for_broken_windows(
         if health.0 > 0.0 && *state == GlassState::Broken {
             // info!("Window fixed at {:?}", transform.translation.truncate());
             *state = GlassState::Intact;
+            stat_events.write(crate::run_history::StatEvent::WindowRepaired);
 
             commands.entity(entity).remove::<NeedsBreachTracking>();
 